@@ -177,9 +177,12 @@ impl Compiler {
     }
 
     // Topo sort the module graph
-    let mut module_graph = self.context.module_graph.write();
-    module_graph.update_execution_order_for_modules();
-    drop(module_graph);
+    let cyclic = self
+      .context
+      .module_graph
+      .write()
+      .update_execution_order_for_modules();
+    self.check_circular_dependency(&cyclic)?;
 
     // set stats if stats is enabled
     self.set_module_graph_stats();
@@ -190,21 +193,41 @@ impl Compiler {
     }
   }
 
+  /// fail with the first cycle of the module graph when `fail_on_circular_dependency` is enabled
+  pub(crate) fn check_circular_dependency(&self, cyclic: &[Vec<ModuleId>]) -> Result<()> {
+    if !self.context.config.fail_on_circular_dependency {
+      return Ok(());
+    }
+
+    if let Some(cycle) = cyclic.first() {
+      let mut cycle = cycle.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+      // close the cycle, e.g. a -> b -> a
+      cycle.push(cycle[0].clone());
+
+      return Err(CompilationError::CircularDependencyError { cycle });
+    }
+
+    Ok(())
+  }
+
   pub(crate) fn handle_global_log(&self, errors: &mut Vec<CompilationError>) {
     for err in self.context.log_store.lock().errors() {
       errors.push(CompilationError::GenericError(err.to_string()));
     }
 
-    if !self.context.log_store.lock().warnings().is_empty() {
-      for warning in self.context.log_store.lock().warnings() {
-        println!("[warn] {warning}");
-      }
-    }
+    self.print_warnings();
 
     // clear log store
     self.context.log_store.lock().clear();
   }
 
+  /// print the warnings and remove them from the log store, so that they are not printed again
+  pub(crate) fn print_warnings(&self) {
+    for warning in self.context.log_store.lock().take_warnings() {
+      println!("[warn] {warning}");
+    }
+  }
+
   pub(crate) fn resolve_module_id(
     resolve_param: &PluginResolveHookParam,
     context: &Arc<CompilationContext>,
@@ -1,8 +1,20 @@
-use std::sync::Arc;
+use std::{
+  collections::{BTreeMap, HashMap},
+  sync::Arc,
+};
 
 use farmfe_core::{
-  config::Mode, context::CompilationContext, plugin::PluginFinalizeResourcesHookParams,
+  config::Mode,
+  context::CompilationContext,
+  plugin::PluginFinalizeResourcesHookParams,
+  resource::{Resource, ResourceOrigin, ResourceType},
+  serde::Serialize,
+  serde_json,
 };
+use farmfe_toolkit::hash::sha256;
+
+pub const CHECKSUMS_FILENAME: &str = "checksums.txt";
+pub const MANIFEST_FILENAME: &str = "manifest.json";
 
 pub fn finalize_resources(context: &Arc<CompilationContext>) -> farmfe_core::error::Result<()> {
   {
@@ -41,7 +53,121 @@ pub fn finalize_resources(context: &Arc<CompilationContext>) -> farmfe_core::err
         resources_map.remove(&r);
       });
     }
+
+    // the manifest is created before the checksums, so that it's listed in `checksums.txt`
+    if context.config.output.write_manifest {
+      let manifest = create_manifest_resource(&resources_map, context);
+      resources_map.insert(manifest.name.clone(), manifest);
+    }
+
+    if context.config.output.checksums {
+      let checksums = create_checksums_resource(resources_map.values());
+      resources_map.insert(checksums.name.clone(), checksums);
+    }
   }
 
   Ok(())
 }
+
+#[derive(Debug, Default, Serialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+struct ManifestEntry {
+  name: String,
+  /// the js resource of the entry, or its css resource for a css entry
+  file: String,
+  /// the js chunks that are loaded before the entry
+  imports: Vec<String>,
+  css: Vec<String>,
+}
+
+/// entry module id -> the resources of the entry, the resources are found by the resource pots of the entry's module group
+fn create_manifest_resource(
+  resources_map: &HashMap<String, Resource>,
+  context: &Arc<CompilationContext>,
+) -> Resource {
+  let module_graph = context.module_graph.read();
+  let module_group_graph = context.module_group_graph.read();
+  let resource_pot_map = context.resource_pot_map.read();
+  // sorted by module id to make the output stable
+  let mut manifest = BTreeMap::new();
+
+  for (entry, entry_name) in &module_graph.entries {
+    let Some(module_group) = module_group_graph.module_group(entry) else {
+      continue;
+    };
+    let mut manifest_entry = ManifestEntry {
+      name: entry_name.clone(),
+      ..Default::default()
+    };
+
+    for resource_pot_id in module_group.resource_pots() {
+      let Some(resource_pot) = resource_pot_map.resource_pot(resource_pot_id) else {
+        continue;
+      };
+      let is_entry_resource_pot = resource_pot.entry_module.as_ref() == Some(entry);
+
+      for resource in resource_pot
+        .resources()
+        .into_iter()
+        .filter_map(|name| resources_map.get(name))
+      {
+        match resource.resource_type {
+          ResourceType::Js if is_entry_resource_pot => manifest_entry.file = resource.name.clone(),
+          ResourceType::Js => manifest_entry.imports.push(resource.name.clone()),
+          ResourceType::Css => manifest_entry.css.push(resource.name.clone()),
+          _ => {}
+        }
+      }
+    }
+
+    manifest_entry.imports.sort();
+    manifest_entry.css.sort();
+
+    if manifest_entry.file.is_empty() {
+      match manifest_entry.css.first() {
+        Some(css) => manifest_entry.file = css.clone(),
+        // e.g. the runtime entry, which is inlined into the other entries
+        None => continue,
+      }
+    }
+
+    manifest.insert(entry.to_string(), manifest_entry);
+  }
+
+  Resource {
+    name: MANIFEST_FILENAME.to_string(),
+    bytes: serde_json::to_vec_pretty(&manifest).unwrap(),
+    emitted: false,
+    resource_type: ResourceType::Custom("json".to_string()),
+    origin: ResourceOrigin::ResourcePot(MANIFEST_FILENAME.to_string()),
+    info: None,
+  }
+}
+
+/// one `<sha256>  <resource name>` line for each resource that will be written to the output dir
+fn create_checksums_resource<'a>(resources: impl Iterator<Item = &'a Resource>) -> Resource {
+  let mut resources = resources
+    .filter(|resource| !resource.emitted && resource.name != CHECKSUMS_FILENAME)
+    .collect::<Vec<_>>();
+  // sort by name to make the output stable
+  resources.sort_by(|a, b| a.name.cmp(&b.name));
+
+  let mut content = String::new();
+
+  for resource in resources {
+    content.push_str(&format!(
+      "{}  {}\n",
+      sha256(&resource.bytes, 64),
+      resource.name
+    ));
+  }
+
+  Resource {
+    name: CHECKSUMS_FILENAME.to_string(),
+    bytes: content.into_bytes(),
+    emitted: false,
+    resource_type: ResourceType::Custom("txt".to_string()),
+    origin: ResourceOrigin::ResourcePot(CHECKSUMS_FILENAME.to_string()),
+    info: None,
+  }
+}
@@ -35,7 +35,12 @@ impl Compiler {
 
     finalize_resources(&self.context)?;
 
-    self.context.plugin_driver.generate_end(&self.context)
+    self.context.plugin_driver.generate_end(&self.context)?;
+
+    // warnings reported by the build_end and generate hooks
+    self.print_warnings();
+
+    Ok(())
   }
 
   fn optimize_module_graph(&self) -> Result<()> {
@@ -87,6 +87,19 @@ pub fn get_enforce_resource_name_for_module(
   None
 }
 
+pub const VENDOR_RESOURCE_NAME: &str = "vendor";
+
+/// a module is put in the vendor resource when it comes from `node_modules` and is shared by 2 or more entries
+pub fn is_vendor_module(module: &Module, module_graph: &ModuleGraph) -> bool {
+  module.id.to_string().contains("node_modules")
+    && module
+      .module_groups
+      .iter()
+      .filter(|group_id| module_graph.entries.contains_key(*group_id))
+      .count()
+      >= 2
+}
+
 pub fn call_partial_bundling_hook(
   modules: &Vec<ModuleId>,
   context: &Arc<CompilationContext>,
@@ -171,15 +184,22 @@ fn generate_enforce_resource_pots(
   // generate enforce resource pots first
   for g in module_group_graph.module_groups() {
     for module_id in g.modules() {
+      let module = module_graph.module(module_id).unwrap();
       // ignore external module
-      if module_graph.module(module_id).unwrap().external {
+      if module.external {
         continue;
       }
 
-      if let Some(name) = get_enforce_resource_name_for_module(
+      let enforce_resource_name = get_enforce_resource_name_for_module(
         module_id,
         &context.config.partial_bundling.enforce_resources,
-      ) {
+      )
+      .or_else(|| {
+        (context.config.partial_bundling.vendor_chunk && is_vendor_module(module, &module_graph))
+          .then(|| VENDOR_RESOURCE_NAME.to_string())
+      });
+
+      if let Some(name) = enforce_resource_name {
         let (resource_pot_type, resource_pot_name, resource_pot_id) =
           get_resource_pot_id_for_enforce_resources(name.clone(), module_id, &module_graph);
 
@@ -15,7 +15,7 @@ use farmfe_core::{
 };
 use farmfe_toolkit::{
   common::append_source_map_comment,
-  fs::{transform_output_entry_filename, transform_output_filename},
+  fs::{resource_ext, transform_output_entry_filename, transform_output_filename},
 };
 
 use crate::generate::resource_cache::{set_resource_cache, try_get_resource_cache};
@@ -94,21 +94,43 @@ pub fn render_resource_pots_and_generate_resources(
           augment_resource_hash.unwrap_or_default().as_bytes(),
         ]
         .concat();
+        let output = &context.config.output;
+
         if let Some(name) = resource_pot.entry_module.as_ref() {
           let entry_name = entries.get(name).unwrap();
           r.name = transform_output_entry_filename(
-            context.config.output.entry_filename.clone(),
+            output.entry_filename.clone(),
             resource_pot.id.to_string().as_str(),
             entry_name,
             content_with_extra_content_hash,
-            &r.resource_type.to_ext(),
+            &resource_ext(
+              &r.resource_type,
+              output.format.get(Some(entry_name)),
+              &output.target_env,
+            ),
+          );
+        } else if let (Some(chunk_filename), ResourceType::Js) =
+          (&output.chunk_filename, &r.resource_type)
+        {
+          let module_ids = resource_pot
+            .modules()
+            .into_iter()
+            .map(|module_id| module_id.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+          r.name = transform_output_filename(
+            chunk_filename.clone(),
+            &r.name,
+            &[module_ids.as_bytes(), content_with_extra_content_hash].concat(),
+            &resource_ext(&r.resource_type, output.format.common(), &output.target_env),
           );
         } else {
           r.name = transform_output_filename(
-            context.config.output.filename.clone(),
+            output.filename.clone(),
             &r.name,
             content_with_extra_content_hash,
-            &r.resource_type.to_ext(),
+            &resource_ext(&r.resource_type, output.format.common(), &output.target_env),
           );
         }
       }
@@ -118,6 +140,13 @@ pub fn render_resource_pots_and_generate_resources(
         .plugin_driver
         .process_generated_resources(&mut res, context)?;
 
+      // rename the resource before the source map and the resource pot refer to its name
+      if !matches!(res.resource.resource_type, ResourceType::Runtime) {
+        context
+          .plugin_driver
+          .rename_resource(&mut res.resource, context)?;
+      }
+
       let mut cached_result: PluginGenerateResourcesHookResult =
         PluginGenerateResourcesHookResult {
           resource: Default::default(),
@@ -133,13 +162,16 @@ pub fn render_resource_pots_and_generate_resources(
         );
         append_source_map_comment(&mut res.resource, &source_map, &context.config.sourcemap);
 
-        if context.config.persistent_cache.enabled() {
-          cached_result.source_map = Some(source_map.clone());
-        }
+        // the inline source map is embedded in the resource as a data uri, no separate file is emitted
+        if !context.config.sourcemap.is_inline() {
+          if context.config.persistent_cache.enabled() {
+            cached_result.source_map = Some(source_map.clone());
+          }
 
-        resource_pot.add_resource(source_map.name.clone());
+          resource_pot.add_resource(source_map.name.clone());
 
-        resources.lock().push(source_map);
+          resources.lock().push(source_map);
+        }
       }
 
       if context.config.persistent_cache.enabled() {
@@ -5,13 +5,14 @@
 #![allow(clippy::assigning_clones)]
 #![feature(box_patterns)]
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use farmfe_core::{
   config::{Config, Mode},
   context::CompilationContext,
   error::Result,
   farm_profile_function,
+  module::ModuleId,
   plugin::Plugin,
   rayon::{ThreadPool, ThreadPoolBuilder},
 };
@@ -19,9 +20,14 @@ use farmfe_core::{
 pub use farmfe_plugin_css::FARM_CSS_MODULES_SUFFIX;
 pub use farmfe_plugin_lazy_compilation::DYNAMIC_VIRTUAL_SUFFIX;
 pub use farmfe_plugin_runtime::RUNTIME_SUFFIX;
+pub use farmfe_plugin_tree_shake::tree_shaking_report::TreeShakeInfo;
+use farmfe_plugin_tree_shake::tree_shaking_report::TREE_SHAKING_REPORT;
 
 pub mod build;
 pub mod generate;
+pub mod module_graph_snapshot;
+#[cfg(feature = "profile")]
+pub mod profile;
 pub mod trace_module_graph;
 pub mod update;
 
@@ -71,7 +77,7 @@ impl Compiler {
     }
 
     if config.preset_env.enabled() {
-      plugins.push(Arc::new(farmfe_plugin_polyfill::FarmPluginPolyfill::new(&config)) as _);
+      plugins.push(Arc::new(farmfe_plugin_polyfill::FarmPluginPolyfill::new(&config)?) as _);
     }
     // default resolve will be executed at last within internal plugins
     // but it will be executed earlier than external plugins
@@ -179,6 +185,21 @@ impl Compiler {
   pub fn context(&self) -> &Arc<CompilationContext> {
     &self.context
   }
+
+  /// The declared and retained exports of each tree shaken module of the last compilation,
+  /// empty if tree shaking is disabled or the project is not compiled yet.
+  pub fn tree_shaking_report(&self) -> HashMap<ModuleId, TreeShakeInfo> {
+    self
+      .context
+      .custom
+      .get(TREE_SHAKING_REPORT)
+      .and_then(|report| {
+        report
+          .downcast_ref::<HashMap<ModuleId, TreeShakeInfo>>()
+          .cloned()
+      })
+      .unwrap_or_default()
+  }
 }
 
 fn write_cache(context: Arc<CompilationContext>) {
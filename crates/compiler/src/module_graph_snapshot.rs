@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use farmfe_core::{
+  module::{ModuleId, ModuleType},
+  plugin::ResolveKind,
+  resource::resource_pot::ResourcePotId,
+  serde::{Deserialize, Serialize},
+};
+
+use crate::Compiler;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct ModuleSnapshot {
+  pub id: ModuleId,
+  pub module_type: ModuleType,
+  /// size of the module source in bytes, after load and transform
+  pub size: usize,
+  pub execution_order: usize,
+  /// the resource pot the module is rendered in, `None` for external modules
+  pub resource_pot: Option<ResourcePotId>,
+  pub external: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct ModuleSnapshotEdge {
+  pub from: ModuleId,
+  pub to: ModuleId,
+  /// the source of the import, e.g. `./a`
+  pub source: String,
+  pub kind: ResolveKind,
+}
+
+/// A read-only copy of the module graph of the last compilation for tooling like bundle analyzers,
+/// the modules are sorted by execution order and the edges follow the import order of each module.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct ModuleGraphSnapshot {
+  /// entry module id -> entry name
+  pub entries: HashMap<ModuleId, String>,
+  pub modules: Vec<ModuleSnapshot>,
+  pub edges: Vec<ModuleSnapshotEdge>,
+}
+
+impl Compiler {
+  /// Copy the module graph of the last compilation, the lock of the module graph is only held while copying.
+  pub fn module_graph_snapshot(&self) -> ModuleGraphSnapshot {
+    let module_graph = self.context.module_graph.read();
+
+    let mut modules = module_graph
+      .modules()
+      .into_iter()
+      .map(|module| ModuleSnapshot {
+        id: module.id.clone(),
+        module_type: module.module_type.clone(),
+        size: module.size,
+        execution_order: module.execution_order,
+        resource_pot: module.resource_pot.clone(),
+        external: module.external,
+      })
+      .collect::<Vec<_>>();
+    modules.sort_by_key(|module| module.execution_order);
+
+    let mut edges = vec![];
+
+    for module in &modules {
+      for (dep, edge) in module_graph.dependencies(&module.id) {
+        let mut items = edge.items().iter().collect::<Vec<_>>();
+        items.sort_by_key(|item| item.order);
+
+        edges.extend(items.into_iter().map(|item| ModuleSnapshotEdge {
+          from: module.id.clone(),
+          to: dep.clone(),
+          source: item.source.clone(),
+          kind: item.kind.clone(),
+        }));
+      }
+    }
+
+    ModuleGraphSnapshot {
+      entries: module_graph.entries.clone(),
+      modules,
+      edges,
+    }
+  }
+}
@@ -0,0 +1,91 @@
+//! Export the profile scopes recorded by `farm_profile_scope!` and `farm_profile_function!` as a JSON trace.
+//!
+//! The trace uses the [Trace Event Format](https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU),
+//! which can be loaded by `chrome://tracing`, [Perfetto](https://ui.perfetto.dev) or [speedscope](https://www.speedscope.app).
+use std::sync::Arc;
+
+use farmfe_core::{
+  parking_lot::Mutex,
+  puffin::{self, FrameData, FrameSinkId, Reader, Scope, Stream},
+  serde_json::{json, Value},
+};
+
+/// Collects the profile frames reported between [ProfileCollector::start] and [ProfileCollector::finish].
+pub struct ProfileCollector {
+  frames: Arc<Mutex<Vec<Arc<FrameData>>>>,
+  sink_id: FrameSinkId,
+}
+
+impl ProfileCollector {
+  pub fn start() -> Self {
+    puffin::set_scopes_on(true);
+
+    let frames = Arc::new(Mutex::new(vec![]));
+    let sink_frames = frames.clone();
+    let sink_id = puffin::GlobalProfiler::lock().add_sink(Box::new(move |frame| {
+      sink_frames.lock().push(frame);
+    }));
+
+    Self { frames, sink_id }
+  }
+
+  /// Stop collecting and return the JSON trace of the collected scopes
+  pub fn finish(self) -> Value {
+    {
+      let mut profiler = puffin::GlobalProfiler::lock();
+      // flush the scopes of current frame
+      profiler.new_frame();
+      profiler.remove_sink(self.sink_id);
+    }
+
+    let mut trace_events = vec![];
+
+    for frame in self.frames.lock().iter() {
+      let Ok(frame) = frame.unpacked() else {
+        continue;
+      };
+
+      for (thread_info, stream_info) in &frame.thread_streams {
+        let stream = &stream_info.stream;
+
+        if let Ok(scopes) = Reader::from_start(stream).read_top_scopes() {
+          push_trace_events(&mut trace_events, stream, scopes, &thread_info.name);
+        }
+      }
+    }
+
+    json!({
+      "traceEvents": trace_events,
+      "displayTimeUnit": "ms",
+    })
+  }
+}
+
+fn push_trace_events(
+  trace_events: &mut Vec<Value>,
+  stream: &Stream,
+  scopes: Vec<Scope<'_>>,
+  thread_name: &str,
+) {
+  for scope in scopes {
+    let record = scope.record;
+
+    trace_events.push(json!({
+      "name": record.id,
+      "cat": record.location,
+      "ph": "X",
+      // the timestamps of trace events are in microseconds
+      "ts": record.start_ns as f64 / 1000.0,
+      "dur": record.duration_ns as f64 / 1000.0,
+      "pid": 0,
+      "tid": thread_name,
+      "args": { "data": record.data },
+    }));
+
+    if let Ok(children) =
+      Reader::with_offset(stream, scope.child_begin_position).and_then(|r| r.read_top_scopes())
+    {
+      push_trace_events(trace_events, stream, children, thread_name);
+    }
+  }
+}
@@ -34,6 +34,7 @@ use self::{
   patch_module_group_graph::patch_module_group_graph,
   regenerate_resources::{
     regenerate_resources_for_affected_module_groups, render_and_generate_update_resource,
+    UpdateResource,
   },
   update_context::UpdateContext,
 };
@@ -69,6 +70,15 @@ struct HandleUpdateDependenciesParams {
   update_context: Arc<UpdateContext>,
 }
 
+struct PatchContextResult {
+  affected_module_groups: HashSet<ModuleGroupId>,
+  updated_module_ids: Vec<ModuleId>,
+  diff_result: DiffResult,
+  removed_modules: HashMap<ModuleId, Module>,
+  /// cycles of the patched module graph
+  cyclic: Vec<Vec<ModuleId>>,
+}
+
 impl Compiler {
   fn set_update_module_graph_stats(&self, update_context: &Arc<UpdateContext>) {
     if self.context.config.record {
@@ -243,8 +253,13 @@ impl Compiler {
         .collect::<HashSet<_>>()
     };
 
-    let (affected_module_groups, updated_module_ids, diff_result, removed_modules) =
-      self.diff_and_patch_context(paths, &update_context);
+    let PatchContextResult {
+      affected_module_groups,
+      updated_module_ids,
+      diff_result,
+      removed_modules,
+      cyclic,
+    } = self.diff_and_patch_context(paths, &update_context);
     // record graph patch result
     self.set_module_group_graph_stats();
 
@@ -271,6 +286,10 @@ impl Compiler {
       sync,
     );
 
+    // fail after the module graph, module groups and resources are all patched,
+    // so the following updates are diffed against a consistent context
+    self.check_circular_dependency(&cyclic)?;
+
     // after update_module, diff old_resource and new_resource
     {
       let watch_graph = self.context.watch_graph.read();
@@ -301,12 +320,22 @@ impl Compiler {
       !module.module_type.is_script()
     });
 
-    let (immutable_resources, mutable_resources) = if should_reload_page {
-      ("window.location.reload()".to_string(), "{}".to_string())
+    let update_resource = if should_reload_page {
+      UpdateResource::new(
+        "window.location.reload()".to_string(),
+        "{}".to_string(),
+        &updated_module_ids,
+        &diff_result,
+      )
     } else if generate_update_resource {
       render_and_generate_update_resource(&updated_module_ids, &diff_result, &self.context)?
     } else {
-      ("{}".to_string(), "{}".to_string())
+      UpdateResource::new(
+        "{}".to_string(),
+        "{}".to_string(),
+        &updated_module_ids,
+        &diff_result,
+      )
     };
 
     // find the boundaries.
@@ -314,13 +343,15 @@ impl Compiler {
 
     update_result
       .added_module_ids
-      .extend(diff_result.added_modules);
-    update_result.updated_module_ids.extend(updated_module_ids);
+      .extend(update_resource.added_module_ids);
+    update_result
+      .updated_module_ids
+      .extend(update_resource.updated_module_ids);
     update_result
       .removed_module_ids
-      .extend(diff_result.removed_modules);
-    update_result.immutable_resources = immutable_resources;
-    update_result.mutable_resources = mutable_resources;
+      .extend(update_resource.removed_module_ids);
+    update_result.immutable_resources = update_resource.immutable_resources;
+    update_result.mutable_resources = update_resource.mutable_resources;
     update_result.boundaries = boundaries;
     update_result.dynamic_resources_map = dynamic_resources_map;
     Ok(update_result)
@@ -548,12 +579,7 @@ impl Compiler {
     &self,
     paths: Vec<(String, UpdateType)>,
     update_context: &Arc<UpdateContext>,
-  ) -> (
-    HashSet<ModuleId>,
-    Vec<ModuleId>,
-    DiffResult,
-    HashMap<ModuleId, Module>,
-  ) {
+  ) -> PatchContextResult {
     let start_points: Vec<ModuleId> = paths
       .into_iter()
       // Note: HMR does not support the module with query
@@ -572,6 +598,10 @@ impl Compiler {
       &mut update_module_graph,
     );
 
+    // the execution order of the patched modules is only relative to the update module graph,
+    // update it on the whole module graph before the module graph updated hook, e.g. the css plugin reads it as the style order
+    let cyclic = module_graph.update_execution_order_for_modules();
+
     let mut module_group_graph = self.context.module_group_graph.write();
 
     let affected_module_groups = patch_module_group_graph(
@@ -590,12 +620,13 @@ impl Compiler {
       &start_points,
     );
 
-    (
+    PatchContextResult {
       affected_module_groups,
-      start_points,
+      updated_module_ids: start_points,
       diff_result,
       removed_modules,
-    )
+      cyclic,
+    }
   }
 
   fn regenerate_resources<F>(
@@ -23,6 +23,9 @@ use crate::{
 /// 4. diff resource pots
 ///    4.1 for existing resource pot only rerender it when it's modules are changed, added or removed.
 ///    4.2 alway render new resource pots and remove the old ones
+///
+/// The affected enforce resource pots are only rerendered when they contain an added or updated module, or their modules are changed.
+/// The affected module groups and resource pots are processed in order of their ids, so the result is deterministic across updates.
 pub fn generate_and_diff_resource_pots(
   module_groups: &HashSet<ModuleGroupId>,
   diff_result: &DiffResult,
@@ -31,6 +34,7 @@ pub fn generate_and_diff_resource_pots(
   context: &Arc<CompilationContext>,
 ) -> farmfe_core::error::Result<Vec<ResourcePotId>> {
   let affected_modules = get_affected_modules(module_groups, context);
+  let previous_resource_pot_modules = get_resource_pot_modules(module_groups, context);
 
   let (enforce_resource_pot_ids, modules) = handle_enforce_resource_pots(
     &affected_modules,
@@ -43,6 +47,7 @@ pub fn generate_and_diff_resource_pots(
   // for enforce resource pots, only rerender it when it's modules are changed, added or removed.
   let mut resources_pots =
     call_partial_bundling_hook(&modules, context, &PluginHookContext::default())?;
+  resources_pots.sort_by(|a, b| a.id.cmp(&b.id));
 
   // fill necessary fields for all resource pots
   {
@@ -60,14 +65,33 @@ pub fn generate_and_diff_resource_pots(
   let mut new_resource_pot_ids =
     diff_and_patch_resource_pot_map(resources_pots, &enforce_resource_pot_ids, context);
 
-  // alway render affected resource pots resource pots
-  enforce_resource_pot_ids.into_iter().for_each(|id| {
-    if !new_resource_pot_ids.contains(&id) {
-      new_resource_pot_ids.insert(id);
-    }
-  });
+  // only render the affected enforce resource pots that contain a changed module or whose modules are changed
+  {
+    let resource_pot_map = context.resource_pot_map.read();
+
+    enforce_resource_pot_ids.into_iter().for_each(|id| {
+      let Some(resource_pot) = resource_pot_map.resource_pot(&id) else {
+        return;
+      };
+      let modules = resource_pot
+        .modules()
+        .into_iter()
+        .cloned()
+        .collect::<HashSet<_>>();
+      let contains_changed_module = modules.iter().any(|module_id| {
+        diff_result.added_modules.contains(module_id) || updated_module_ids.contains(module_id)
+      });
+
+      if contains_changed_module || previous_resource_pot_modules.get(&id) != Some(&modules) {
+        new_resource_pot_ids.insert(id);
+      }
+    });
+  }
 
-  Ok(new_resource_pot_ids.into_iter().collect())
+  let mut new_resource_pot_ids = new_resource_pot_ids.into_iter().collect::<Vec<_>>();
+  new_resource_pot_ids.sort();
+
+  Ok(new_resource_pot_ids)
 }
 
 fn get_affected_modules(
@@ -75,8 +99,7 @@ fn get_affected_modules(
   context: &Arc<CompilationContext>,
 ) -> Vec<ModuleId> {
   let module_group_graph = context.module_group_graph.read();
-  // let mut enforce_resource_pots = HashSet::new();
-  module_groups
+  let mut affected_modules = module_groups
     .iter()
     .fold(HashSet::new(), |mut acc, module_group_id| {
       let module_group = module_group_graph.module_group(module_group_id).unwrap();
@@ -84,7 +107,32 @@ fn get_affected_modules(
       acc
     })
     .into_iter()
-    .collect::<Vec<_>>()
+    .collect::<Vec<_>>();
+  affected_modules.sort();
+
+  affected_modules
+}
+
+/// modules of the resource pots in the module groups before the resource pots are patched
+fn get_resource_pot_modules(
+  module_groups: &HashSet<ModuleGroupId>,
+  context: &Arc<CompilationContext>,
+) -> HashMap<ResourcePotId, HashSet<ModuleId>> {
+  let module_group_graph = context.module_group_graph.read();
+  let resource_pot_map = context.resource_pot_map.read();
+
+  module_groups
+    .iter()
+    .filter_map(|module_group_id| module_group_graph.module_group(module_group_id))
+    .flat_map(|module_group| module_group.resource_pots())
+    .filter_map(|id| resource_pot_map.resource_pot(id))
+    .map(|resource_pot| {
+      (
+        resource_pot.id.clone(),
+        resource_pot.modules().into_iter().cloned().collect(),
+      )
+    })
+    .collect()
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -199,11 +247,10 @@ fn handle_enforce_resource_pots(
 
   let mut modules = un_enforced_modules.into_iter().collect::<Vec<_>>();
   modules.sort();
+  let mut affected_resource_pot_ids = affected_resource_pot_ids.into_iter().collect::<Vec<_>>();
+  affected_resource_pot_ids.sort();
 
-  (
-    affected_resource_pot_ids.into_iter().collect::<Vec<_>>(),
-    modules,
-  )
+  (affected_resource_pot_ids, modules)
 }
 
 fn diff_and_patch_resource_pot_map(
@@ -232,6 +279,9 @@ fn diff_and_patch_resource_pot_map(
 
     resource_pot.module_groups = module_groups.clone();
 
+    let mut module_groups = module_groups.into_iter().collect::<Vec<_>>();
+    module_groups.sort();
+
     for module_group_id in module_groups {
       let module_group = module_group_graph
         .module_group_mut(&module_group_id)
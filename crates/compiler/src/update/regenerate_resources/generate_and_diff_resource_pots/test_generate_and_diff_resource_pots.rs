@@ -305,3 +305,159 @@ fn test_generate_and_diff_resource_pots_one_module_changed() {
 
   assert!(new_resource_pot_ids.is_empty());
 }
+
+/// only the enforce resource pot that the added module is bundled into is rerendered
+#[test]
+fn test_generate_and_diff_resource_pots_unchanged_enforce_resource_pots() {
+  let mut module_graph = construct_test_module_graph();
+  let mut update_module_graph = construct_test_module_graph();
+  update_module_graph.add_module(Module::new("H".into()));
+  update_module_graph
+    .add_edge(&"B".into(), &"H".into(), Default::default())
+    .unwrap();
+
+  let updated_modules = vec!["B".into()];
+  let mut module_group_graph = module_group_graph_from_entries(
+    &module_graph.entries.clone().into_keys().collect(),
+    &mut module_graph,
+  );
+  let diff_result = diff_module_graph(updated_modules.clone(), &module_graph, &update_module_graph);
+
+  let removed_modules = patch_module_graph(
+    updated_modules.clone(),
+    &diff_result,
+    &mut module_graph,
+    &mut update_module_graph,
+  );
+
+  let affected_groups = patch_module_group_graph(
+    updated_modules.clone(),
+    &diff_result,
+    &removed_modules,
+    &mut module_graph,
+    &mut module_group_graph,
+  );
+  assert_eq!(affected_groups, HashSet::from(["B".into(), "D".into()]));
+
+  let mut config = Config::default();
+  config.partial_bundling.enforce_resources = vec![
+    PartialBundlingEnforceResourceConfig {
+      name: "d".into(),
+      test: vec![ConfigRegex::new("^D$"), ConfigRegex::new("^H$")],
+    },
+    PartialBundlingEnforceResourceConfig {
+      name: "e".into(),
+      test: vec![ConfigRegex::new("^E$")],
+    },
+  ];
+  let plugins: Vec<Arc<dyn Plugin + 'static>> = vec![Arc::new(
+    farmfe_plugin_partial_bundling::FarmPluginPartialBundling::new(&config),
+  )];
+  let context = Arc::new(CompilationContext::new(config, plugins).unwrap());
+
+  {
+    let mut mg = context.module_graph.write();
+    *mg = module_graph;
+  }
+
+  {
+    let mut mgg = context.module_group_graph.write();
+    *mgg = module_group_graph;
+  }
+
+  let resource_pot_map =
+    generate_resource_pot_map(&context, &PluginHookContext::default()).unwrap();
+  context.resource_pot_map.write().replace(resource_pot_map);
+
+  let resource_pot_ids = generate_and_diff_resource_pots(
+    &affected_groups,
+    &diff_result,
+    &updated_modules,
+    &removed_modules,
+    &context,
+  )
+  .unwrap();
+
+  // the resource pot of E is in the same module group, but its modules are unchanged
+  assert_eq!(
+    resource_pot_ids,
+    vec![String::from("d_custom(\"__farm_unknown\")")]
+  );
+
+  let resource_pot_map = context.resource_pot_map.read();
+  let resource_pot_d = resource_pot_map
+    .resource_pot(&"d_custom(\"__farm_unknown\")".to_string())
+    .unwrap();
+  assert_eq!(resource_pot_d.modules(), vec![&"D".into(), &"H".into()]);
+}
+
+fn generate_resource_pot_ids_for_update() -> Vec<String> {
+  let mut module_graph = construct_test_module_graph();
+  let mut update_module_graph = construct_test_module_graph();
+  update_module_graph.add_module(Module::new("H".into()));
+  update_module_graph
+    .add_edge(&"B".into(), &"H".into(), Default::default())
+    .unwrap();
+
+  let updated_modules = vec!["F".into(), "E".into(), "B".into()];
+  let mut module_group_graph = module_group_graph_from_entries(
+    &module_graph.entries.clone().into_keys().collect(),
+    &mut module_graph,
+  );
+  let diff_result = diff_module_graph(updated_modules.clone(), &module_graph, &update_module_graph);
+
+  let removed_modules = patch_module_graph(
+    updated_modules.clone(),
+    &diff_result,
+    &mut module_graph,
+    &mut update_module_graph,
+  );
+
+  let affected_groups = patch_module_group_graph(
+    updated_modules.clone(),
+    &diff_result,
+    &removed_modules,
+    &mut module_graph,
+    &mut module_group_graph,
+  );
+
+  let config = Config::default();
+  let plugins: Vec<Arc<dyn Plugin + 'static>> = vec![Arc::new(
+    farmfe_plugin_partial_bundling::FarmPluginPartialBundling::new(&config),
+  )];
+  let context = Arc::new(CompilationContext::new(config, plugins).unwrap());
+
+  {
+    let mut mg = context.module_graph.write();
+    *mg = module_graph;
+  }
+
+  {
+    let mut mgg = context.module_group_graph.write();
+    *mgg = module_group_graph;
+  }
+
+  generate_and_diff_resource_pots(
+    &affected_groups,
+    &diff_result,
+    &updated_modules,
+    &removed_modules,
+    &context,
+  )
+  .unwrap()
+}
+
+/// the same update should always generate the same resource pots in the same order
+#[test]
+fn test_generate_and_diff_resource_pots_deterministic() {
+  let resource_pot_ids = generate_resource_pot_ids_for_update();
+  assert!(resource_pot_ids.len() > 1);
+
+  let mut sorted_resource_pot_ids = resource_pot_ids.clone();
+  sorted_resource_pot_ids.sort();
+  assert_eq!(resource_pot_ids, sorted_resource_pot_ids);
+
+  for _ in 0..5 {
+    assert_eq!(generate_resource_pot_ids_for_update(), resource_pot_ids);
+  }
+}
@@ -3,18 +3,14 @@ use std::sync::Arc;
 
 use farmfe_core::{
   context::CompilationContext,
-  enhanced_magic_string::types::SourceMapOptions,
-  error::CompilationError,
   module::{module_group::ModuleGroupId, Module, ModuleId},
   resource::resource_pot::{ResourcePot, ResourcePotMetaData, ResourcePotType},
 };
 
 use farmfe_plugin_runtime::render_resource_pot::{
-  resource_pot_to_runtime_object, RenderedJsResourcePot,
+  render_wrapped_resource_pot_to_code, RenderedResourcePot,
 };
-use farmfe_plugin_runtime::ASYNC_MODULES;
 use farmfe_toolkit::hash::base64_encode;
-use farmfe_utils::relative;
 
 use crate::{
   generate::render_resource_pots::{
@@ -26,14 +22,59 @@ use crate::{
 use super::diff_and_patch_module_graph::DiffResult;
 
 mod generate_and_diff_resource_pots;
+#[cfg(test)]
+mod test_render_and_generate_update_resource;
 
 use generate_and_diff_resource_pots::generate_and_diff_resource_pots;
 
+/// The update resources and the changed modules of a HMR update
+#[derive(Debug, Default)]
+pub struct UpdateResource {
+  /// code of the updated immutable modules
+  pub immutable_resources: String,
+  /// code of the updated mutable modules
+  pub mutable_resources: String,
+  pub added_module_ids: Vec<ModuleId>,
+  pub updated_module_ids: Vec<ModuleId>,
+  pub removed_module_ids: Vec<ModuleId>,
+}
+
+impl UpdateResource {
+  /// The added and removed modules are derived from the diff result, sorted by id
+  pub fn new(
+    immutable_resources: String,
+    mutable_resources: String,
+    updated_module_ids: &[ModuleId],
+    diff_result: &DiffResult,
+  ) -> Self {
+    let mut added_module_ids = diff_result
+      .added_modules
+      .iter()
+      .cloned()
+      .collect::<Vec<_>>();
+    added_module_ids.sort();
+    let mut removed_module_ids = diff_result
+      .removed_modules
+      .iter()
+      .cloned()
+      .collect::<Vec<_>>();
+    removed_module_ids.sort();
+
+    Self {
+      immutable_resources,
+      mutable_resources,
+      added_module_ids,
+      updated_module_ids: updated_module_ids.to_vec(),
+      removed_module_ids,
+    }
+  }
+}
+
 pub fn render_and_generate_update_resource(
   updated_module_ids: &Vec<ModuleId>,
   diff_result: &DiffResult,
   context: &Arc<CompilationContext>,
-) -> farmfe_core::error::Result<(String, String)> {
+) -> farmfe_core::error::Result<UpdateResource> {
   let mut immutable_update_resource_pot = ResourcePot::new(
     String::from("__IMMUTABLE_UPDATE_RESOURCE_POT__"),
     ResourcePotType::Js,
@@ -76,42 +117,27 @@ pub fn render_and_generate_update_resource(
     }
   }
 
+  drop(module_graph);
+
   let gen_resource_pot_code =
     |resource_pot: &mut ResourcePot| -> farmfe_core::error::Result<String> {
-      let async_modules = context.custom.get(ASYNC_MODULES).unwrap();
-      let async_modules = async_modules.downcast_ref::<HashSet<ModuleId>>().unwrap();
       if !resource_pot.modules().is_empty() {
-        let RenderedJsResourcePot {
-          mut bundle,
+        let RenderedResourcePot {
+          code,
+          map,
           rendered_modules,
           ..
-        } = resource_pot_to_runtime_object(resource_pot, &module_graph, async_modules, context)?;
-        bundle.prepend("(");
-        bundle.append(")", None);
-
-        let mut rendered_map_chain = vec![];
-
-        if context.config.sourcemap.enabled(resource_pot.immutable) {
-          let root = context.config.root.clone();
-          let map = bundle
-            .generate_map(SourceMapOptions {
-              include_content: Some(true),
-              remap_source: Some(Box::new(move |src| format!("/{}", relative(&root, src)))),
-              ..Default::default()
-            })
-            .map_err(|_| CompilationError::GenerateSourceMapError {
-              id: resource_pot.id.clone(),
-            })?;
-
-          let mut buf = vec![];
-          map.to_writer(&mut buf).expect("failed to write sourcemap");
-          rendered_map_chain.push(Arc::new(String::from_utf8(buf).unwrap()));
-        }
+        } = render_wrapped_resource_pot_to_code(resource_pot, context, |bundle, _| {
+          bundle.prepend("(");
+          bundle.append(")", None);
+          Ok(())
+        })?;
+
         // The hmr result should alway be a js resource
         resource_pot.meta = ResourcePotMetaData {
           rendered_modules,
-          rendered_content: Arc::new(bundle.to_string()),
-          rendered_map_chain,
+          rendered_content: Arc::new(code),
+          rendered_map_chain: map.map(|map| vec![Arc::new(map)]).unwrap_or_default(),
           ..Default::default()
         };
 
@@ -145,7 +171,12 @@ pub fn render_and_generate_update_resource(
   let immutable_update_resource = gen_resource_pot_code(&mut immutable_update_resource_pot)?;
   let mutable_update_resource = gen_resource_pot_code(&mut mutable_update_resource_pot)?;
 
-  Ok((immutable_update_resource, mutable_update_resource))
+  Ok(UpdateResource::new(
+    immutable_update_resource,
+    mutable_update_resource,
+    updated_module_ids,
+    diff_result,
+  ))
 }
 
 pub fn regenerate_resources_for_affected_module_groups(
@@ -155,12 +186,6 @@ pub fn regenerate_resources_for_affected_module_groups(
   removed_modules: &HashMap<ModuleId, Module>,
   context: &Arc<CompilationContext>,
 ) -> farmfe_core::error::Result<()> {
-  // if there are deps changes, update execution order
-  {
-    let mut module_graph = context.module_graph.write();
-    module_graph.update_execution_order_for_modules();
-  }
-
   // skip diff resource pots if diff_result is empty
   let mut affected_resource_pots_ids = if diff_result.added_modules.is_empty()
     && diff_result.removed_modules.is_empty()
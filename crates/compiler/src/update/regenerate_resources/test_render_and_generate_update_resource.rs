@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use farmfe_core::{
+  config::Config,
+  context::CompilationContext,
+  module::{Module, ModuleId},
+};
+use farmfe_plugin_runtime::ASYNC_MODULES;
+use farmfe_testing_helpers::construct_test_module_graph;
+
+use crate::update::{
+  diff_and_patch_module_graph::{diff_module_graph, patch_module_graph},
+  regenerate_resources::render_and_generate_update_resource,
+};
+
+#[test]
+fn test_render_and_generate_update_resource() {
+  let mut module_graph = construct_test_module_graph();
+  let mut update_module_graph = construct_test_module_graph();
+  update_module_graph.remove_module(&"G".into());
+  update_module_graph.add_module(Module::new("I".into()));
+  update_module_graph.add_module(Module::new("H".into()));
+  update_module_graph
+    .add_edge(&"B".into(), &"H".into(), Default::default())
+    .unwrap();
+  update_module_graph
+    .add_edge(&"B".into(), &"I".into(), Default::default())
+    .unwrap();
+
+  let updated_modules: Vec<ModuleId> = vec!["E".into(), "B".into()];
+  let diff_result = diff_module_graph(updated_modules.clone(), &module_graph, &update_module_graph);
+  patch_module_graph(
+    updated_modules.clone(),
+    &diff_result,
+    &mut module_graph,
+    &mut update_module_graph,
+  );
+
+  // external modules are not rendered, so the update resources are empty
+  for module in module_graph.modules_mut() {
+    module.external = true;
+  }
+
+  let context = Arc::new(CompilationContext::new(Config::default(), vec![]).unwrap());
+  context.custom.insert(
+    ASYNC_MODULES.to_string(),
+    Box::new(HashSet::<ModuleId>::new()),
+  );
+  *context.module_graph.write() = module_graph;
+
+  let update_resource =
+    render_and_generate_update_resource(&updated_modules, &diff_result, &context).unwrap();
+
+  assert_eq!(update_resource.immutable_resources, "{}");
+  assert_eq!(update_resource.mutable_resources, "{}");
+  assert_eq!(
+    update_resource.added_module_ids,
+    vec![ModuleId::from("H"), ModuleId::from("I")]
+  );
+  assert_eq!(update_resource.updated_module_ids, updated_modules);
+  assert_eq!(
+    update_resource.removed_module_ids,
+    vec![ModuleId::from("G")]
+  );
+}
@@ -1,20 +1,25 @@
-use std::{collections::HashMap, path::PathBuf};
-
-use farmfe_core::config::{bool_or_obj::BoolOrObj, config_regex::ConfigRegex, Mode, TargetEnv};
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+use farmfe_core::{
+  config::{
+    bool_or_obj::BoolOrObj, config_regex::ConfigRegex,
+    partial_bundling::PartialBundlingEnforceResourceConfig, Mode, ModuleFormat, TargetEnv,
+  },
+  context::CompilationContext,
+  plugin::Plugin,
+};
+use farmfe_plugin_bundle::register_polyfill;
+use farmfe_testing_helpers::fixture;
 mod common;
 use crate::common::{
   assert_compiler_result_with_config, create_compiler_with_args, AssertCompilerResultConfig,
+  WarningsCollector,
 };
 
 #[allow(dead_code)]
 #[cfg(test)]
 fn test(file: String, crate_path: String) {
-  use std::fs;
-
-  use common::get_config_field;
-  use farmfe_core::config::partial_bundling::PartialBundlingEnforceResourceConfig;
-
-  use crate::common::try_read_config_from_json;
+  use crate::common::{get_fixture_configs, merge_config_from_json};
 
   let file_path_buf = PathBuf::from(file.clone());
   let create_path_buf = PathBuf::from(crate_path);
@@ -24,36 +29,8 @@ fn test(file: String, crate_path: String) {
   let entry_name = "index".to_string();
 
   let runtime_entry = cwd.to_path_buf().join("runtime.ts");
-  let files = fs::read_dir(cwd)
-    .unwrap()
-    .filter_map(|item| item.map(|item| Some(item)).unwrap_or(None))
-    .filter_map(|item| {
-      let filename = item
-        .path()
-        .file_name()
-        .unwrap_or_default()
-        .to_string_lossy()
-        .to_string();
-
-      if filename.starts_with("config") || filename.ends_with(".json") {
-        Some((
-          item.path(),
-          filename
-            .trim_start_matches("config")
-            .trim_start_matches(".")
-            .trim_end_matches("json")
-            .trim_end_matches(".")
-            .to_string(),
-        ))
-      } else {
-        None
-      }
-    })
-    .collect::<Vec<_>>();
-
-  for (config_entry, config_named) in files {
-    let config_from_file = try_read_config_from_json(config_entry);
 
+  for (config_from_file, output_file) in get_fixture_configs(cwd) {
     let compiler = create_compiler_with_args(
       cwd.to_path_buf(),
       create_path_buf.clone(),
@@ -80,21 +57,7 @@ fn test(file: String, crate_path: String) {
           name: "index".to_string(),
         }];
 
-        if let Some(config_from_file) = config_from_file {
-          if let Some(mode) = get_config_field(&config_from_file, &["mode"]) {
-            config.mode = mode;
-          }
-
-          if let Some(format) = get_config_field(&config_from_file, &["output", "format"]) {
-            config.output.format = format;
-          }
-
-          if let Some(target_env) = get_config_field(&config_from_file, &["output", "targetEnv"]) {
-            config.output.target_env = target_env;
-          }
-        }
-
-        (config, plugins)
+        (merge_config_from_json(config, &config_from_file), plugins)
       },
     );
 
@@ -105,15 +68,7 @@ fn test(file: String, crate_path: String) {
       AssertCompilerResultConfig {
         entry_name: Some(entry_name.clone()),
         ignore_emitted_field: false,
-        output_file: Some(format!(
-          "output.{}js",
-          if config_named.is_empty() {
-            "".into()
-          } else {
-            format!("{config_named}.")
-          }
-        )),
-        ..Default::default()
+        output_file: Some(output_file),
       },
     );
   }
@@ -122,3 +77,147 @@ fn test(file: String, crate_path: String) {
 farmfe_testing::testing! {"tests/fixtures/bundle/library/**/index.ts", test}
 // farmfe_testing::testing! {"tests/fixtures/runtime/bundle/cjs/export/entryExportStar/**/index.ts", test}
 // farmfe_testing::testing! {"tests/fixtures/runtime/bundle/external/import/namespace/**/index.ts", test}
+
+#[test]
+fn with_stmt() {
+  fixture!(
+    "tests/fixtures/bundle/with_stmt/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.output.target_env = TargetEnv::Library;
+
+          (config, plugins)
+        });
+
+      let err = compiler.compile().unwrap_err().to_string();
+      assert!(err.contains(
+        "Module `legacy.js` contains a `with` statement, which is not allowed in strict mode"
+      ));
+    }
+  );
+}
+
+#[test]
+fn arguments_callee() {
+  fixture!(
+    "tests/fixtures/bundle/arguments_callee/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.output.target_env = TargetEnv::Library;
+
+          (config, plugins)
+        });
+
+      let err = compiler.compile().unwrap_err().to_string();
+      assert!(err.contains(
+        "Module `legacy.js` contains `arguments.callee`, which is not allowed in strict mode"
+      ));
+    }
+  );
+}
+
+#[test]
+fn sloppy_commonjs_skips_use_strict_directive() {
+  fixture!(
+    "tests/fixtures/bundle/use_strict_sloppy/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let warnings_collector = Arc::new(WarningsCollector::default());
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, mut plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.output.target_env = TargetEnv::Custom("library-node".to_string());
+          config.output.format = ModuleFormat::CommonJs.into();
+          plugins.push(warnings_collector.clone() as _);
+
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      // the wrapped commonjs module keeps working in sloppy mode
+      assert_compiler_result_with_config(&compiler, AssertCompilerResultConfig::default());
+
+      let warnings = warnings_collector.warnings.lock();
+      assert!(warnings.iter().any(|warning| warning.contains(
+        "Module `legacy.js` is not strict mode code, the \"use strict\" directive is not emitted"
+      )));
+    }
+  );
+}
+
+#[test]
+fn custom_polyfill() {
+  struct GlobalThisPolyfillPlugin;
+
+  impl Plugin for GlobalThisPolyfillPlugin {
+    fn name(&self) -> &str {
+      "global-this-polyfill"
+    }
+
+    fn build_start(
+      &self,
+      context: &Arc<CompilationContext>,
+    ) -> farmfe_core::error::Result<Option<()>> {
+      register_polyfill(
+        context,
+        "global_this",
+        r#"var _global_this = typeof globalThis !== "undefined" ? globalThis : window;"#,
+      );
+
+      Ok(Some(()))
+    }
+  }
+
+  fixture!(
+    "tests/fixtures/bundle/custom_polyfill/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, mut plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.output.target_env = TargetEnv::Custom("library-node".to_string());
+          config.output.format = ModuleFormat::CommonJs.into();
+          plugins.push(Arc::new(GlobalThisPolyfillPlugin) as _);
+
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      // the polyfill is inserted once, and the module variable is renamed to avoid the name it declares
+      assert_compiler_result_with_config(&compiler, AssertCompilerResultConfig::default());
+    }
+  );
+}
+
+#[test]
+fn iife_format_without_name() {
+  fixture!(
+    "tests/fixtures/bundle/library/format/iife/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.output.target_env = TargetEnv::Custom("library-browser".to_string());
+          config.output.format = ModuleFormat::Iife.into();
+
+          (config, plugins)
+        });
+
+      let err = compiler.compile().unwrap_err().to_string();
+      assert!(err.contains("`output.name` is required when `output.format` is `iife`"));
+    }
+  );
+}
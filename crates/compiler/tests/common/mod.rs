@@ -1,4 +1,8 @@
-use std::{collections::HashMap, path::PathBuf, sync::Arc};
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
 use farmfe_compiler::Compiler;
 use farmfe_core::{
@@ -6,6 +10,8 @@ use farmfe_core::{
     bool_or_obj::BoolOrObj, config_regex::ConfigRegex, persistent_cache::PersistentCacheConfig,
     preset_env::PresetEnvConfig, Config, CssConfig, Mode, RuntimeConfig, SourcemapConfig,
   },
+  context::CompilationContext,
+  parking_lot::Mutex,
   plugin::Plugin,
   serde::de::DeserializeOwned,
   serde_json::{self, Value},
@@ -321,3 +327,79 @@ pub fn get_config_field<T: DeserializeOwned>(value: &Value, keys: &[&str]) -> Op
       .expect(format!("{} type is not correct", keys.join(".")).as_str()),
   )
 }
+
+/// The configs of the fixture in `cwd`, `config.json` is asserted against `output.js` and `config.<name>.json` against `output.<name>.js`.
+/// The fixture is compiled once with an empty config if there is no config file
+#[allow(dead_code)]
+pub fn get_fixture_configs(cwd: &Path) -> Vec<(Value, String)> {
+  let mut configs = std::fs::read_dir(cwd)
+    .unwrap()
+    .filter_map(|entry| {
+      let filename = entry.ok()?.file_name().to_string_lossy().to_string();
+      let name = filename
+        .strip_prefix("config")?
+        .strip_suffix("json")?
+        .trim_matches('.');
+      let output_file = if name.is_empty() {
+        "output.js".to_string()
+      } else {
+        format!("output.{name}.js")
+      };
+
+      Some((try_read_config_from_json(cwd.join(filename))?, output_file))
+    })
+    .collect::<Vec<_>>();
+
+  if configs.is_empty() {
+    configs.push((Value::Object(Default::default()), "output.js".to_string()));
+  }
+
+  configs.sort_by(|(_, a), (_, b)| a.cmp(b));
+  configs
+}
+
+/// Merge the config of a fixture into `config`, objects are merged recursively and the other values are replaced
+#[allow(dead_code)]
+pub fn merge_config_from_json(config: Config, value: &Value) -> Config {
+  fn merge(target: &mut Value, value: &Value) {
+    match (target, value) {
+      (Value::Object(target), Value::Object(value)) => {
+        for (key, value) in value {
+          merge(target.entry(key.clone()).or_insert(Value::Null), value);
+        }
+      }
+      (target, value) => *target = value.clone(),
+    }
+  }
+
+  let mut merged = serde_json::to_value(config).unwrap();
+  merge(&mut merged, value);
+
+  serde_json::from_value(merged).expect("the config of the fixture is not valid")
+}
+
+/// Collect the warnings of the compilation, the compiler removes the warnings from the log store after printing them
+#[allow(dead_code)]
+#[derive(Default)]
+pub struct WarningsCollector {
+  pub warnings: Mutex<Vec<String>>,
+}
+
+impl Plugin for WarningsCollector {
+  fn name(&self) -> &str {
+    "WarningsCollector"
+  }
+
+  fn generate_end(
+    &self,
+    context: &Arc<CompilationContext>,
+  ) -> farmfe_core::error::Result<Option<()>> {
+    let log_store = context.log_store.lock();
+    self
+      .warnings
+      .lock()
+      .extend(log_store.warnings().iter().cloned());
+
+    Ok(None)
+  }
+}
@@ -1,10 +1,10 @@
 use std::collections::HashMap;
 
-use farmfe_core::config::{CssConfig, CssModulesConfig, CssPrefixerConfig};
+use farmfe_core::config::{CssConfig, CssModulesConfig, CssPrefixerConfig, Mode};
 use farmfe_testing_helpers::fixture;
 mod common;
 
-use crate::common::{assert_compiler_result, create_css_compiler};
+use crate::common::{assert_compiler_result, create_compiler_with_args, create_css_compiler};
 
 #[test]
 fn css_modules() {
@@ -85,3 +85,69 @@ fn css_url_replacer() {
     }
   );
 }
+
+#[test]
+fn css_runtime_order() {
+  fixture!(
+    "tests/fixtures/css/runtime_order/**/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let entry_name = "index".to_string();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.mode = Mode::Development;
+          config.input = HashMap::from([(entry_name.clone(), "./index.ts".into())]);
+          config.css = Box::new(CssConfig {
+            modules: None,
+            prefixer: None,
+            ..Default::default()
+          });
+
+          (config, plugins)
+        });
+
+      compiler.compile().unwrap();
+
+      assert_compiler_result(&compiler, Some(&entry_name));
+    }
+  );
+}
+
+#[test]
+fn css_export_style_element() {
+  fixture!(
+    "tests/fixtures/css/export_style_element/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let entry_name = "index".to_string();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.mode = Mode::Development;
+          config.input = HashMap::from([(entry_name.clone(), "./index.ts".into())]);
+          config.css = Box::new(CssConfig {
+            modules: None,
+            prefixer: None,
+            export_style_element: true,
+          });
+
+          (config, plugins)
+        });
+
+      compiler.compile().unwrap();
+
+      {
+        let resources_map = compiler.context().resources_map.lock();
+        let code = String::from_utf8_lossy(&resources_map["index.js"].bytes).to_string();
+        // the default export is the injected `<style>` element
+        assert!(code.contains("const style = document.createElement('style');"));
+        assert!(code.contains("exports.default = style;"));
+      }
+
+      assert_compiler_result(&compiler, Some(&entry_name));
+    }
+  );
+}
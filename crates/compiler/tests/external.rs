@@ -1,9 +1,15 @@
 mod common;
 use crate::common::{assert_compiler_result, create_compiler_with_args};
-use std::{collections::HashMap, path::PathBuf};
+use farmfe_testing_helpers::fixture;
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
-use farmfe_core::config::{
-  config_regex::ConfigRegex, custom::CUSTOM_CONFIG_EXTERNAL_RECORD, ModuleFormat, TargetEnv,
+use farmfe_core::{
+  config::{
+    config_regex::ConfigRegex,
+    custom::{CUSTOM_CONFIG_EXTERNAL_PREFER, CUSTOM_CONFIG_EXTERNAL_RECORD},
+    Config, ModuleFormat, TargetEnv,
+  },
+  plugin::Plugin,
 };
 
 fn test(file: String, crate_path: String) {
@@ -44,7 +50,8 @@ fn test(file: String, crate_path: String) {
         ModuleFormat::CommonJs
       } else {
         ModuleFormat::EsModule
-      };
+      }
+      .into();
 
       (config, plugins)
     });
@@ -55,3 +62,130 @@ fn test(file: String, crate_path: String) {
 }
 
 farmfe_testing::testing! {"tests/fixtures/external/**/index.ts", test}
+
+#[test]
+fn native_dynamic_import_of_externals_in_esm() {
+  fixture!(
+    "tests/fixtures/external_dynamic_import/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.output.target_env = TargetEnv::Node;
+          config.output.format = ModuleFormat::EsModule.into();
+          config.external = vec![ConfigRegex::new("^some-external")];
+
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      let resources_map = compiler.context().resources_map.lock();
+      let code = String::from_utf8_lossy(&resources_map["index.js"].bytes).to_string();
+
+      // the specifiers are preserved exactly and resolved by the host
+      assert!(code.contains("await import('some-external');"));
+      assert!(code.contains("await import(\"some-external/helper.js\");"));
+      assert!(!code.contains("farmDynamicRequire(\"some-external"));
+      // the externals are neither imported eagerly nor listed as dynamic resources to load
+      assert!(!code.contains("__farm_external_module_some_external"));
+      assert!(!code.contains("setExternalModules"));
+      assert!(code.contains(".setDynamicModuleResourcesMap([],{  });"));
+    }
+  );
+}
+
+#[test]
+fn external_contributed_by_plugin() {
+  struct PeerDepsPlugin;
+
+  impl Plugin for PeerDepsPlugin {
+    fn name(&self) -> &str {
+      "peer-deps"
+    }
+
+    fn config(&self, config: &mut Config) -> farmfe_core::error::Result<Option<()>> {
+      config.external.push(ConfigRegex::new("^peer-dep$"));
+      Ok(Some(()))
+    }
+  }
+
+  fixture!(
+    "tests/fixtures/external_plugin/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, mut plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.output.target_env = TargetEnv::Node;
+          plugins.push(Arc::new(PeerDepsPlugin) as _);
+
+          (config, plugins)
+        });
+      // `peer-dep` is not installed, the compilation fails if it is not external
+      compiler.compile().unwrap();
+
+      let module_graph = compiler.context().module_graph.read();
+      let peer_dep = module_graph.module(&"peer-dep".into()).unwrap();
+      assert!(peer_dep.external);
+
+      let resources_map = compiler.context().resources_map.lock();
+      let code = String::from_utf8_lossy(&resources_map["index.js"].bytes).to_string();
+      assert!(code.contains("\"peer-dep\""));
+    }
+  );
+}
+
+#[test]
+fn external_prefer() {
+  fixture!(
+    "tests/fixtures/external_prefer/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      for prefer in ["external", "local"] {
+        let compiler = create_compiler_with_args(
+          cwd.to_path_buf(),
+          crate_path.clone(),
+          |mut config, plugins| {
+            config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+            config.output.target_env = TargetEnv::Node;
+            config.external = vec![
+              ConfigRegex::new("^shared-dep$"),
+              ConfigRegex::new("^missing-dep$"),
+            ];
+            config.custom.insert(
+              CUSTOM_CONFIG_EXTERNAL_PREFER.to_string(),
+              format!("\"{prefer}\""),
+            );
+
+            (config, plugins)
+          },
+        );
+        compiler.compile().unwrap();
+
+        let module_graph = compiler.context().module_graph.read();
+        let shared_dep_external = module_graph
+          .module(&"shared-dep".into())
+          .is_some_and(|m| m.external);
+        let shared_dep_local = module_graph
+          .modules()
+          .into_iter()
+          .any(|m| !m.external && m.id.to_string().contains("node_modules/shared-dep"));
+
+        if prefer == "external" {
+          assert!(shared_dep_external);
+          assert!(!shared_dep_local);
+        } else {
+          assert!(!shared_dep_external);
+          assert!(shared_dep_local);
+        }
+
+        // `missing-dep` can not be resolved locally, it is kept external in both cases
+        assert!(module_graph.module(&"missing-dep".into()).unwrap().external);
+      }
+    }
+  );
+}
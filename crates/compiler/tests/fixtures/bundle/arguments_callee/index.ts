@@ -0,0 +1,3 @@
+import { factorial } from './legacy';
+
+console.log(factorial(5));
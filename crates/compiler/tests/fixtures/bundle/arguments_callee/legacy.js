@@ -0,0 +1,3 @@
+export function factorial(n) {
+  return n <= 1 ? 1 : n * arguments.callee(n - 1);
+}
@@ -0,0 +1,6 @@
+// conflicts with the name declared by the registered polyfill
+const _global_this = 'local';
+
+export default function name() {
+  return _global_this;
+}
@@ -0,0 +1,12 @@
+//index.js:
+ "use strict";
+var _global_this = typeof globalThis !== "undefined" ? globalThis : window;function _export_es_module(exports) {
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+}const _global_this$1 = 'local';
+function name() {
+    return _global_this$1;
+}
+module.exports.default = name;
+_export_es_module(exports);
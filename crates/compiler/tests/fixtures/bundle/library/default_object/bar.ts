@@ -0,0 +1,4 @@
+export const a = 'bar';
+export function b() {
+  return a;
+}
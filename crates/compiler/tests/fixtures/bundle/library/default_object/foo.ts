@@ -0,0 +1,6 @@
+const a = 1;
+function b() {
+  return a;
+}
+
+export default { a, b, c: a + 1 };
@@ -0,0 +1,9 @@
+import foo from './foo';
+import { a as barA, b as barB } from './bar';
+
+export const a = 2;
+export function b() {
+  return a;
+}
+
+console.log(barA, barB(), foo.a, foo.b(), foo.c, a, b());
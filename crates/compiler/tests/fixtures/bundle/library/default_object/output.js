@@ -0,0 +1,26 @@
+//index.mjs:
+ function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}const a = 1;
+function b() {
+    return a;
+}
+var foo_default = {
+    a: a,
+    b: b,
+    c: a + 1
+};
+
+const a$1 = 'bar';
+function b$1() {
+    return a$1;
+}
+
+const a$2 = 2;
+function b$2() {
+    return a$2;
+}
+console.log(a$1, b$1(), foo_default.a, foo_default.b(), foo_default.c, a$2, b$2());
+export { a$2 as a, b$2 as b };
@@ -0,0 +1,3 @@
+//index.js:
+ "use strict";
+console.log('marker');
@@ -0,0 +1,2 @@
+//index.js:
+ console.log('marker');
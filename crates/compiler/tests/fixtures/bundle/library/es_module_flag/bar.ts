@@ -0,0 +1,3 @@
+const util = require('node:util');
+
+export const bar = util.format('%s', 'bar');
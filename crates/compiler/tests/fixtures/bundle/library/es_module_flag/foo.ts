@@ -0,0 +1,3 @@
+const os = require('node:os');
+
+export const foo = os.platform();
@@ -0,0 +1,6 @@
+export * from 'node:fs';
+export * from 'node:path';
+export { foo } from './foo';
+export { bar } from './bar';
+
+export default 'index';
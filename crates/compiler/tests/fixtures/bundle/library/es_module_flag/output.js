@@ -0,0 +1,98 @@
+//index.js:
+ function _export_es_module(exports) {
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+}function _export_star(from, to) {
+    Object.keys(from).forEach(function(k) {
+        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
+            Object.defineProperty(to, k, {
+                enumerable: true,
+                get: function() {
+                    return from[k];
+                }
+            });
+        }
+    });
+    return from;
+}function _interop_require_wildcard(obj, nodeInterop) {
+    if (!nodeInterop && obj && obj.__esModule) return obj;
+    if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
+        default: obj
+    };
+    var cache = _getRequireWildcardCache(nodeInterop);
+    if (cache && cache.has(obj)) return cache.get(obj);
+    var newObj = {
+        __proto__: null
+    };
+    var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
+    for(var key in obj){
+        if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
+            var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;
+            if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
+            else newObj[key] = obj[key];
+        }
+    }
+    newObj.default = obj;
+    if (cache) cache.set(obj, newObj);
+    return newObj;
+}function _getRequireWildcardCache(nodeInterop) {
+    if (typeof WeakMap !== "function") return null;
+    var cacheBabelInterop = new WeakMap();
+    var cacheNodeInterop = new WeakMap();
+    return (_getRequireWildcardCache = function(nodeInterop) {
+        return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
+    })(nodeInterop);
+}function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}var node_fs_ns = _interop_require_wildcard(require("node:fs"));
+var node_path_ns = _interop_require_wildcard(require("node:path"));
+var foo_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    _export_es_module(exports);
+    Object.defineProperty(exports, "foo", {
+        enumerable: true,
+        get: function() {
+            return foo;
+        }
+    });
+    const os = require('node:os');
+    const foo = os.platform();
+});
+var foo = foo_cjs()["foo"];
+
+var bar_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    _export_es_module(exports);
+    Object.defineProperty(exports, "bar", {
+        enumerable: true,
+        get: function() {
+            return bar;
+        }
+    });
+    const util = require('node:util');
+    const bar = util.format('%s', 'bar');
+});
+var bar = bar_cjs()["bar"];
+
+var index_default = 'index';
+module.exports.bar = bar;
+module.exports.foo = foo;
+module.exports.default = index_default;
+_export_star(node_fs_ns, module.exports);
+_export_star(node_path_ns, module.exports);
+_export_es_module(exports);
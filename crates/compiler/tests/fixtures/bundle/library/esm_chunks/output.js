@@ -0,0 +1,24 @@
+//chunks/lazy_bd40.js:
+ import { shared } from "./shared.js";
+var lazy_default = shared('lazy');
+var lazy_ns = {
+    get "default" () {
+        return lazy_default;
+    },
+    __esModule: true
+};
+export default lazy_default;
+
+
+//chunks/shared.js:
+ function shared(name) {
+    return `shared by ${name}`;
+}
+export { shared };
+
+
+//index.js:
+ import { shared } from "./chunks/shared.js";
+const main = ()=>shared('main');
+const lazy = ()=>import("./chunks/lazy_bd40.js");
+export { lazy, main };
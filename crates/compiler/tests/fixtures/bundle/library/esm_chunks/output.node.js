@@ -0,0 +1,24 @@
+//chunks/lazy_bd40.mjs:
+ import { shared } from "./shared.mjs";
+var lazy_default = shared('lazy');
+var lazy_ns = {
+    get "default" () {
+        return lazy_default;
+    },
+    __esModule: true
+};
+export default lazy_default;
+
+
+//chunks/shared.mjs:
+ function shared(name) {
+    return `shared by ${name}`;
+}
+export { shared };
+
+
+//index.mjs:
+ import { shared } from "./chunks/shared.mjs";
+const main = ()=>shared('main');
+const lazy = ()=>import("./chunks/lazy_bd40.mjs");
+export { lazy, main };
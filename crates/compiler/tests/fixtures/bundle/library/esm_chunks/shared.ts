@@ -0,0 +1,3 @@
+export function shared(name: string) {
+  return `shared by ${name}`;
+}
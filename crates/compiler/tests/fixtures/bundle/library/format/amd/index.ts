@@ -0,0 +1,8 @@
+import $ from 'jquery';
+import { format } from './format';
+
+export const version = '1.0.0';
+
+export default function render(selector: string) {
+  return $(selector).text(format(version));
+}
@@ -0,0 +1,23 @@
+//index.js:
+ define(["jquery"], function (jquery_ns) {
+"use strict";
+function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}var $ = _interop_require_default(jquery_ns).default;
+function format(value) {
+    return `v${value}`;
+}
+
+const version = '1.0.0';
+function render(selector) {
+    return $(selector).text(format(version));
+}
+return {
+    version: version,
+    default: render,
+    __esModule: true
+};
+
+});
\ No newline at end of file
@@ -0,0 +1,7 @@
+import { format } from './format';
+
+export const version = '1.0.0';
+
+export default function greet(name: string) {
+  return format(`hello ${name}`);
+}
@@ -0,0 +1,18 @@
+//index.js:
+ var MyLib = (function () {
+"use strict";
+function format(value) {
+    return `${value}!`;
+}
+
+const version = '1.0.0';
+function greet(name) {
+    return format(`hello ${name}`);
+}
+return {
+    version: version,
+    default: greet,
+    __esModule: true
+};
+
+})();
\ No newline at end of file
@@ -0,0 +1,27 @@
+//index.js:
+ var MyLib = (function () {
+"use strict";
+const loadLazy = ()=>import("./lazy_bd40.js");
+return {
+    loadLazy: loadLazy,
+    __esModule: true
+};
+
+})();
+
+//lazy_bd40.js:
+ this["lazy_bd40_js"] = (function () {
+"use strict";
+const lazy = 'lazy';
+var lazy_ns = {
+    get lazy () {
+        return lazy;
+    },
+    __esModule: true
+};
+return {
+    lazy: lazy,
+    __esModule: true
+};
+
+})();
\ No newline at end of file
@@ -0,0 +1,12 @@
+//index.js:
+ var MyLib = (function (lodash_ns, react_ns) {
+"use strict";
+var debounce = lodash_ns.debounce;
+var createElement = react_ns.createElement;
+const render = debounce(()=>createElement('div'), 100);
+return {
+    render: render,
+    __esModule: true
+};
+
+})(this["lodash"], this["React"]);
\ No newline at end of file
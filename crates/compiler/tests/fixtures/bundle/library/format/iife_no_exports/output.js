@@ -0,0 +1,10 @@
+//index.js:
+ (function () {
+"use strict";
+function format(value) {
+    return `${value}!`;
+}
+
+document.title = format('farm');
+
+})();
\ No newline at end of file
@@ -0,0 +1,9 @@
+import $ from 'jquery';
+
+export const version = '1.0.0';
+
+export default function render(selector: string) {
+  return $(selector).text(version);
+}
+
+export const loadLazy = () => import('./lazy');
@@ -0,0 +1,47 @@
+//index.js:
+ System.register(["jquery"], function (exports, context) {
+  var jquery_ns;
+  return {
+    setters: [function (module) { jquery_ns = module; }],
+    execute: function () {
+"use strict";
+function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}var $ = jquery_ns.default;
+const version = '1.0.0';
+function render(selector) {
+    return $(selector).text(version);
+}
+const loadLazy = ()=>context.import("./lazy_bd40.js");
+exports({
+    loadLazy: loadLazy,
+    version: version,
+    default: render
+});
+
+    }
+  };
+});
+
+//lazy_bd40.js:
+ System.register([], function (exports, context) {
+  return {
+    setters: [],
+    execute: function () {
+"use strict";
+const lazy = 'lazy';
+var lazy_ns = {
+    get lazy () {
+        return lazy;
+    },
+    __esModule: true
+};
+exports({
+    lazy: lazy
+});
+
+    }
+  };
+});
\ No newline at end of file
@@ -0,0 +1,7 @@
+import { sum } from './math';
+
+export const version = '1.0.0';
+
+export default function add(a: number, b: number) {
+  return sum(a, b);
+}
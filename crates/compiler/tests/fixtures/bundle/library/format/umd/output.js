@@ -0,0 +1,31 @@
+//index.js:
+ (function (global, factory) {
+  if (typeof exports === 'object' && typeof module !== 'undefined') {
+    factory(module, exports, require);
+  } else if (typeof define === 'function' && define.amd) {
+    define(['module', 'exports', 'require'], factory);
+  } else {
+    global = typeof globalThis !== 'undefined' ? globalThis : global || self;
+    var m = { exports: {} };
+    factory(m, m.exports, function (id) { return global[id]; });
+    global["MyLib"] = m.exports;
+  }
+})(this, function (module, exports, require) {
+"use strict";
+function _export_es_module(exports) {
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+}function sum(a, b) {
+    return a + b;
+}
+
+const version = '1.0.0';
+function add(a, b) {
+    return sum(a, b);
+}
+module.exports.version = version;
+module.exports.default = add;
+_export_es_module(exports);
+
+});
\ No newline at end of file
@@ -0,0 +1,4 @@
+import { createElement } from 'react';
+import { debounce } from 'lodash';
+
+export const render = debounce(() => createElement('div'), 100);
@@ -0,0 +1,27 @@
+//index.js:
+ (function (global, factory) {
+  if (typeof exports === 'object' && typeof module !== 'undefined') {
+    factory(module, exports, require);
+  } else if (typeof define === 'function' && define.amd) {
+    define(['module', 'exports', 'require'], factory);
+  } else {
+    global = typeof globalThis !== 'undefined' ? globalThis : global || self;
+    var m = { exports: {} };
+    factory(m, m.exports, function (id) { return global[{"lodash":"lodash","react":"React"}[id] || id]; });
+    global["MyLib"] = m.exports;
+  }
+})(this, function (module, exports, require) {
+"use strict";
+function _export_es_module(exports) {
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+}var lodash_ns = require("lodash");
+var debounce = lodash_ns.debounce;
+var react_ns = require("react");
+var createElement = react_ns.createElement;
+const render = debounce(()=>createElement('div'), 100);
+module.exports.render = render;
+_export_es_module(exports);
+
+});
\ No newline at end of file
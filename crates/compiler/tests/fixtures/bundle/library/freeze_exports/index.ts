@@ -0,0 +1,5 @@
+export const version = '1.0.0';
+
+export default function greet(name: string) {
+  return `hello ${name}`;
+}
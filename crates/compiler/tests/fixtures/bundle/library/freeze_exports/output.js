@@ -0,0 +1,14 @@
+//index.js:
+ "use strict";
+function _export_es_module(exports) {
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+}const version = '1.0.0';
+function greet(name) {
+    return `hello ${name}`;
+}
+module.exports.version = version;
+module.exports.default = greet;
+_export_es_module(exports);
+Object.freeze(module.exports);
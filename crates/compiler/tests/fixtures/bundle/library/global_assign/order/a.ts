@@ -0,0 +1,2 @@
+globalThis.__order__ = ['a'];
+globalThis.__value__ = 'a';
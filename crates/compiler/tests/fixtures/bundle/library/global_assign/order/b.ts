@@ -0,0 +1,6 @@
+globalThis.__order__.push('b');
+globalThis.__value__ = 'b';
+
+export function readB() {
+  return globalThis.__value__;
+}
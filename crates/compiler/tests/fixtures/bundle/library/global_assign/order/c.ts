@@ -0,0 +1,2 @@
+globalThis.__order__.push('c');
+globalThis.__value__ = 'c';
@@ -0,0 +1,8 @@
+import './a';
+import { readB } from './b';
+import './c';
+
+globalThis.__order__.push('index');
+
+export const order = globalThis.__order__;
+export { readB };
@@ -0,0 +1,42 @@
+//index.mjs:
+ function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}var a_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    globalThis.__order__ = [
+        'a'
+    ];
+    globalThis.__value__ = 'a';
+});
+a_cjs();
+
+globalThis.__order__.push('b');
+globalThis.__value__ = 'b';
+function readB() {
+    return globalThis.__value__;
+}
+
+var c_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    globalThis.__order__.push('c');
+    globalThis.__value__ = 'c';
+});
+c_cjs();
+
+globalThis.__order__.push('index');
+const order = globalThis.__order__;
+export { order, readB };
@@ -0,0 +1 @@
+export * as a from './foo';
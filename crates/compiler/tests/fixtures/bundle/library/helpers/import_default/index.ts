@@ -0,0 +1,3 @@
+import a from './foo';
+
+console.log(a);
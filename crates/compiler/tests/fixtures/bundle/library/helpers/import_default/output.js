@@ -0,0 +1,9 @@
+//index.mjs:
+ function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}var foo_default = 'foo';
+const foo = 'foo';
+
+console.log(foo_default);
@@ -0,0 +1 @@
+console.log('runtime');
\ No newline at end of file
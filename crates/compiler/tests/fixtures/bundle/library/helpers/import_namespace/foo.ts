@@ -0,0 +1,3 @@
+export default 'foo';
+
+export const foo = 'foo';
@@ -0,0 +1,3 @@
+import * as a from './foo';
+
+console.log(a.foo, a.default);
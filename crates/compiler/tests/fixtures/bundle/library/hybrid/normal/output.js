@@ -1,49 +1,13 @@
-//index.js:
+//index.mjs:
  import __farmNodeModule from 'module';
-global.nodeRequire = __farmNodeModule.createRequire(import.meta.url);function _interop_require_default(obj) {
-    return obj && obj.__esModule ? obj : {
-        default: obj
-    };
-}function _export_star(from, to) {
-    Object.keys(from).forEach(function(k) {
-        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
-            Object.defineProperty(to, k, {
-                enumerable: true,
-                get: function() {
-                    return from[k];
-                }
-            });
-        }
+global.nodeRequire = __farmNodeModule.createRequire(import.meta.url);function _export_es_module(exports) {
+    Object.defineProperty(exports, "__esModule", {
+        value: true
     });
-    return from;
-}function _interop_require_wildcard(obj, nodeInterop) {
-    if (!nodeInterop && obj && obj.__esModule) return obj;
-    if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
+}function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
         default: obj
     };
-    var cache = _getRequireWildcardCache(nodeInterop);
-    if (cache && cache.has(obj)) return cache.get(obj);
-    var newObj = {
-        __proto__: null
-    };
-    var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
-    for(var key in obj){
-        if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
-            var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;
-            if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
-            else newObj[key] = obj[key];
-        }
-    }
-    newObj.default = obj;
-    if (cache) cache.set(obj, newObj);
-    return newObj;
-}function _getRequireWildcardCache(nodeInterop) {
-    if (typeof WeakMap !== "function") return null;
-    var cacheBabelInterop = new WeakMap();
-    var cacheNodeInterop = new WeakMap();
-    return (_getRequireWildcardCache = function(nodeInterop) {
-        return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
-    })(nodeInterop);
 }function __commonJs(mod) {
   var module;
   return () => {
@@ -63,9 +27,7 @@ global.nodeRequire = __farmNodeModule.createRequire(import.meta.url);function _i
 }import fs from "node:fs";
 var index_cjs = __commonJs((module, exports)=>{
     "use strict";
-    Object.defineProperty(exports, "__esModule", {
-        value: true
-    });
+    _export_es_module(exports);
     function _export(target, all) {
         for(var name in all)Object.defineProperty(target, name, {
             enumerable: true,
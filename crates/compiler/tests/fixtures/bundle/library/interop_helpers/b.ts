@@ -0,0 +1,7 @@
+import fs from 'node:fs';
+import * as path from 'node:path';
+export * from 'node:os';
+
+export function bExists(file: string) {
+  return fs.existsSync(path.join('b', file));
+}
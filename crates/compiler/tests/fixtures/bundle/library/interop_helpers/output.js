@@ -0,0 +1,108 @@
+//index.js:
+ "use strict";
+function _export_es_module(exports) {
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+}function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}function _export_star(from, to) {
+    Object.keys(from).forEach(function(k) {
+        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
+            Object.defineProperty(to, k, {
+                enumerable: true,
+                get: function() {
+                    return from[k];
+                }
+            });
+        }
+    });
+    return from;
+}function _interop_require_wildcard(obj, nodeInterop) {
+    if (!nodeInterop && obj && obj.__esModule) return obj;
+    if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
+        default: obj
+    };
+    var cache = _getRequireWildcardCache(nodeInterop);
+    if (cache && cache.has(obj)) return cache.get(obj);
+    var newObj = {
+        __proto__: null
+    };
+    var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
+    for(var key in obj){
+        if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
+            var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;
+            if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
+            else newObj[key] = obj[key];
+        }
+    }
+    newObj.default = obj;
+    if (cache) cache.set(obj, newObj);
+    return newObj;
+}function _getRequireWildcardCache(nodeInterop) {
+    if (typeof WeakMap !== "function") return null;
+    var cacheBabelInterop = new WeakMap();
+    var cacheNodeInterop = new WeakMap();
+    return (_getRequireWildcardCache = function(nodeInterop) {
+        return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
+    })(nodeInterop);
+}function _mergeNamespaces(n, m) {
+    m.forEach(function (e) {
+        e && typeof e !== 'string' && !Array.isArray(e) && Object.keys(e).forEach(function (k) {
+            if (k !== 'default' && !(k in n)) {
+                var d = Object.getOwnPropertyDescriptor(e, k);
+                Object.defineProperty(n, k, d.get ? d : {
+                    enumerable: true,
+                    get: function () { return e[k]; }
+                });
+            }
+        });
+    });
+    return Object.freeze(n);
+}var node_fs_ns = require("node:fs");
+var fs = _interop_require_default(node_fs_ns).default;
+var node_os_ns = _interop_require_wildcard(require("node:os"));
+var node_path_ns = _interop_require_wildcard(require("node:path"));
+function aExists(file) {
+    return fs.existsSync(node_path_ns.join('a', file));
+}
+var a_ns = _mergeNamespaces({
+    get aExists () {
+        return aExists;
+    },
+    __esModule: true
+}, [
+    node_os_ns
+]);
+
+function bExists(file) {
+    return fs.existsSync(node_path_ns.join('b', file));
+}
+var b_ns = _mergeNamespaces({
+    get bExists () {
+        return bExists;
+    },
+    __esModule: true
+}, [
+    node_os_ns
+]);
+
+function cExists(file) {
+    return fs.existsSync(node_path_ns.join('c', file));
+}
+var c_ns = _mergeNamespaces({
+    get cExists () {
+        return cExists;
+    },
+    __esModule: true
+}, [
+    node_os_ns
+]);
+
+module.exports.aExists = aExists;
+module.exports.bExists = bExists;
+module.exports.cExists = cExists;
+_export_star(node_os_ns, module.exports);
+_export_es_module(exports);
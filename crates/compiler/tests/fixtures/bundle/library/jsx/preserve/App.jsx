@@ -0,0 +1,13 @@
+import { Button } from './Button.tsx';
+
+export function App({ title }) {
+  return (
+    <div className="app">
+      <h1>{title}</h1>
+      <Button label="ok" />
+      <>
+        <span>fragment</span>
+      </>
+    </div>
+  );
+}
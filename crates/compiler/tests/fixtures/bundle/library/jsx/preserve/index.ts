@@ -0,0 +1,4 @@
+import { App } from './App.jsx';
+import { Button } from './Button.tsx';
+
+export { App, Button };
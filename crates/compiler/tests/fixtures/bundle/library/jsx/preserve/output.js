@@ -0,0 +1,16 @@
+//index.mjs:
+ function Button({ label }) {
+    return <button type="button">{label}</button>;
+}
+
+function App({ title }) {
+    return <div className="app">
+      <h1>{title}</h1>
+      <Button label="ok"/>
+      <>
+        <span>fragment</span>
+      </>
+    </div>;
+}
+
+export { App, Button };
@@ -0,0 +1,4 @@
+import { join } from './legacy';
+
+export const url = import.meta.url;
+export const file = join(import.meta.dirname, 'data.json');
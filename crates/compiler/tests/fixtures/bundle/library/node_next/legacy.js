@@ -0,0 +1,5 @@
+const path = require('node:path');
+
+exports.join = function (...paths) {
+  return path.join(...paths);
+};
@@ -0,0 +1,29 @@
+//index.mjs:
+ import { createRequire } from 'module';
+var require = createRequire(import.meta.url);function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}var legacy_cjs = __commonJs((module, exports)=>{
+    const path = require('node:path');
+    exports.join = function(...paths) {
+        return path.join(...paths);
+    };
+});
+var join = legacy_cjs()["join"];
+
+const url = import.meta.url;
+const file = join(import.meta.dirname, 'data.json');
+export { file, url };
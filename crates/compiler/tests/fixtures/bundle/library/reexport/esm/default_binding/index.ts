@@ -0,0 +1,3 @@
+import foo from './foo';
+
+export default foo;
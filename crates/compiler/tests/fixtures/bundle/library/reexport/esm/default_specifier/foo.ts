@@ -0,0 +1,7 @@
+let foo = 'foo';
+
+export function setFoo(value: string) {
+  foo = value;
+}
+
+export { foo as default };
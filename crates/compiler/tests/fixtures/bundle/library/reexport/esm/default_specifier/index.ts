@@ -0,0 +1 @@
+export { default, setFoo } from './foo';
@@ -0,0 +1,11 @@
+//index.mjs:
+ function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}let foo = 'foo';
+function setFoo(value) {
+    foo = value;
+}
+
+export { foo as default, setFoo };
@@ -0,0 +1,2 @@
+export { default as data } from './json/data.json' with { type: 'json' };
+export { default as pkg } from './json/data.json' assert { type: 'json' };
@@ -0,0 +1,30 @@
+//index.mjs:
+ function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}var data_cjs = __commonJs((module, exports)=>{
+    module.exports = {
+        "name": "farm",
+        "version": "1.0.0"
+    };
+});
+var data_default = _interop_require_default(data_cjs()).default;
+
+export { data_default as data, data_default as pkg };
@@ -1,21 +1,5 @@
-//index.js:
- function _interop_require_default(obj) {
-    return obj && obj.__esModule ? obj : {
-        default: obj
-    };
-}function _export_star(from, to) {
-    Object.keys(from).forEach(function(k) {
-        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
-            Object.defineProperty(to, k, {
-                enumerable: true,
-                get: function() {
-                    return from[k];
-                }
-            });
-        }
-    });
-    return from;
-}function _interop_require_wildcard(obj, nodeInterop) {
+//index.mjs:
+ function _interop_require_wildcard(obj, nodeInterop) {
     if (!nodeInterop && obj && obj.__esModule) return obj;
     if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
         default: obj
@@ -47,9 +31,15 @@
 const foo$1 = 'foo';
 const bar = 'bar';
 var foo_ns = {
-    bar: bar,
-    foo: foo$1,
-    "default": foo_default,
+    get bar () {
+        return bar;
+    },
+    get foo () {
+        return foo$1;
+    },
+    get "default" () {
+        return foo_default;
+    },
     __esModule: true
 };
 
@@ -0,0 +1,5 @@
+export * as a from './x';
+
+import * as a from './x';
+
+console.log(a.b.something);
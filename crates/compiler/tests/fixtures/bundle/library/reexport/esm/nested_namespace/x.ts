@@ -0,0 +1,3 @@
+export * as b from './y';
+
+export const x = 'x';
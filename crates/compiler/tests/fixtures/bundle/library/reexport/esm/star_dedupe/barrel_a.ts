@@ -0,0 +1,2 @@
+export * from './shared';
+export const a = 'a';
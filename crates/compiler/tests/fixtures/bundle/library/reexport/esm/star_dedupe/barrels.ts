@@ -0,0 +1,2 @@
+export * from './barrel_a';
+export * from './barrel_b';
@@ -0,0 +1,4 @@
+import * as barrels from './barrels';
+export * from './barrel_a';
+export * from './barrel_b';
+export { barrels };
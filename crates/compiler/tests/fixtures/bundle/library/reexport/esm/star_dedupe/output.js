@@ -0,0 +1,98 @@
+//index.mjs:
+ function _export_star(from, to) {
+    Object.keys(from).forEach(function(k) {
+        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
+            Object.defineProperty(to, k, {
+                enumerable: true,
+                get: function() {
+                    return from[k];
+                }
+            });
+        }
+    });
+    return from;
+}function _interop_require_wildcard(obj, nodeInterop) {
+    if (!nodeInterop && obj && obj.__esModule) return obj;
+    if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
+        default: obj
+    };
+    var cache = _getRequireWildcardCache(nodeInterop);
+    if (cache && cache.has(obj)) return cache.get(obj);
+    var newObj = {
+        __proto__: null
+    };
+    var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
+    for(var key in obj){
+        if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
+            var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;
+            if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
+            else newObj[key] = obj[key];
+        }
+    }
+    newObj.default = obj;
+    if (cache) cache.set(obj, newObj);
+    return newObj;
+}function _getRequireWildcardCache(nodeInterop) {
+    if (typeof WeakMap !== "function") return null;
+    var cacheBabelInterop = new WeakMap();
+    var cacheNodeInterop = new WeakMap();
+    return (_getRequireWildcardCache = function(nodeInterop) {
+        return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
+    })(nodeInterop);
+}const shared = 'shared';
+const util = ()=>shared;
+var shared_ns = {
+    get shared () {
+        return shared;
+    },
+    get util () {
+        return util;
+    },
+    __esModule: true
+};
+
+const a = 'a';
+var barrel_a_ns = {
+    get a () {
+        return a;
+    },
+    get shared () {
+        return shared;
+    },
+    get util () {
+        return util;
+    },
+    __esModule: true
+};
+
+const b = 'b';
+var barrel_b_ns = {
+    get b () {
+        return b;
+    },
+    get shared () {
+        return shared;
+    },
+    get util () {
+        return util;
+    },
+    __esModule: true
+};
+
+var barrels_ns = {
+    get a () {
+        return a;
+    },
+    get b () {
+        return b;
+    },
+    get shared () {
+        return shared;
+    },
+    get util () {
+        return util;
+    },
+    __esModule: true
+};
+
+export { a, b, barrels_ns as barrels, shared, util };
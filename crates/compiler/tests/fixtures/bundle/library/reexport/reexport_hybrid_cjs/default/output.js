@@ -1,48 +1,12 @@
-//index.js:
- function _interop_require_default(obj) {
-    return obj && obj.__esModule ? obj : {
-        default: obj
-    };
-}function _export_star(from, to) {
-    Object.keys(from).forEach(function(k) {
-        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
-            Object.defineProperty(to, k, {
-                enumerable: true,
-                get: function() {
-                    return from[k];
-                }
-            });
-        }
+//index.mjs:
+ function _export_es_module(exports) {
+    Object.defineProperty(exports, "__esModule", {
+        value: true
     });
-    return from;
-}function _interop_require_wildcard(obj, nodeInterop) {
-    if (!nodeInterop && obj && obj.__esModule) return obj;
-    if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
+}function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
         default: obj
     };
-    var cache = _getRequireWildcardCache(nodeInterop);
-    if (cache && cache.has(obj)) return cache.get(obj);
-    var newObj = {
-        __proto__: null
-    };
-    var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
-    for(var key in obj){
-        if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
-            var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;
-            if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
-            else newObj[key] = obj[key];
-        }
-    }
-    newObj.default = obj;
-    if (cache) cache.set(obj, newObj);
-    return newObj;
-}function _getRequireWildcardCache(nodeInterop) {
-    if (typeof WeakMap !== "function") return null;
-    var cacheBabelInterop = new WeakMap();
-    var cacheNodeInterop = new WeakMap();
-    return (_getRequireWildcardCache = function(nodeInterop) {
-        return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
-    })(nodeInterop);
 }function __commonJs(mod) {
   var module;
   return () => {
@@ -61,9 +25,7 @@
   };
 }var foo_cjs = __commonJs((module, exports)=>{
     "use strict";
-    Object.defineProperty(exports, "__esModule", {
-        value: true
-    });
+    _export_es_module(exports);
     Object.defineProperty(exports, "default", {
         enumerable: true,
         get: function() {
@@ -73,6 +35,6 @@
     module.exports.cjs = true;
     var _default = 'foo';
 });
-var foo_default = foo_cjs()["default"];
+var foo_default = _interop_require_default(foo_cjs()).default;
 
 export { foo_default as default };
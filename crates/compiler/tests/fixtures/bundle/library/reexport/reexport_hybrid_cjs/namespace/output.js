@@ -1,20 +1,8 @@
-//index.js:
- function _interop_require_default(obj) {
-    return obj && obj.__esModule ? obj : {
-        default: obj
-    };
-}function _export_star(from, to) {
-    Object.keys(from).forEach(function(k) {
-        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
-            Object.defineProperty(to, k, {
-                enumerable: true,
-                get: function() {
-                    return from[k];
-                }
-            });
-        }
+//index.mjs:
+ function _export_es_module(exports) {
+    Object.defineProperty(exports, "__esModule", {
+        value: true
     });
-    return from;
 }function _interop_require_wildcard(obj, nodeInterop) {
     if (!nodeInterop && obj && obj.__esModule) return obj;
     if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
@@ -61,9 +49,7 @@
   };
 }var foo_cjs = __commonJs((module, exports)=>{
     "use strict";
-    Object.defineProperty(exports, "__esModule", {
-        value: true
-    });
+    _export_es_module(exports);
     function _export(target, all) {
         for(var name in all)Object.defineProperty(target, name, {
             enumerable: true,
@@ -0,0 +1,4 @@
+'use strict';
+import { sum } from './sum';
+
+export const total = sum(1, 2);
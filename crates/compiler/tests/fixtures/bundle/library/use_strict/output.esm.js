@@ -0,0 +1,7 @@
+//index.mjs:
+ function sum(a, b) {
+    return a + b;
+}
+
+const total = sum(1, 2);
+export { total };
@@ -0,0 +1,13 @@
+//index.js:
+ "use strict";
+function _export_es_module(exports) {
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+}function sum(a, b) {
+    return a + b;
+}
+
+const total = sum(1, 2);
+module.exports.total = total;
+_export_es_module(exports);
@@ -0,0 +1,5 @@
+"use strict";
+
+export function sum(a: number, b: number) {
+  return a + b;
+}
@@ -0,0 +1,3 @@
+import counter from './legacy';
+
+console.log(counter());
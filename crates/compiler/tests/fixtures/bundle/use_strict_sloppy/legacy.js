@@ -0,0 +1,5 @@
+// sloppy mode code, which assigns an implicit global and reads `arguments.callee`
+module.exports = function counter() {
+  count = typeof count === 'number' ? count + 1 : 1;
+  return arguments.callee === counter ? count : -1;
+};
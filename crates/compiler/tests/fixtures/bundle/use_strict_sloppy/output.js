@@ -0,0 +1,30 @@
+//index.js:
+ function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}var legacy_cjs = __commonJs((module, exports)=>{
+    module.exports = function counter() {
+        count = typeof count === 'number' ? count + 1 : 1;
+        return arguments.callee === counter ? count : -1;
+    };
+});
+var counter = _interop_require_default(legacy_cjs()).default;
+
+console.log(counter());
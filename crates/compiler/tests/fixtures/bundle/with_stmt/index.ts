@@ -0,0 +1,3 @@
+import { sum } from './legacy';
+
+console.log(sum({ a: 1, b: 2 }));
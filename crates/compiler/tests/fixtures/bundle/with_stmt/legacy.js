@@ -0,0 +1,5 @@
+exports.sum = function (scope) {
+  with (scope) {
+    return a + b;
+  }
+};
@@ -0,0 +1,2 @@
+export const loadA = () => import('./a');
+export const loadB = () => import('./b');
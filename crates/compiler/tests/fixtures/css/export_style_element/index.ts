@@ -0,0 +1,5 @@
+import style from './index.css';
+
+style.setAttribute('data-theme', 'dark');
+
+export { style };
@@ -0,0 +1,70 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){// module_id: ../../_internal/runtime/index.js.farm-runtime
+var index_js_cjs = __commonJs({
+    "../../_internal/runtime/index.js.farm-runtime": (module, exports)=>{
+        "use strict";
+        console.log('runtime/index.js');
+        window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+    }
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_2544.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"index.css":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    const cssCode = `.app {
+  color: red;
+}
+`;
+    const farmId = 'index.css';
+    const farmOrder = 1;
+    const previousStyle = document.querySelector(`style[data-farm-id="${farmId}"]`);
+    const style = document.createElement('style');
+    style.setAttribute('data-farm-id', farmId);
+    style.setAttribute('data-farm-order', farmOrder);
+    style.innerHTML = cssCode;
+    if (previousStyle) {
+        previousStyle.replaceWith(style);
+    } else {
+        const nextStyle = Array.from(document.querySelectorAll('style[data-farm-order]')).find((s)=>Number(s.getAttribute('data-farm-order')) > farmOrder);
+        if (nextStyle) {
+            nextStyle.parentNode.insertBefore(style, nextStyle);
+        } else {
+            document.head.appendChild(style);
+        }
+    }
+    if (module.meta.hot) {
+        module.meta.hot.accept();
+        module.meta.hot.prune(()=>{
+            style.remove();
+        });
+    }
+    exports.default = style;
+}
+,
+"index.ts":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "style", function() {
+        return module.f(_f_index);
+    });
+    var _f_index = module.i(farmRequire("index.css"));
+    module.f(_f_index).setAttribute('data-theme', 'dark');
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("index.ts");var style=entry.style;export { style };
\ No newline at end of file
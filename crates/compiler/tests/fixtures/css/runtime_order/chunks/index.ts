@@ -0,0 +1,5 @@
+import './a.css';
+
+import('./lazy').then(() => {
+  console.log('lazy loaded');
+});
@@ -0,0 +1,3 @@
+import './b.css';
+
+export const lazy = true;
@@ -0,0 +1,104 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){// module_id: ../../../_internal/runtime/index.js.farm-runtime
+var index_js_cjs = __commonJs({
+    "../../../_internal/runtime/index.js.farm-runtime": (module, exports)=>{
+        "use strict";
+        console.log('runtime/index.js');
+        window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+    }
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_6031.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"a.css":function  (module, exports, farmRequire, farmDynamicRequire) {
+    const cssCode = `.a {
+  color: red;
+}
+`;
+    const farmId = 'a.css';
+    const farmOrder = 1;
+    const previousStyle = document.querySelector(`style[data-farm-id="${farmId}"]`);
+    const style = document.createElement('style');
+    style.setAttribute('data-farm-id', farmId);
+    style.setAttribute('data-farm-order', farmOrder);
+    style.innerHTML = cssCode;
+    if (previousStyle) {
+        previousStyle.replaceWith(style);
+    } else {
+        const nextStyle = Array.from(document.querySelectorAll('style[data-farm-order]')).find((s)=>Number(s.getAttribute('data-farm-order')) > farmOrder);
+        if (nextStyle) {
+            nextStyle.parentNode.insertBefore(style, nextStyle);
+        } else {
+            document.head.appendChild(style);
+        }
+    }
+    if (module.meta.hot) {
+        module.meta.hot.accept();
+        module.meta.hot.prune(()=>{
+            style.remove();
+        });
+    }
+}
+,
+"index.ts":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    farmRequire("a.css");
+    farmDynamicRequire("lazy.ts").then(()=>{
+        console.log('lazy loaded');
+    });
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([{ path: 'lazy_bebd.js', type: 0 }],{ 'lazy.ts': [0] });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("index.ts");
+
+//lazy_bebd.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='lazy_bebd.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b.css":function  (module, exports, farmRequire, farmDynamicRequire) {
+    const cssCode = `.b {
+  color: blue;
+}
+`;
+    const farmId = 'b.css';
+    const farmOrder = 2;
+    const previousStyle = document.querySelector(`style[data-farm-id="${farmId}"]`);
+    const style = document.createElement('style');
+    style.setAttribute('data-farm-id', farmId);
+    style.setAttribute('data-farm-order', farmOrder);
+    style.innerHTML = cssCode;
+    if (previousStyle) {
+        previousStyle.replaceWith(style);
+    } else {
+        const nextStyle = Array.from(document.querySelectorAll('style[data-farm-order]')).find((s)=>Number(s.getAttribute('data-farm-order')) > farmOrder);
+        if (nextStyle) {
+            nextStyle.parentNode.insertBefore(style, nextStyle);
+        } else {
+            document.head.appendChild(style);
+        }
+    }
+    if (module.meta.hot) {
+        module.meta.hot.accept();
+        module.meta.hot.prune(()=>{
+            style.remove();
+        });
+    }
+}
+,
+"lazy.ts":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "lazy", function() {
+        return lazy;
+    });
+    farmRequire("b.css");
+    var lazy = true;
+}
+,});
\ No newline at end of file
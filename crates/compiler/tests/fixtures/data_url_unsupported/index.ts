@@ -0,0 +1,3 @@
+import text from 'data:text/plain,hello';
+
+console.log(text);
@@ -0,0 +1,7 @@
+import namedOnly from './named-only';
+import { default as alsoNamedOnly } from './named-only';
+import withDefault from './with-default';
+import reexportedDefault from './reexport-default';
+import cjs from './cjs';
+
+console.log(namedOnly, alsoNamedOnly, withDefault, reexportedDefault, cjs);
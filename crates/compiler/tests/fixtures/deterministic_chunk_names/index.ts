@@ -0,0 +1,2 @@
+import('./pages/a').then(({ page }) => console.log(page));
+import('./pages/b').then(({ page }) => console.log(page));
@@ -0,0 +1 @@
+import('./widgets/a').then(({ widget }) => console.log(widget));
@@ -0,0 +1 @@
+export const widget = 'widgets/a';
@@ -0,0 +1,4 @@
+export async function loadJquery() {
+  const $ = await import('jquery');
+  return $.find;
+}
@@ -0,0 +1,6 @@
+export async function load() {
+  const { default: main } = await import('some-external');
+  const { helper } = await import("some-external/helper.js");
+
+  return [main, helper];
+}
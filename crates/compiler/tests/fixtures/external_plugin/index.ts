@@ -0,0 +1,3 @@
+import { peer } from 'peer-dep';
+
+console.log(peer);
@@ -0,0 +1,4 @@
+import { shared } from 'shared-dep';
+import { missing } from 'missing-dep';
+
+console.log(shared, missing);
@@ -0,0 +1 @@
+export let count = 0;
@@ -0,0 +1,11 @@
+import { count } from './a';
+
+function increase(count: number) {
+  // shadowed binding, should not be reported
+  count = count + 1;
+  return count;
+}
+
+count = increase(count);
+
+console.log(count);
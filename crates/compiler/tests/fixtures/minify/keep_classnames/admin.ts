@@ -0,0 +1,5 @@
+export class UserService {
+  isAdmin() {
+    return true;
+  }
+}
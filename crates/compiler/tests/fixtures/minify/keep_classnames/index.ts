@@ -0,0 +1,13 @@
+import { UserService as AdminService } from './admin';
+
+class UserService {
+  getUser(id: number) {
+    return { id };
+  }
+}
+
+function createLogger(prefix: string) {
+  return (message: string) => console.log(`${prefix} ${message}`);
+}
+
+export const names = [UserService.name, AdminService.name, createLogger.name];
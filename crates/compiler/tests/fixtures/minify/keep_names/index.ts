@@ -0,0 +1,13 @@
+function createLogger(prefix: string) {
+  return (message: string) => console.log(`${prefix} ${message}`);
+}
+
+class UserService {
+  getUser(id: number) {
+    return { id };
+  }
+}
+
+const logger = createLogger('[user]');
+logger(createLogger.name);
+logger(new UserService().constructor.name);
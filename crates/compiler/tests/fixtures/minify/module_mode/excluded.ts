@@ -0,0 +1,4 @@
+export function excluded(value: number) {
+  const excludedLocalValue = value * 2;
+  return excludedLocalValue + 1;
+}
@@ -0,0 +1,4 @@
+export function included(value: number) {
+  const includedLocalValue = value * 2;
+  return includedLocalValue + 1;
+}
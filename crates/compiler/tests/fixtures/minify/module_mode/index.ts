@@ -0,0 +1,4 @@
+import { included } from './included';
+import { excluded } from './excluded';
+
+console.log(included(1), excluded(2));
@@ -0,0 +1,3 @@
+import b from './b';
+
+console.log('a', b);
@@ -0,0 +1,6 @@
+import './a';
+import b from './b';
+
+console.log(b);
+
+export const loadC = () => import('./c');
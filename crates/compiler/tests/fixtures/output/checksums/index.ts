@@ -0,0 +1,3 @@
+import './index.css';
+
+export const loadLazy = () => import('./lazy');
@@ -0,0 +1,52 @@
+//checksums.txt:
+ e651ef0420b3f951cc87e1879846cf74b8cd32949e737a5a26830fbc7c77cae4  index.js
+dca460be647282a026c57ca2f0a5d6224e32f88f530d4ba020f19fa3fa9538d5  index_337c.css
+b779f9cce0f48df978c4c18755fe807aba470320aecc3f1de03090a95106ea94  lazy_bd40.js
+
+
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "loadLazy", function() {
+        return loadLazy;
+    });
+    "";
+    var loadLazy = ()=>farmDynamicRequire("8bb3fc6d");
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([{ path: 'lazy_bd40.js', type: 0 }],{ '8bb3fc6d': [0] });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");var loadLazy=entry.loadLazy;export { loadLazy };
+
+//index_337c.css:
+ body {
+  color: red;
+}
+
+//lazy_bd40.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='lazy_bd40.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"8bb3fc6d":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "lazy", function() {
+        return lazy;
+    });
+    var lazy = 'lazy';
+}
+,});
\ No newline at end of file
@@ -0,0 +1,5 @@
+const events = require('events');
+
+module.exports.create = function () {
+  return new events.EventEmitter();
+};
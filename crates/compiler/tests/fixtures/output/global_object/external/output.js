@@ -0,0 +1,41 @@
+//index.js:
+ function loadExternalRequire(name) {
+  var _g = (self || {});
+  var m = _g[name];
+  var assign = function() {
+    var args = Array.prototype.slice.call(arguments);
+    var target = args.shift();
+    var hasOwnProperty = Object.hasOwnProperty;
+    for(var i = 0; i < args.length; i ++) {
+      for(var key in args[i]) {
+        if(!hasOwnProperty.call(target, key)) {
+          target[key] = args[i][key];
+        }
+      }
+    }
+    return target;
+  }
+  return m ? m.default && !m.__esModule ? assign({}, m, {__esModule: true}) : (assign({}, m)) : m;
+};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}var index_cjs = __commonJs((module, exports)=>{
+    const events = loadExternalRequire("events");
+    module.exports.create = function() {
+        return new events.EventEmitter();
+    };
+});
+index_cjs();
@@ -0,0 +1,4 @@
+self.onmessage = async (event: MessageEvent<number>) => {
+  const { double } = await import('./lazy');
+  self.postMessage(double(event.data));
+};
@@ -0,0 +1,41 @@
+//index.js:
+ self['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    self['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';self['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    self.onmessage = async (event)=>{
+        const { double } = await farmDynamicRequire("8bb3fc6d");
+        self.postMessage(double(event.data));
+    };
+}
+,});self['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);self['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([{ path: 'lazy_bd40.js', type: 0 }],{ '8bb3fc6d': [0] });var farmModuleSystem = self['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
+
+//lazy_bd40.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='lazy_bd40.js';self['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"8bb3fc6d":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "double", function() {
+        return double;
+    });
+    function double(value) {
+        return value * 2;
+    }
+}
+,});
\ No newline at end of file
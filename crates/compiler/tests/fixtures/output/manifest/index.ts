@@ -0,0 +1,5 @@
+import './index.css';
+import { vendor } from './vendor';
+
+export const loadLazy = () => import('./lazy');
+export { vendor };
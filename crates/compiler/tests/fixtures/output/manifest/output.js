@@ -0,0 +1,86 @@
+//__farm_runtime.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+})());
+
+//checksums.txt:
+ 1630b557b1fdef1223dfec96c53a33bcadfdfb5a901410ed99d08504e7548897  __farm_runtime.js
+7fa38953c59eca9f245d5e8bdd9cc7299a4049e415b08bb314a2ef2215f087c7  index.js
+dca460be647282a026c57ca2f0a5d6224e32f88f530d4ba020f19fa3fa9538d5  index_337c.dca460be.css
+b779f9cce0f48df978c4c18755fe807aba470320aecc3f1de03090a95106ea94  lazy_bd40.b779f9cc.js
+50aa80164a517c8834cdecfa542bb15b3a0b3decf010a674d68b9157cc0d56cf  manifest.json
+7a396c18ad324e36d737ce28446cc0d7e6d957975d3e2baca012d551a92bafff  vendor.7a396c18.js
+
+
+//index.js:
+ import "./__farm_runtime.js";import "./vendor.7a396c18.js";(function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "loadLazy", function() {
+        return loadLazy;
+    });
+    module.o(exports, "vendor", function() {
+        return _f_vendor.vendor;
+    });
+    "";
+    var _f_vendor = farmRequire("acdbb96f");
+    var loadLazy = ()=>farmDynamicRequire("8bb3fc6d");
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources(['vendor.7a396c18.js']);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([{ path: 'lazy_bd40.b779f9cc.js', type: 0 }],{ '8bb3fc6d': [0] });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");var loadLazy=entry.loadLazy;export { loadLazy };var vendor=entry.vendor;export { vendor };
+
+//index_337c.dca460be.css:
+ body {
+  color: red;
+}
+
+//lazy_bd40.b779f9cc.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='lazy_bd40.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"8bb3fc6d":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "lazy", function() {
+        return lazy;
+    });
+    var lazy = 'lazy';
+}
+,});
+
+//manifest.json:
+ {
+  "index.ts": {
+    "name": "index",
+    "file": "index.js",
+    "imports": [
+      "vendor.7a396c18.js"
+    ],
+    "css": [
+      "index_337c.dca460be.css"
+    ]
+  }
+}
+
+//vendor.7a396c18.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='vendor.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"acdbb96f":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "vendor", function() {
+        return vendor;
+    });
+    var vendor = 'vendor';
+}
+,});
\ No newline at end of file
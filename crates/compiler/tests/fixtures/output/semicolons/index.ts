@@ -0,0 +1,14 @@
+const a = 1;
+const b = [a, 2];
+// the next lines would continue the previous statement without a semicolon
+(function () {
+  console.log(a);
+})();
+[a, ...b].forEach((item) => console.log(item));
+`${a}`.split('').forEach((item) => console.log(item));
+
+for (let i = 0; i < b.length; i++) {
+  console.log(b[i]);
+}
+
+export { a, b };
@@ -0,0 +1,48 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict"
+    console.log('runtime/index.js')
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([])
+})
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports)
+    module.o(exports, "a", function() {
+        return a
+    })
+    module.o(exports, "b", function() {
+        return b
+    })
+    const a = 1
+    const b = [
+        a,
+        2
+    ]
+    ;(function() {
+        console.log(a)
+    })()
+    ;[
+        a,
+        ...b
+    ].forEach((item)=>console.log(item))
+    ;`${a}`.split('').forEach((item)=>console.log(item))
+    for(let i = 0; i < b.length; i++){
+        console.log(b[i])
+    }
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");var a=entry.a;export { a };var b=entry.b;export { b };
\ No newline at end of file
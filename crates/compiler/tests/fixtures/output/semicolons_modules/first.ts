@@ -0,0 +1,3 @@
+export const log = (value: string) => console.log(value);
+
+log('first');
@@ -0,0 +1,8 @@
+//index.js:
+ const log = (value)=>console.log(value)
+log('first')
+
+;(function() {
+    log('second')
+})()
+
@@ -0,0 +1,5 @@
+import { log } from './first';
+
+(function () {
+  log('second');
+})();
@@ -0,0 +1,8 @@
+//index.js:
+ /*! farm-lib v1.0.0 | MIT */
+window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};var e,s;e=(e,s)=>{"use strict";console.log("runtime/index.js"),window.__farm_default_namespace__.__farm_module_system__.setPlugins([]);},()=>(s||(s={exports:{}},"function"==typeof e?e(s,s.exports):e[Object.keys(e)[0]](s,s.exports)),s.exports);(function(_){for(var r in _){_[r].__farm_resource_pot__='index_58f5.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function c(c,o,e,f){c._m(o),console.log(e("c83c55f4").greet("farm"));},"c83c55f4":function n(n,t,e,o){function r(n){return`hello ${n}`;}n._m(t),n.o(t,"greet",function(){return r;});},});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
+/* end of farm-lib */
+//# sourceMappingURL=index.js.map
+
+//index.js.map:
+ {"version":3,"sources":["/index.ts","/greet.ts"],"sourcesContent":["import { greet } from './greet';\n\nconsole.log(greet('farm'));\n","export function greet(name: string) {\n  return `hello ${name}`;\n}\n"],"names":[],"mappings":";2fAEA,OAAA,CAAQ,GAAG,CAAC,CAAA,CAAA,CAAA,QAAA,CAAA,CAAA,CAAA,KAAA,CAAM,CAAA,IAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,QAAA,CAAA,CAAA,QAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CCFX,QAAA,CAAS,CAAA,CAAM,CAAY,CAAA,CAChC,MAAO,CAAC,KAAA,CAAM,CAAA,CAAE,CAAA,CAAK,CAAC,CACxB,CAAA,CAAA,CAAA,EAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,KAAA,CAAA,CAAA,QAAA,CAAA,CAAA,CAAA,MAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA,CAAA"}
\ No newline at end of file
@@ -0,0 +1,8 @@
+//index.js:
+ /*! farm-lib v1.0.0 | MIT */
+console.log("hello farm");
+/* end of farm-lib */
+//# sourceMappingURL=index.js.map
+
+//index.js.map:
+ {"version":3,"sources":["index_58f5"],"sourcesContent":["function greet(name) {\n    return `hello ${name}`;\n}\n\nconsole.log(greet('farm'));\n"],"names":[],"mappings":"AAIA,QAAQ,GAAG,CAHA"}
\ No newline at end of file
@@ -0,0 +1,39 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_58f5.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    var _f_greet = farmRequire("c83c55f4");
+    console.log(_f_greet.greet('farm'));
+}
+,
+"c83c55f4":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "greet", function() {
+        return greet;
+    });
+    function greet(name) {
+        return `hello ${name}`;
+    }
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
+//# sourceMappingURL=data:application/json;base64,eyJ2ZXJzaW9uIjozLCJzb3VyY2VzIjpbIi9pbmRleC50cyIsIi9ncmVldC50cyJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBncmVldCB9IGZyb20gJy4vZ3JlZXQnO1xuXG5jb25zb2xlLmxvZyhncmVldCgnZmFybScpKTtcbiIsImV4cG9ydCBmdW5jdGlvbiBncmVldChuYW1lOiBzdHJpbmcpIHtcbiAgcmV0dXJuIGBoZWxsbyAke25hbWV9YDtcbn1cbiJdLCJuYW1lcyI6W10sIm1hcHBpbmdzIjoiOzs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7O0FBRWtCO0FBQUE7QUFBQTtBQUFBO0FBQUE7QUFBQTtBQUFBO0FBQUE7QUNETTtBQUN4QjtBQUFBO0FBQUEifQ==
\ No newline at end of file
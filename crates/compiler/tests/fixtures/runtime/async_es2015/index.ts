@@ -0,0 +1,8 @@
+import { load } from './loader';
+
+async function main() {
+  const value = await load();
+  console.log(value);
+}
+
+main();
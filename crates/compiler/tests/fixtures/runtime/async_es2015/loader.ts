@@ -0,0 +1,3 @@
+export async function load() {
+  return await Promise.resolve('loaded');
+}
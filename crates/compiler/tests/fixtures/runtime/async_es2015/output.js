@@ -0,0 +1,86 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_1c87.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"07b2d251":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "_async_to_generator", ()=>_async_to_generator);
+    module.o(exports, "_", ()=>_async_to_generator);
+    function asyncGeneratorStep(gen, resolve, reject, _next, _throw, key, arg) {
+        try {
+            var info = gen[key](arg);
+            var value = info.value;
+        } catch (error) {
+            reject(error);
+            return;
+        }
+        if (info.done) resolve(value);
+        else Promise.resolve(value).then(_next, _throw);
+    }
+    function _async_to_generator(fn) {
+        return function() {
+            var self = this, args = arguments;
+            return new Promise(function(resolve, reject) {
+                var gen = fn.apply(self, args);
+                function _next(value) {
+                    asyncGeneratorStep(gen, resolve, reject, _next, _throw, 'next', value);
+                }
+                function _throw(err) {
+                    asyncGeneratorStep(gen, resolve, reject, _next, _throw, 'throw', err);
+                }
+                _next(undefined);
+            });
+        };
+    }
+}
+,
+"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    var _f__async_to_generator = farmRequire("07b2d251");
+    var _f_loader = farmRequire("e2818c47");
+    function main() {
+        return _main.apply(this, arguments);
+    }
+    function _main() {
+        _main = _f__async_to_generator._(function*() {
+            const value = yield _f_loader.load();
+            console.log(value);
+        });
+        return _main.apply(this, arguments);
+    }
+    main();
+}
+,
+"e2818c47":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "load", ()=>load);
+    var _f__async_to_generator = farmRequire("07b2d251");
+    function load() {
+        return _load.apply(this, arguments);
+    }
+    function _load() {
+        _load = _f__async_to_generator._(function*() {
+            return yield Promise.resolve('loaded');
+        });
+        return _load.apply(this, arguments);
+    }
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
\ No newline at end of file
@@ -0,0 +1,4 @@
+exports.loaded = false;
+const b = require('./b');
+exports.bLoaded = () => b.loaded;
+exports.loaded = true;
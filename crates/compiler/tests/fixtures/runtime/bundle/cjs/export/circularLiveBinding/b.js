@@ -0,0 +1,4 @@
+exports.loaded = false;
+const a = require('./a');
+exports.aLoaded = () => a.loaded;
+exports.loaded = true;
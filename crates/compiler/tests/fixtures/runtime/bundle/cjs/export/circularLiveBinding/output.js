@@ -0,0 +1,44 @@
+//index.js:
+ import __farmNodeModule from 'node:module';global.nodeRequire = __farmNodeModule.createRequire(import.meta.url);global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}// module_id: b.js.farm-runtime
+var b_js_cjs = __commonJs({
+    "b.js.farm-runtime": (module, exports)=>{
+        exports.loaded = false;
+        const a = a_js_cjs();
+        exports.aLoaded = ()=>a.loaded;
+        exports.loaded = true;
+    }
+});
+
+// module_id: a.js.farm-runtime
+var a_js_cjs = __commonJs({
+    "a.js.farm-runtime": (module, exports)=>{
+        exports.loaded = false;
+        const b = b_js_cjs();
+        exports.bLoaded = ()=>b.loaded;
+        exports.loaded = true;
+    }
+});
+var aLoaded = b_js_cjs()["aLoaded"];
+var bLoaded = a_js_cjs()["bLoaded"], loaded = a_js_cjs()["loaded"];
+
+// module_id: runtime.ts.farm-runtime
+console.log(loaded, bLoaded(), aLoaded());
+global['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+(function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';global['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"index.ts":function  (module, exports, farmRequire, farmDynamicRequire) {}
+,});global['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);global['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = global['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("index.ts");
\ No newline at end of file
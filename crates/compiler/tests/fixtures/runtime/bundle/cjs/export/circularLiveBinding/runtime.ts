@@ -0,0 +1,4 @@
+import { bLoaded, loaded } from './a';
+import { aLoaded } from './b';
+
+console.log(loaded, bLoaded(), aLoaded());
@@ -1,8 +1,8 @@
 //index.js:
- global.nodeRequire = require;global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};function _interop_require_default(obj) {
-    return obj && obj.__esModule ? obj : {
-        default: obj
-    };
+ global.nodeRequire = require;global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};function _export_es_module(exports) {
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
 }function _export_star(from, to) {
     Object.keys(from).forEach(function(k) {
         if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
@@ -62,9 +62,7 @@
 }var node_fs_ns = _interop_require_wildcard(require("node:fs.farm-runtime"));
 var cjsExport_ts_cjs = __commonJs((module, exports)=>{
     "use strict";
-    Object.defineProperty(exports, "__esModule", {
-        value: true
-    });
+    _export_es_module(exports);
     Object.defineProperty(exports, "esmName", {
         enumerable: true,
         get: function() {
@@ -79,7 +77,9 @@ var cjsExport_ts_ns = _interop_require_wildcard(cjsExport_ts_cjs());
 
 const foo = "foo";
 var esmExport_ts_ns = {
-    foo: foo,
+    get foo () {
+        return foo;
+    },
     __esModule: true
 };
 
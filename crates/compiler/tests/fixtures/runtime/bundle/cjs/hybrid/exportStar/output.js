@@ -1,5 +1,9 @@
 //index.js:
- import __farmNodeModule from 'node:module';global.nodeRequire = __farmNodeModule.createRequire(import.meta.url);global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};function _interop_require_default(obj) {
+ import __farmNodeModule from 'node:module';global.nodeRequire = __farmNodeModule.createRequire(import.meta.url);global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};function _export_es_module(exports) {
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+}function _interop_require_default(obj) {
     return obj && obj.__esModule ? obj : {
         default: obj
     };
@@ -86,8 +90,12 @@ var cjsExport_ts_cjs = __commonJs({
 const esmName = 'esmName';
 const esmAge = 18;
 var esmExport_ts_ns = {
-    esmAge: esmAge,
-    esmName: esmName,
+    get esmAge () {
+        return esmAge;
+    },
+    get esmName () {
+        return esmName;
+    },
     __esModule: true
 };
 
@@ -96,9 +104,15 @@ const esmName2 = "esmName";
 const esmAge2 = 18;
 function foo() {}
 var esmExport2_ts_ns = {
-    esmAge2: esmAge2,
-    esmName2: esmName2,
-    "default": foo,
+    get esmAge2 () {
+        return esmAge2;
+    },
+    get esmName2 () {
+        return esmName2;
+    },
+    get "default" () {
+        return foo;
+    },
     __esModule: true
 };
 
@@ -106,9 +120,7 @@ var esmExport2_ts_ns = {
 var cjsExportEsm_ts_cjs = __commonJs({
     "cjsExportEsm.ts.farm-runtime": (module, exports)=>{
         "use strict";
-        Object.defineProperty(exports, "__esModule", {
-            value: true
-        });
+        _export_es_module(exports);
         function _export(target, all) {
             for(var name in all)Object.defineProperty(target, name, {
                 enumerable: true,
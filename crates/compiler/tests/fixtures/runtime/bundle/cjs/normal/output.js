@@ -3,18 +3,6 @@
     return obj && obj.__esModule ? obj : {
         default: obj
     };
-}function _export_star(from, to) {
-    Object.keys(from).forEach(function(k) {
-        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
-            Object.defineProperty(to, k, {
-                enumerable: true,
-                get: function() {
-                    return from[k];
-                }
-            });
-        }
-    });
-    return from;
 }function _interop_require_wildcard(obj, nodeInterop) {
     if (!nodeInterop && obj && obj.__esModule) return obj;
     if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
@@ -67,8 +55,12 @@ var esmExport_ts_default = {
     age: age
 };
 var esmExport_ts_ns = {
-    name: name,
-    "default": esmExport_ts_default,
+    get name () {
+        return name;
+    },
+    get "default" () {
+        return esmExport_ts_default;
+    },
     __esModule: true
 };
 
@@ -103,9 +95,9 @@ var cjsRequire_ts_cjs = __commonJs({
         console.log(esmExport, age);
     }
 });
+cjsRequire_ts_cjs();
 
 // module_id: runtime.ts.farm-runtime
-cjsRequire_ts_cjs();
 global['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
 (function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';global['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"index.ts":function  (module, exports, farmRequire, farmDynamicRequire) {}
 ,});global['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);global['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = global['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("index.ts");
\ No newline at end of file
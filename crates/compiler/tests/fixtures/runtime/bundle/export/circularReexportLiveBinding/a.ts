@@ -0,0 +1,13 @@
+import { y } from './b';
+
+export let x = 'x-initial';
+
+export function setX() {
+  x = 'x-updated';
+}
+
+export function readY() {
+  return y;
+}
+
+export { y as reexportedY } from './b';
@@ -0,0 +1,14 @@
+import { x, setX } from './a';
+
+export let y = 'y-initial';
+
+export function readX() {
+  return x;
+}
+
+export function init() {
+  setX();
+  y = 'y-updated';
+}
+
+export { x as reexportedX } from './a';
@@ -0,0 +1,26 @@
+//index.js:
+ import __farmNodeModule from 'node:module';global.nodeRequire = __farmNodeModule.createRequire(import.meta.url);global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};// module_id: b.ts.farm-runtime
+let y = 'y-initial';
+function readX() {
+    return x;
+}
+function init() {
+    setX();
+    y = 'y-updated';
+}
+
+// module_id: a.ts.farm-runtime
+let x = 'x-initial';
+function setX() {
+    x = 'x-updated';
+}
+function readY() {
+    return y;
+}
+
+// module_id: runtime.ts.farm-runtime
+init();
+console.log(readX(), readY(), x, y);
+global['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+(function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';global['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"index.ts":function  (module, exports, farmRequire, farmDynamicRequire) {}
+,});global['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);global['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = global['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("index.ts");
\ No newline at end of file
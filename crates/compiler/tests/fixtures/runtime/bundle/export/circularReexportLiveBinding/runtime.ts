@@ -0,0 +1,6 @@
+import { readY, reexportedY } from './a';
+import { init, readX, reexportedX } from './b';
+
+init();
+
+console.log(readX(), readY(), reexportedX, reexportedY);
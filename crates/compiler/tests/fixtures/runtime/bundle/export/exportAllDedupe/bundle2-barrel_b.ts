@@ -0,0 +1,2 @@
+export * from './bundle2-shared';
+export const b = 'b';
@@ -0,0 +1,2 @@
+export * from './bundle2-barrel_a';
+export * from './bundle2-barrel_b';
@@ -0,0 +1,79 @@
+//__farm_runtime.js:
+ import __farmNodeModule from 'node:module';global.nodeRequire = __farmNodeModule.createRequire(import.meta.url);global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};function _export_star(from, to) {
+    Object.keys(from).forEach(function(k) {
+        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
+            Object.defineProperty(to, k, {
+                enumerable: true,
+                get: function() {
+                    return from[k];
+                }
+            });
+        }
+    });
+    return from;
+}function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}var runtime_ts_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime');
+    global['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+
+
+//bundle2.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='bundle2.js';global['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"75d40081":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "shared", function() {
+        return shared;
+    });
+    module.o(exports, "util", function() {
+        return util;
+    });
+    var shared = 'shared';
+    var util = ()=>shared;
+}
+,
+"9dbdc62e":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "b", function() {
+        return b;
+    });
+    var _f_bundle2_shared = farmRequire("75d40081");
+    module._e(exports, _f_bundle2_shared);
+    var b = 'b';
+}
+,
+"d7ae42fd":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "a", function() {
+        return a;
+    });
+    var _f_bundle2_shared = farmRequire("75d40081");
+    module._e(exports, _f_bundle2_shared);
+    var a = 'a';
+}
+,});
+
+//index.js:
+ import "./__farm_runtime.js";import "./bundle2.js";(function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';global['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    var _f_bundle2_barrel_a = farmRequire("d7ae42fd");
+    module._e(exports, _f_bundle2_barrel_a);
+    var _f_bundle2_barrel_b = farmRequire("9dbdc62e");
+    module._e(exports, _f_bundle2_barrel_b);
+}
+,});global['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources(['bundle2.js']);global['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = global['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");var a=entry.a;export { a };var shared=entry.shared;export { shared };var util=entry.util;export { util };var b=entry.b;export { b };
\ No newline at end of file
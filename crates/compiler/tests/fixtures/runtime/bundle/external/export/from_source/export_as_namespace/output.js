@@ -1,9 +1,5 @@
 //index.js:
- import __farmNodeModule from 'node:module';global.nodeRequire = __farmNodeModule.createRequire(import.meta.url);global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};function _interop_require_default(obj) {
-    return obj && obj.__esModule ? obj : {
-        default: obj
-    };
-}function _export_star(from, to) {
+ import __farmNodeModule from 'node:module';global.nodeRequire = __farmNodeModule.createRequire(import.meta.url);global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};function _export_star(from, to) {
     Object.keys(from).forEach(function(k) {
         if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
             Object.defineProperty(to, k, {
@@ -78,8 +74,12 @@
 });
 
 var export_ts_ns = _mergeNamespaces({
-    readFile: readFile,
-    readFileSync: readFileSync,
+    get readFile () {
+        return readFile;
+    },
+    get readFileSync () {
+        return readFileSync;
+    },
     __esModule: true
 }, [
     exportCjs_ts_cjs()
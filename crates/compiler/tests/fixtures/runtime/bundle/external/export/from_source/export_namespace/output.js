@@ -1,9 +1,5 @@
 //index.js:
- global.nodeRequire = require;global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};function _interop_require_default(obj) {
-    return obj && obj.__esModule ? obj : {
-        default: obj
-    };
-}function _export_star(from, to) {
+ global.nodeRequire = require;global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};function _export_star(from, to) {
     Object.keys(from).forEach(function(k) {
         if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
             Object.defineProperty(to, k, {
@@ -47,7 +43,9 @@
 var node_fs_ns = _interop_require_wildcard(require("node:fs.farm-runtime"));
 console.log('export namespace');
 var exportNamespace_ts_ns = {
-    "fs": node_fs_ns,
+    get "fs" () {
+        return node_fs_ns;
+    },
     __esModule: true
 };
 
@@ -55,15 +53,23 @@ var exportNamespace_ts_ns = {
 const bundle2A = 'bundle2A';
 const bundle2B = 'bundle2B';
 var bundle2_dep_ts_ns = {
-    bundle2A: bundle2A,
-    bundle2B: bundle2B,
+    get bundle2A () {
+        return bundle2A;
+    },
+    get bundle2B () {
+        return bundle2B;
+    },
     __esModule: true
 };
 
 // module_id: bundle2-index.ts.farm-runtime
 var bundle2_index_ts_ns = {
-    bundle2A: bundle2A,
-    bundle2B: bundle2B,
+    get bundle2A () {
+        return bundle2A;
+    },
+    get bundle2B () {
+        return bundle2B;
+    },
     __esModule: true
 };
 
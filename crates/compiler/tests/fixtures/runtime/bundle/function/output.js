@@ -3,18 +3,6 @@
     return obj && obj.__esModule ? obj : {
         default: obj
     };
-}function _export_star(from, to) {
-    Object.keys(from).forEach(function(k) {
-        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
-            Object.defineProperty(to, k, {
-                enumerable: true,
-                get: function() {
-                    return from[k];
-                }
-            });
-        }
-    });
-    return from;
 }function _interop_require_wildcard(obj, nodeInterop) {
     if (!nodeInterop && obj && obj.__esModule) return obj;
     if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
@@ -79,9 +67,15 @@ var dep_ts_default = {
     removeDom: removeDom
 };
 var dep_ts_ns = {
-    getChartSize: getChartSize,
-    removeDom: removeDom,
-    "default": dep_ts_default,
+    get getChartSize () {
+        return getChartSize;
+    },
+    get removeDom () {
+        return removeDom;
+    },
+    get "default" () {
+        return dep_ts_default;
+    },
     __esModule: true
 };
 
@@ -1,9 +1,5 @@
 //index.js:
- import __farmNodeModule from 'node:module';global.nodeRequire = __farmNodeModule.createRequire(import.meta.url);global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};function _interop_require_default(obj) {
-    return obj && obj.__esModule ? obj : {
-        default: obj
-    };
-}function _export_star(from, to) {
+ import __farmNodeModule from 'node:module';global.nodeRequire = __farmNodeModule.createRequire(import.meta.url);global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};function _export_star(from, to) {
     Object.keys(from).forEach(function(k) {
         if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
             Object.defineProperty(to, k, {
@@ -57,17 +53,27 @@ var dep_ts_default = {
     c: c
 };
 var dep_ts_ns = {
-    a: a,
-    b: b,
-    "default": dep_ts_default,
+    get a () {
+        return a;
+    },
+    get b () {
+        return b;
+    },
+    get "default" () {
+        return dep_ts_default;
+    },
     __esModule: true
 };
 
 
 
 var exportAll_ts_ns = {
-    a: a,
-    b: b,
+    get a () {
+        return a;
+    },
+    get b () {
+        return b;
+    },
     __esModule: true
 };
 
@@ -0,0 +1,7 @@
+import * as counter from './counter';
+
+console.log(counter.count);
+counter.increment();
+console.log(counter.count);
+
+export { counter };
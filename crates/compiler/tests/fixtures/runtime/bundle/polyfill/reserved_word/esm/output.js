@@ -46,8 +46,12 @@
 }const a = 10;
 function foo() {}
 var dep_ts_ns = {
-    a: a,
-    "default": foo,
+    get a () {
+        return a;
+    },
+    get "default" () {
+        return foo;
+    },
     __esModule: true
 };
 
@@ -3,18 +3,6 @@
     return obj && obj.__esModule ? obj : {
         default: obj
     };
-}function _export_star(from, to) {
-    Object.keys(from).forEach(function(k) {
-        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
-            Object.defineProperty(to, k, {
-                enumerable: true,
-                get: function() {
-                    return from[k];
-                }
-            });
-        }
-    });
-    return from;
 }function _interop_require_wildcard(obj, nodeInterop) {
     if (!nodeInterop && obj && obj.__esModule) return obj;
     if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
@@ -54,13 +42,27 @@ var exportNamed_ts_default = {
     renamedC: namedC
 };
 var exportNamed_ts_ns = {
-    namedA: namedA,
-    namedB: namedB,
-    namedC: namedC,
-    renamedA: namedA,
-    renamedB: namedB,
-    renamedC: namedC,
-    "default": exportNamed_ts_default,
+    get namedA () {
+        return namedA;
+    },
+    get namedB () {
+        return namedB;
+    },
+    get namedC () {
+        return namedC;
+    },
+    get renamedA () {
+        return namedA;
+    },
+    get renamedB () {
+        return namedB;
+    },
+    get renamedC () {
+        return namedC;
+    },
+    get "default" () {
+        return exportNamed_ts_default;
+    },
     __esModule: true
 };
 
@@ -74,7 +76,9 @@ function say() {
     console.log('hello');
 }
 var sameNameWithFile_ts_ns = {
-    say: say,
+    get say () {
+        return say;
+    },
     __esModule: true
 };
 
@@ -0,0 +1,4 @@
+import { greet } from './shared';
+
+export const message = greet('main');
+export default greet;
@@ -0,0 +1,83 @@
+//__farm_runtime.js:
+ import __farmNodeModule from 'node:module';global.nodeRequire = __farmNodeModule.createRequire(import.meta.url);global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    global['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+
+
+//__farm_runtime_cjs.js:
+ global.nodeRequire = require;global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    global['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+
+
+//legacy.js:
+ require("./__farm_runtime_cjs.js");require("./main_2c6c.js");(function(_){for(var r in _){_[r].__farm_resource_pot__='file://'+__filename;global['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"e083b4d3":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "message", function() {
+        return message;
+    });
+    var _f_shared = farmRequire("ded636f8");
+    var message = _f_shared.greet('legacy');
+    exports.default = _f_shared.greet;
+}
+,});global['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources(['main_2c6c.js']);global['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = global['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("e083b4d3");module.exports.message = entry.message;module.exports = entry.default || entry;
+
+//main.js:
+ import "./__farm_runtime.js";import "./main_2c6c.js";(function(_){for(var r in _){_[r].__farm_resource_pot__='main_564c.js';global['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"7c4a34c2":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "message", function() {
+        return message;
+    });
+    var _f_shared = farmRequire("ded636f8");
+    var message = _f_shared.greet('main');
+    exports.default = _f_shared.greet;
+}
+,});global['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources(['main_2c6c.js']);global['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = global['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("7c4a34c2");var message=entry.message;export { message };export default entry.default || entry;
+
+//main_2c6c.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='main_2c6c.js';global['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"ded636f8":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "greet", function() {
+        return greet;
+    });
+    function greet(name) {
+        return `hello ${name}`;
+    }
+}
+,});
\ No newline at end of file
@@ -0,0 +1,46 @@
+//farm_runtime_resource.js:
+ function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+})());
+
+//index.html:
+ <!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="UTF-8">
+  <title>Document</title>
+<script nonce="abc123">
+window['__farm_default_namespace__'] = {};
+window['__farm_default_namespace__'] = {
+  __FARM_TARGET_ENV__: 'browser',
+};</script><script src="/farm_runtime_resource.js" nonce="abc123"></script></head>
+<body>
+  
+
+
+<script src="/index_564c.js" data-farm-resource="true" nonce="abc123"></script><script nonce="abc123">window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources(['index_564c.js']);</script><script nonce="abc123">window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });</script><script nonce="abc123">window['__farm_default_namespace__'].__farm_module_system__.setPublicPaths(['/']);</script><script nonce="abc123">window['__farm_default_namespace__'].__farm_module_system__.bootstrap();</script><script nonce="abc123">window['__farm_default_namespace__'].__farm_module_system__.require("7c4a34c2")</script></body></html>
+
+//index_564c.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='index_564c.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"7c4a34c2":function  (module, exports, farmRequire, farmDynamicRequire) {
+    console.log('main');
+}
+,});
\ No newline at end of file
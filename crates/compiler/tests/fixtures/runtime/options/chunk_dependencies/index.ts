@@ -0,0 +1 @@
+export const loadPage = () => import('./page');
@@ -0,0 +1,51 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "loadPage", function() {
+        return loadPage;
+    });
+    var loadPage = ()=>farmDynamicRequire("6c3f8d7d");
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([{ path: 'vendor.js', type: 0 },{ path: 'page_d5e0.js', type: 0, deps: [0] }],{ '6c3f8d7d': [0,1] });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");var loadPage=entry.loadPage;export { loadPage };
+
+//page_d5e0.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='page_d5e0.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"6c3f8d7d":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "page", function() {
+        return page;
+    });
+    var _f_vendor = farmRequire("acdbb96f");
+    var page = `page with ${_f_vendor.vendor}`;
+}
+,});
+
+//vendor.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='vendor.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"acdbb96f":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "vendor", function() {
+        return vendor;
+    });
+    var vendor = 'vendor';
+}
+,});
\ No newline at end of file
@@ -0,0 +1,3 @@
+import { vendor } from './vendor';
+
+export const page = `page with ${vendor}`;
@@ -0,0 +1,3 @@
+const fetchConfig = () => Promise.resolve({ name: 'farm' });
+
+export default await fetchConfig();
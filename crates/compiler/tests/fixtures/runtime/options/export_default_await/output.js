@@ -0,0 +1,43 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_9978.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":async function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    const [_f_config__f] = await Promise.all([
+        farmRequire("edceee38")
+    ]);
+    var _f_config = module.i(_f_config__f);
+    console.log(module.f(_f_config).name);
+}
+,
+"edceee38":async function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    const fetchConfig = ()=>Promise.resolve({
+            name: 'farm'
+        });
+    exports.default = await fetchConfig();
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
\ No newline at end of file
@@ -0,0 +1 @@
+export const moduleId = __farm_module_id__;
@@ -0,0 +1,4 @@
+import { moduleId } from './a';
+import { strictModuleId } from './strict';
+
+console.log(moduleId, strictModuleId, __farm_module_id__);
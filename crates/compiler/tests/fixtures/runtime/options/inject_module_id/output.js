@@ -0,0 +1,48 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){// module_id: ../../../_internal/runtime/index.js.farm-runtime
+var index_js_cjs = __commonJs({
+    "../../../_internal/runtime/index.js.farm-runtime": (module, exports)=>{
+        "use strict";
+        console.log('runtime/index.js');
+        window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+    }
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_12e6.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"a.ts":function  (module, exports, farmRequire, farmDynamicRequire) {
+    var __farm_module_id__ = "a.ts";
+    module._m(exports);
+    module.o(exports, "moduleId", function() {
+        return moduleId;
+    });
+    var moduleId = __farm_module_id__;
+}
+,
+"index.ts":function  (module, exports, farmRequire, farmDynamicRequire) {
+    var __farm_module_id__ = "index.ts";
+    module._m(exports);
+    var _f_a = farmRequire("a.ts");
+    var _f_strict = farmRequire("strict.js");
+    console.log(_f_a.moduleId, _f_strict.strictModuleId, __farm_module_id__);
+}
+,
+"strict.js":function  (module, exports, farmRequire, farmDynamicRequire) {
+    "use strict";
+    var __farm_module_id__ = "strict.js";
+    module.exports.strictModuleId = __farm_module_id__;
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("index.ts");
\ No newline at end of file
@@ -0,0 +1,2 @@
+"use strict";
+module.exports.strictModuleId = __farm_module_id__;
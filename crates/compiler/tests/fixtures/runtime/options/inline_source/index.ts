@@ -0,0 +1 @@
+console.log('entry executed');
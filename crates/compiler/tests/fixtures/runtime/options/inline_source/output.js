@@ -0,0 +1,45 @@
+//index.js:
+ import __farmNodeModule from 'node:module';global.nodeRequire = __farmNodeModule.createRequire(import.meta.url);global['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'node'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}var __farm_inline_runtime___ts_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    var modules = {};
+    global['__farm_default_namespace__'].__farm_module_system__ = {
+        register: function(id, init) {
+            modules[id] = init;
+        },
+        require: function(id) {
+            var module = {
+                exports: {}
+            };
+            modules[id](module, module.exports);
+            return module.exports;
+        },
+        bootstrap: function() {
+            console.log('inline runtime bootstrapped');
+        },
+        setPlugins: function() {},
+        setInitialLoadedResources: function() {},
+        setDynamicModuleResourcesMap: function() {}
+    };
+    global['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+__farm_inline_runtime___ts_cjs();
+(function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';global['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    console.log('entry executed');
+}
+,});global['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);global['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = global['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
\ No newline at end of file
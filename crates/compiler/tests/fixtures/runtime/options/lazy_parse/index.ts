@@ -0,0 +1,5 @@
+import { format } from './rarely-used';
+
+export function report(value: string) {
+  console.log(format(value));
+}
@@ -0,0 +1,37 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_8429.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "report", function() {
+        return report;
+    });
+    var _f_rarely_used = farmRequire("fae97727");
+    function report(value) {
+        console.log(_f_rarely_used.format(value));
+    }
+}
+,
+"fae97727":function  (module, exports, farmRequire, farmDynamicRequire) {
+    return new Function("module", "exports", "farmRequire", "farmDynamicRequire", '"use strict";\nmodule._m(exports);\nmodule.o(exports, "format", function() {\n    return format;\n});\nfunction format(value) {\n    return `[report] ${value}`;\n}\n')(module, exports, farmRequire, farmDynamicRequire);
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");var report=entry.report;export { report };
\ No newline at end of file
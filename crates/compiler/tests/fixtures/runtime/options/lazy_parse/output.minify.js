@@ -0,0 +1,2 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};var e,s;e=(e,s)=>{"use strict";console.log("runtime/index.js"),window.__farm_default_namespace__.__farm_module_system__.setPlugins([]);},()=>(s||(s={exports:{}},"function"==typeof e?e(s,s.exports):e[Object.keys(e)[0]](s,s.exports)),s.exports);(function(_){for(var r in _){_[r].__farm_resource_pot__='index_8429.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function o(o,n,r,t){o._m(n),o.o(n,"report",function(){return c;});var f=r("fae97727");function c(o){console.log(f.format(o));}},"fae97727":function n(n,r,t,o){return new Function("n","r","t","o",'"use strict";function u(n){return`[report] ${n}`;}n._m(r),n.o(r,"format",function(){return u;});')(n,r,t,o);},});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");var report=entry.report;export { report };
\ No newline at end of file
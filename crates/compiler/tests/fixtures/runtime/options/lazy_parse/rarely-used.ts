@@ -0,0 +1,4 @@
+export function format(value: string) {
+  // rarely used, parsed on first require
+  return `[report] ${value}`;
+}
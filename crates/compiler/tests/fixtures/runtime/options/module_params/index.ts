@@ -0,0 +1,11 @@
+import { add } from './math';
+import { config } from './config';
+import legacy from './legacy';
+
+// local variables named the same as the module params
+const r = 'local r';
+const m = 'local m';
+
+export const sum = add(1, legacy.two);
+export const loadLazy = () => import('./lazy');
+export const locals = [r, m, config];
@@ -0,0 +1,2 @@
+module.exports = { two: 2 };
+exports.module = { module };
@@ -0,0 +1,95 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_2193.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"0a846096":function  (m, e, r, dr) {
+    m.exports = {
+        two: 2
+    };
+    e.module = {
+        module: m
+    };
+}
+,
+"86920496":function  (m, e, r, dr) {
+    m._m(e);
+    m.o(e, "add", function() {
+        return add;
+    });
+    function add(a, b) {
+        return a + b;
+    }
+}
+,
+"b5d64806":async function  (m, e, r, dr) {
+    m._m(e);
+    m.o(e, "sum", function() {
+        return sum;
+    });
+    m.o(e, "loadLazy", function() {
+        return loadLazy;
+    });
+    m.o(e, "locals", function() {
+        return locals;
+    });
+    const [_f_math__f, _f_config__f, _f_legacy__f] = await Promise.all([
+        r("86920496"),
+        r("edceee38"),
+        r("0a846096")
+    ]);
+    var _f_math = _f_math__f;
+    var _f_config = _f_config__f;
+    var _f_legacy = m.i(_f_legacy__f);
+    const r1 = 'local r';
+    const m1 = 'local m';
+    var sum = _f_math.add(1, m.f(_f_legacy).two);
+    var loadLazy = ()=>dr("8bb3fc6d");
+    var locals = [
+        r1,
+        m1,
+        _f_config.config
+    ];
+}
+,
+"edceee38":async function  (m, e, r, dr) {
+    m._m(e);
+    m.o(e, "config", function() {
+        return config;
+    });
+    var config = await Promise.resolve({
+        name: 'farm'
+    });
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([{ path: 'lazy_bd40.js', type: 0 }],{ '8bb3fc6d': [0] });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");var sum;entry.then(function(entry){sum=entry.sum;});export { sum };var loadLazy;entry.then(function(entry){loadLazy=entry.loadLazy;});export { loadLazy };var locals;entry.then(function(entry){locals=entry.locals;});export { locals };
+
+//lazy_bd40.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='lazy_bd40.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"8bb3fc6d":function  (m, e, r, dr) {
+    m._m(e);
+    m.o(e, "lazy", function() {
+        return lazy;
+    });
+    var lazy = 'lazy';
+}
+,});
\ No newline at end of file
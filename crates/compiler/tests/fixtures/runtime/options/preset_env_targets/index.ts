@@ -0,0 +1,3 @@
+const config: { theme?: { color?: string } } = { theme: {} };
+
+export const color = config?.theme?.color;
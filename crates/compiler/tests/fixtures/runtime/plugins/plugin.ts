@@ -0,0 +1,6 @@
+export default {
+  name: 'custom-runtime-plugin',
+  bootstrap() {
+    console.log('custom runtime plugin bootstrap');
+  }
+};
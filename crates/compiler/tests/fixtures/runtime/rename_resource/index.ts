@@ -0,0 +1,4 @@
+export async function greet() {
+  const { hello } = await import('./locale');
+  return hello;
+}
@@ -0,0 +1 @@
+export function _(obj) { return obj; }
@@ -0,0 +1,5 @@
+import { _ as interopRequireDefault } from '@swc/helpers/_/_interop_require_default';
+import * as utils from './utils';
+import defaultValue from './utils';
+
+console.log(interopRequireDefault(utils), defaultValue);
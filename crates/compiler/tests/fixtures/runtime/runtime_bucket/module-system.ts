@@ -0,0 +1 @@
+export const moduleSystem: Record<string, unknown> = {};
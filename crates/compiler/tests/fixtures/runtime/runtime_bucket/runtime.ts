@@ -0,0 +1,6 @@
+import { _ as interopRequireDefault } from '@swc/helpers/_/_interop_require_default';
+import { _ as interopRequireWildcard } from '@swc/helpers/_/_interop_require_wildcard';
+import { _ as exportStar } from '@swc/helpers/_/_export_star';
+import { moduleSystem } from './module-system';
+
+moduleSystem.helpers = [interopRequireDefault, interopRequireWildcard, exportStar];
@@ -0,0 +1 @@
+import('./dynamic').then((res) => res.default());
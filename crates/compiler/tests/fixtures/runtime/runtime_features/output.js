@@ -0,0 +1,847 @@
+//about.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};((function() {
+    class Module {
+        id;
+        exports;
+        resource_pot;
+        meta;
+        require;
+        constructor(id, require){
+            this.id = id;
+            this.exports = {};
+            this.meta = {
+                env: {}
+            };
+            this.require = require;
+        }
+        o(to, to_k, get) {
+            Object.defineProperty(to, to_k, {
+                enumerable: true,
+                get
+            });
+        }
+        d(to, to_k, val) {
+            this.o(to, to_k, function() {
+                return val;
+            });
+        }
+        _m(to) {
+            const key = '__esModule';
+            if (to[key]) return;
+            Object.defineProperty(to, key, {
+                value: true
+            });
+        }
+        _e(to, from) {
+            Object.keys(from).forEach(function(k) {
+                if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
+                    Object.defineProperty(to, k, {
+                        value: from[k],
+                        enumerable: true,
+                        configurable: true
+                    });
+                }
+            });
+            return from;
+        }
+        i(obj) {
+            return obj && obj.__esModule ? obj : {
+                default: obj
+            };
+        }
+        _g(nodeInterop) {
+            if (typeof WeakMap !== "function") return null;
+            var cacheBabelInterop = new WeakMap();
+            var cacheNodeInterop = new WeakMap();
+            return (this._g = function(nodeInterop) {
+                return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
+            })(nodeInterop);
+        }
+        w(obj, nodeInterop) {
+            if (!nodeInterop && obj && obj.__esModule) return obj;
+            if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
+                default: obj
+            };
+            var cache = this._g(nodeInterop);
+            if (cache && cache.has(obj)) return cache.get(obj);
+            var newObj = {
+                __proto__: null
+            };
+            var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
+            for(var key$1 in obj){
+                if (key$1 !== "default" && Object.prototype.hasOwnProperty.call(obj, key$1)) {
+                    var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key$1) : null;
+                    if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key$1, desc);
+                    else newObj[key$1] = obj[key$1];
+                }
+            }
+            newObj.default = obj;
+            if (cache) cache.set(obj, newObj);
+            return newObj;
+        }
+        _(to, to_k, from, from_k) {
+            this.d(to, to_k, from[from_k || to_k]);
+        }
+        p(to, val) {
+            for (const key$2 of Object.keys(val)){
+                const newKey = to[key$2];
+                if (newKey && !Object.prototype.hasOwnProperty.call(val, newKey)) {
+                    this.d(val, newKey, val[key$2]);
+                }
+            }
+        }
+        f(v) {
+            if (typeof v.default !== 'undefined') {
+                return v.default;
+            }
+            return v;
+        }
+    }
+    class FarmRuntimePluginContainer {
+        plugins = [];
+        constructor(plugins){
+            this.plugins = plugins;
+        }
+        hookSerial(hookName, ...args) {
+            for (const plugin of this.plugins){
+                const hook = plugin[hookName];
+                if (hook) {
+                    hook.apply(plugin, args);
+                }
+            }
+        }
+        hookBail(hookName, ...args) {
+            for (const plugin$1 of this.plugins){
+                const hook$1 = plugin$1[hookName];
+                if (hook$1) {
+                    const result = hook$1.apply(plugin$1, args);
+                    if (result) {
+                        return result;
+                    }
+                }
+            }
+        }
+    }
+    const __farm_global_this__ = window['__farm_default_namespace__'];
+    const __global_this__ = typeof window !== 'undefined' ? window : typeof global !== 'undefined' ? global : {};
+    const targetEnv = __farm_global_this__.__FARM_TARGET_ENV__ || 'node';
+    const isBrowser = targetEnv === 'browser' && __global_this__.document;
+    class ModuleSystem {
+        modules;
+        cache;
+        externalModules;
+        reRegisterModules;
+        publicPaths;
+        dynamicResources;
+        dynamicModuleResourcesMap;
+        resourceLoader;
+        pluginContainer;
+        targetEnv;
+        constructor(){
+            this.modules = {};
+            this.cache = {};
+            this.publicPaths = [];
+            this.dynamicModuleResourcesMap = {};
+            this.pluginContainer = new FarmRuntimePluginContainer([]);
+            this.targetEnv = targetEnv;
+            this.externalModules = {};
+            this.reRegisterModules = false;
+        }
+        require(moduleId, isCJS = false) {
+            if (this.cache[moduleId]) {
+                const shouldSkip = this.pluginContainer.hookBail("readModuleCache", this.cache[moduleId]);
+                if (!shouldSkip) {
+                    return this.cache[moduleId].exports;
+                }
+            }
+            const initializer = this.modules[moduleId];
+            if (!initializer) {
+                if (this.externalModules[moduleId]) {
+                    const exports = this.externalModules[moduleId];
+                    if (isCJS) {
+                        return exports.default || exports;
+                    }
+                    return exports;
+                }
+                if ((this.targetEnv === "node" || !isBrowser) && nodeRequire) {
+                    const externalModule = nodeRequire(moduleId);
+                    return externalModule;
+                }
+                this.pluginContainer.hookSerial("moduleNotFound", moduleId);
+                console.debug(`[Farm] Module "${moduleId}" is not registered`);
+                return {};
+            }
+            const module = new Module(moduleId, this.require.bind(this));
+            module.resource_pot = initializer.__farm_resource_pot__;
+            this.pluginContainer.hookSerial("moduleCreated", module);
+            this.cache[moduleId] = module;
+            if (!__global_this__.require) {
+                __global_this__.require = this.require.bind(this);
+            }
+            const result$3 = initializer(module, module.exports, this.require.bind(this), this.farmDynamicRequire.bind(this));
+            if (result$3 && result$3 instanceof Promise) {
+                return result$3.then(()=>{
+                    this.pluginContainer.hookSerial("moduleInitialized", module);
+                    return module.exports;
+                });
+            } else {
+                this.pluginContainer.hookSerial("moduleInitialized", module);
+                return module.exports;
+            }
+        }
+        farmDynamicRequire(moduleId) {
+            if (this.modules[moduleId]) {
+                let exports$1;
+                try {
+                    exports$1 = this.require(moduleId);
+                } catch (err) {
+                    return Promise.reject(err);
+                }
+                if (exports$1.__farm_async) {
+                    return exports$1.default;
+                } else {
+                    return Promise.resolve(exports$1);
+                }
+            }
+            return this.loadDynamicResources(moduleId);
+        }
+        getDynamicResources(moduleId) {
+            const indexes = new Set();
+            const visit = (index)=>{
+                if (indexes.has(index)) {
+                    return;
+                }
+                indexes.add(index);
+                this.dynamicResources[index].deps?.forEach(visit);
+            };
+            this.dynamicModuleResourcesMap[moduleId]?.forEach(visit);
+            return Array.from(indexes).map((index)=>this.dynamicResources[index]);
+        }
+        loadDynamicResources(moduleId, force = false) {
+            const resources = this.getDynamicResources(moduleId);
+            if (!resources || resources.length === 0) {
+                throw new Error(`Dynamic imported module "${moduleId}" does not belong to any resource`);
+            }
+            const resourceLoader = this.resourceLoader;
+            if (!resourceLoader) {
+                throw new Error(`Dynamic imported module "${moduleId}" can not be loaded, the resource loader is omitted from the runtime of the entry that doesn't load resources dynamically`);
+            }
+            if (force) {
+                this.clearCache(moduleId);
+            }
+            return Promise.all(resources.map((resource)=>{
+                if (force) {
+                    const resourceLoaded = resourceLoader.isResourceLoaded(resource.path);
+                    resourceLoader.setLoadedResource(resource.path, false);
+                    if (resourceLoaded) {
+                        return resourceLoader.load({
+                            ...resource,
+                            path: `${resource.path}?t=${Date.now()}`
+                        });
+                    }
+                }
+                return resourceLoader.load(resource);
+            })).then(()=>{
+                if (resources.every((resource)=>resource.type !== 0)) {
+                    return;
+                }
+                if (!this.modules[moduleId]) {
+                    throw new Error(`Dynamic imported module "${moduleId}" is not registered.`);
+                }
+                const result$4 = this.require(moduleId);
+                if (result$4.__farm_async) {
+                    return result$4.default;
+                } else {
+                    return result$4;
+                }
+            }).catch((err)=>{
+                console.error(`[Farm] Error loading dynamic module "${moduleId}"`, err);
+                throw err;
+            });
+        }
+        register(moduleId, initializer) {
+            if (this.modules[moduleId] && !this.reRegisterModules) {
+                console.warn(`Module "${moduleId}" has registered! It should not be registered twice`);
+                return;
+            }
+            this.modules[moduleId] = initializer;
+        }
+        update(moduleId, init) {
+            this.modules[moduleId] = init;
+            this.clearCache(moduleId);
+        }
+        delete(moduleId) {
+            if (this.modules[moduleId]) {
+                this.clearCache(moduleId);
+                delete this.modules[moduleId];
+                return true;
+            } else {
+                return false;
+            }
+        }
+        getModuleUrl(moduleId) {
+            const publicPath$1 = this.publicPaths[0] ?? "";
+            if (isBrowser) {
+                const url$1 = `${window.location.protocol}//${window.location.host}${publicPath$1.endsWith("/") ? publicPath$1.slice(0, -1) : publicPath$1}/${this.modules[moduleId].__farm_resource_pot__}`;
+                return url$1;
+            } else {
+                return this.modules[moduleId].__farm_resource_pot__;
+            }
+        }
+        getCache(moduleId) {
+            return this.cache[moduleId];
+        }
+        clearCache(moduleId) {
+            if (this.cache[moduleId]) {
+                delete this.cache[moduleId];
+                return true;
+            } else {
+                return false;
+            }
+        }
+        setInitialLoadedResources(resources) {
+            for (const resource of resources){
+                this.resourceLoader?.setLoadedResource(resource);
+            }
+        }
+        setDynamicModuleResourcesMap(dynamicResources, dynamicModuleResourcesMap) {
+            this.dynamicResources = dynamicResources;
+            this.dynamicModuleResourcesMap = dynamicModuleResourcesMap;
+        }
+        setPublicPaths(publicPaths) {
+            this.publicPaths = publicPaths;
+            if (this.resourceLoader) {
+                this.resourceLoader.publicPaths = this.publicPaths;
+            }
+        }
+        setPlugins(plugins) {
+            this.pluginContainer.plugins = plugins;
+        }
+        addPlugin(plugin) {
+            if (this.pluginContainer.plugins.every((p)=>p.name !== plugin.name)) {
+                this.pluginContainer.plugins.push(plugin);
+            }
+        }
+        removePlugin(pluginName) {
+            this.pluginContainer.plugins = this.pluginContainer.plugins.filter((p)=>p.name !== pluginName);
+        }
+        setExternalModules(externalModules) {
+            Object.assign(this.externalModules, externalModules || {});
+        }
+        bootstrap() {
+            this.pluginContainer.hookSerial("bootstrap", this);
+        }
+    }
+    __farm_global_this__.__farm_module_system__ = (function() {
+        const moduleSystem = new ModuleSystem();
+        return function() {
+            return moduleSystem;
+        };
+    })()();
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+})());
+(function(_){for(var r in _){_[r].__farm_resource_pot__='about_a7d4.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"196eabb1":function  (module, exports, farmRequire, farmDynamicRequire) {
+    console.log('about page');
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("196eabb1");
+
+//dynamic_4632.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='dynamic_4632.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"83bbda17":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "default", function() {
+        return dynamic;
+    });
+    function dynamic() {
+        console.log('dynamic');
+    }
+}
+,});
+
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};((function(){class Module {
+    id;
+    exports;
+    resource_pot;
+    meta;
+    require;
+    constructor(id, require){
+        this.id = id;
+        this.exports = {};
+        this.meta = {
+            env: {}
+        };
+        this.require = require;
+    }
+    o(to, to_k, get) {
+        Object.defineProperty(to, to_k, {
+            enumerable: true,
+            get
+        });
+    }
+    d(to, to_k, val) {
+        this.o(to, to_k, function() {
+            return val;
+        });
+    }
+    _m(to) {
+        const key = '__esModule';
+        if (to[key]) return;
+        Object.defineProperty(to, key, {
+            value: true
+        });
+    }
+    _e(to, from) {
+        Object.keys(from).forEach(function(k) {
+            if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
+                Object.defineProperty(to, k, {
+                    value: from[k],
+                    enumerable: true,
+                    configurable: true
+                });
+            }
+        });
+        return from;
+    }
+    i(obj) {
+        return obj && obj.__esModule ? obj : {
+            default: obj
+        };
+    }
+    _g(nodeInterop) {
+        if (typeof WeakMap !== "function") return null;
+        var cacheBabelInterop = new WeakMap();
+        var cacheNodeInterop = new WeakMap();
+        return (this._g = function(nodeInterop) {
+            return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
+        })(nodeInterop);
+    }
+    w(obj, nodeInterop) {
+        if (!nodeInterop && obj && obj.__esModule) return obj;
+        if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
+            default: obj
+        };
+        var cache = this._g(nodeInterop);
+        if (cache && cache.has(obj)) return cache.get(obj);
+        var newObj = {
+            __proto__: null
+        };
+        var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
+        for(var key$1 in obj){
+            if (key$1 !== "default" && Object.prototype.hasOwnProperty.call(obj, key$1)) {
+                var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key$1) : null;
+                if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key$1, desc);
+                else newObj[key$1] = obj[key$1];
+            }
+        }
+        newObj.default = obj;
+        if (cache) cache.set(obj, newObj);
+        return newObj;
+    }
+    _(to, to_k, from, from_k) {
+        this.d(to, to_k, from[from_k || to_k]);
+    }
+    p(to, val) {
+        for (const key$2 of Object.keys(val)){
+            const newKey = to[key$2];
+            if (newKey && !Object.prototype.hasOwnProperty.call(val, newKey)) {
+                this.d(val, newKey, val[key$2]);
+            }
+        }
+    }
+    f(v) {
+        if (typeof v.default !== 'undefined') {
+            return v.default;
+        }
+        return v;
+    }
+}
+
+class FarmRuntimePluginContainer {
+    plugins = [];
+    constructor(plugins){
+        this.plugins = plugins;
+    }
+    hookSerial(hookName, ...args) {
+        for (const plugin of this.plugins){
+            const hook = plugin[hookName];
+            if (hook) {
+                hook.apply(plugin, args);
+            }
+        }
+    }
+    hookBail(hookName, ...args) {
+        for (const plugin$1 of this.plugins){
+            const hook$1 = plugin$1[hookName];
+            if (hook$1) {
+                const result = hook$1.apply(plugin$1, args);
+                if (result) {
+                    return result;
+                }
+            }
+        }
+    }
+}
+
+const __farm_global_this__ = window['__farm_default_namespace__'];
+const __global_this__ = typeof window !== 'undefined' ? window : typeof global !== 'undefined' ? global : {};
+const chunkLoadTimeout = null;
+const targetEnv = __farm_global_this__.__FARM_TARGET_ENV__ || 'node';
+const isBrowser = targetEnv === 'browser' && __global_this__.document;
+class ResourceLoader {
+    moduleSystem;
+    _loadedResources;
+    _loadingResources;
+    publicPaths;
+    constructor(moduleSystem, publicPaths){
+        this.moduleSystem = moduleSystem;
+        this._loadedResources = {};
+        this._loadingResources = {};
+        this.publicPaths = publicPaths;
+    }
+    load(resource, index = 0) {
+        if (!isBrowser) {
+            const result$1 = this.moduleSystem.pluginContainer.hookBail('loadResource', resource);
+            if (result$1) {
+                return result$1.then((res)=>{
+                    if (!res.success && res.retryWithDefaultResourceLoader) {
+                        if (resource.type === 0) {
+                            return this._loadScript(`./${resource.path}`);
+                        } else if (resource.type === 1) {
+                            return this._loadLink(`./${resource.path}`);
+                        }
+                    } else if (!res.success) {
+                        throw new Error(`[Farm] Failed to load resource: "${resource.path}, type: ${resource.type}". Original Error: ${res.err}`);
+                    }
+                });
+            } else {
+                if (resource.type === 0) {
+                    return this._loadScript(`./${resource.path}`);
+                } else if (resource.type === 1) {
+                    return this._loadLink(`./${resource.path}`);
+                }
+            }
+        }
+        const publicPath = this.publicPaths[index];
+        const url = `${publicPath.endsWith('/') ? publicPath.slice(0, -1) : publicPath}/${resource.path}`;
+        if (this._loadedResources[resource.path]) {
+            return;
+        } else if (this._loadingResources[resource.path]) {
+            return this._loadingResources[resource.path];
+        }
+        const result$2 = this.moduleSystem.pluginContainer.hookBail('loadResource', resource);
+        if (result$2) {
+            return result$2.then((res)=>{
+                if (res.success) {
+                    this.setLoadedResource(resource.path);
+                } else if (res.retryWithDefaultResourceLoader) {
+                    return this._load(url, resource, index);
+                } else {
+                    throw new Error(`[Farm] Failed to load resource: "${resource.path}, type: ${resource.type}". Original Error: ${res.err}`);
+                }
+            });
+        } else {
+            return this._load(url, resource, index);
+        }
+    }
+    setLoadedResource(path, loaded = true) {
+        this._loadedResources[path] = loaded;
+    }
+    isResourceLoaded(path) {
+        return this._loadedResources[path];
+    }
+    _load(url, resource, index) {
+        let promise = Promise.resolve();
+        if (resource.type === 0) {
+            promise = this._loadScript(url);
+        } else if (resource.type === 1) {
+            promise = this._loadLink(url);
+        }
+        if (chunkLoadTimeout > 0) {
+            promise = this._withTimeout(promise, url);
+        }
+        this._loadingResources[resource.path] = promise;
+        promise.then(()=>{
+            this._loadedResources[resource.path] = true;
+            this._loadingResources[resource.path] = null;
+        }).catch((e)=>{
+            console.warn(`[Farm] Failed to load resource "${url}" using publicPath: ${this.publicPaths[index]}`);
+            index++;
+            if (index < this.publicPaths.length) {
+                return this._load(url, resource, index);
+            } else {
+                this._loadingResources[resource.path] = null;
+                throw new Error(`[Farm] Failed to load resource: "${resource.path}, type: ${resource.type}". ${e}`);
+            }
+        });
+        return promise;
+    }
+    _withTimeout(promise, url) {
+        let timer;
+        const timeout = new Promise((_, reject)=>{
+            timer = setTimeout(()=>{
+                reject(new Error(`Loading "${url}" timed out after ${chunkLoadTimeout}ms`));
+            }, chunkLoadTimeout);
+        });
+        return Promise.race([
+            promise,
+            timeout
+        ]).finally(()=>clearTimeout(timer));
+    }
+    _loadScript(path) {
+        if (FARM_RUNTIME_TARGET_ENV !== 'browser') {
+            return import(path);
+        } else {
+            return new Promise((resolve, reject)=>{
+                const script = document.createElement('script');
+                script.src = path;
+                document.body.appendChild(script);
+                script.onload = ()=>{
+                    resolve();
+                };
+                script.onerror = (e)=>{
+                    reject(e);
+                };
+            });
+        }
+    }
+    _loadLink(path) {
+        if (FARM_RUNTIME_TARGET_ENV !== 'browser') {
+            return Promise.resolve();
+        } else {
+            return new Promise((resolve, reject)=>{
+                const link = document.createElement('link');
+                link.rel = 'stylesheet';
+                link.href = path;
+                document.head.appendChild(link);
+                link.onload = ()=>{
+                    resolve();
+                };
+                link.onerror = (e)=>{
+                    reject(e);
+                };
+            });
+        }
+    }
+}
+
+class ModuleSystem {
+    modules;
+    cache;
+    externalModules;
+    reRegisterModules;
+    publicPaths;
+    dynamicResources;
+    dynamicModuleResourcesMap;
+    resourceLoader;
+    pluginContainer;
+    targetEnv;
+    constructor(){
+        this.modules = {};
+        this.cache = {};
+        this.publicPaths = [];
+        this.dynamicModuleResourcesMap = {};
+        if (true) {
+            this.resourceLoader = new ResourceLoader(this, this.publicPaths);
+        }
+        this.pluginContainer = new FarmRuntimePluginContainer([]);
+        this.targetEnv = targetEnv;
+        this.externalModules = {};
+        this.reRegisterModules = false;
+    }
+    require(moduleId, isCJS = false) {
+        if (this.cache[moduleId]) {
+            const shouldSkip = this.pluginContainer.hookBail("readModuleCache", this.cache[moduleId]);
+            if (!shouldSkip) {
+                return this.cache[moduleId].exports;
+            }
+        }
+        const initializer = this.modules[moduleId];
+        if (!initializer) {
+            if (this.externalModules[moduleId]) {
+                const exports = this.externalModules[moduleId];
+                if (isCJS) {
+                    return exports.default || exports;
+                }
+                return exports;
+            }
+            if ((this.targetEnv === "node" || !isBrowser) && nodeRequire) {
+                const externalModule = nodeRequire(moduleId);
+                return externalModule;
+            }
+            this.pluginContainer.hookSerial("moduleNotFound", moduleId);
+            console.debug(`[Farm] Module "${moduleId}" is not registered`);
+            return {};
+        }
+        const module = new Module(moduleId, this.require.bind(this));
+        module.resource_pot = initializer.__farm_resource_pot__;
+        this.pluginContainer.hookSerial("moduleCreated", module);
+        this.cache[moduleId] = module;
+        if (!__global_this__.require) {
+            __global_this__.require = this.require.bind(this);
+        }
+        const result$3 = initializer(module, module.exports, this.require.bind(this), this.farmDynamicRequire.bind(this));
+        if (result$3 && result$3 instanceof Promise) {
+            return result$3.then(()=>{
+                this.pluginContainer.hookSerial("moduleInitialized", module);
+                return module.exports;
+            });
+        } else {
+            this.pluginContainer.hookSerial("moduleInitialized", module);
+            return module.exports;
+        }
+    }
+    farmDynamicRequire(moduleId) {
+        if (this.modules[moduleId]) {
+            let exports$1;
+            try {
+                exports$1 = this.require(moduleId);
+            } catch (err) {
+                return Promise.reject(err);
+            }
+            if (exports$1.__farm_async) {
+                return exports$1.default;
+            } else {
+                return Promise.resolve(exports$1);
+            }
+        }
+        return this.loadDynamicResources(moduleId);
+    }
+    getDynamicResources(moduleId) {
+        const indexes = new Set();
+        const visit = (index)=>{
+            if (indexes.has(index)) {
+                return;
+            }
+            indexes.add(index);
+            this.dynamicResources[index].deps?.forEach(visit);
+        };
+        this.dynamicModuleResourcesMap[moduleId]?.forEach(visit);
+        return Array.from(indexes).map((index)=>this.dynamicResources[index]);
+    }
+    loadDynamicResources(moduleId, force = false) {
+        const resources = this.getDynamicResources(moduleId);
+        if (!resources || resources.length === 0) {
+            throw new Error(`Dynamic imported module "${moduleId}" does not belong to any resource`);
+        }
+        const resourceLoader = this.resourceLoader;
+        if (!resourceLoader) {
+            throw new Error(`Dynamic imported module "${moduleId}" can not be loaded, the resource loader is omitted from the runtime of the entry that doesn't load resources dynamically`);
+        }
+        if (force) {
+            this.clearCache(moduleId);
+        }
+        return Promise.all(resources.map((resource)=>{
+            if (force) {
+                const resourceLoaded = resourceLoader.isResourceLoaded(resource.path);
+                resourceLoader.setLoadedResource(resource.path, false);
+                if (resourceLoaded) {
+                    return resourceLoader.load({
+                        ...resource,
+                        path: `${resource.path}?t=${Date.now()}`
+                    });
+                }
+            }
+            return resourceLoader.load(resource);
+        })).then(()=>{
+            if (resources.every((resource)=>resource.type !== 0)) {
+                return;
+            }
+            if (!this.modules[moduleId]) {
+                throw new Error(`Dynamic imported module "${moduleId}" is not registered.`);
+            }
+            const result$4 = this.require(moduleId);
+            if (result$4.__farm_async) {
+                return result$4.default;
+            } else {
+                return result$4;
+            }
+        }).catch((err)=>{
+            console.error(`[Farm] Error loading dynamic module "${moduleId}"`, err);
+            throw err;
+        });
+    }
+    register(moduleId, initializer) {
+        if (this.modules[moduleId] && !this.reRegisterModules) {
+            console.warn(`Module "${moduleId}" has registered! It should not be registered twice`);
+            return;
+        }
+        this.modules[moduleId] = initializer;
+    }
+    update(moduleId, init) {
+        this.modules[moduleId] = init;
+        this.clearCache(moduleId);
+    }
+    delete(moduleId) {
+        if (this.modules[moduleId]) {
+            this.clearCache(moduleId);
+            delete this.modules[moduleId];
+            return true;
+        } else {
+            return false;
+        }
+    }
+    getModuleUrl(moduleId) {
+        const publicPath$1 = this.publicPaths[0] ?? "";
+        if (isBrowser) {
+            const url$1 = `${window.location.protocol}//${window.location.host}${publicPath$1.endsWith("/") ? publicPath$1.slice(0, -1) : publicPath$1}/${this.modules[moduleId].__farm_resource_pot__}`;
+            return url$1;
+        } else {
+            return this.modules[moduleId].__farm_resource_pot__;
+        }
+    }
+    getCache(moduleId) {
+        return this.cache[moduleId];
+    }
+    clearCache(moduleId) {
+        if (this.cache[moduleId]) {
+            delete this.cache[moduleId];
+            return true;
+        } else {
+            return false;
+        }
+    }
+    setInitialLoadedResources(resources) {
+        for (const resource of resources){
+            this.resourceLoader?.setLoadedResource(resource);
+        }
+    }
+    setDynamicModuleResourcesMap(dynamicResources, dynamicModuleResourcesMap) {
+        this.dynamicResources = dynamicResources;
+        this.dynamicModuleResourcesMap = dynamicModuleResourcesMap;
+    }
+    setPublicPaths(publicPaths) {
+        this.publicPaths = publicPaths;
+        if (this.resourceLoader) {
+            this.resourceLoader.publicPaths = this.publicPaths;
+        }
+    }
+    setPlugins(plugins) {
+        this.pluginContainer.plugins = plugins;
+    }
+    addPlugin(plugin) {
+        if (this.pluginContainer.plugins.every((p)=>p.name !== plugin.name)) {
+            this.pluginContainer.plugins.push(plugin);
+        }
+    }
+    removePlugin(pluginName) {
+        this.pluginContainer.plugins = this.pluginContainer.plugins.filter((p)=>p.name !== pluginName);
+    }
+    setExternalModules(externalModules) {
+        Object.assign(this.externalModules, externalModules || {});
+    }
+    bootstrap() {
+        this.pluginContainer.hookSerial("bootstrap", this);
+    }
+}
+
+__farm_global_this__.__farm_module_system__ = (function() {
+    const moduleSystem = new ModuleSystem();
+    return function() {
+        return moduleSystem;
+    };
+})()();
+window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    farmDynamicRequire("83bbda17").then((res)=>res.default());
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([{ path: 'dynamic_4632.js', type: 0 }],{ '83bbda17': [0] });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
\ No newline at end of file
@@ -1,49 +1,5 @@
 //index.js:
- window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function _interop_require_default(obj) {
-    return obj && obj.__esModule ? obj : {
-        default: obj
-    };
-}function _export_star(from, to) {
-    Object.keys(from).forEach(function(k) {
-        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
-            Object.defineProperty(to, k, {
-                enumerable: true,
-                get: function() {
-                    return from[k];
-                }
-            });
-        }
-    });
-    return from;
-}function _interop_require_wildcard(obj, nodeInterop) {
-    if (!nodeInterop && obj && obj.__esModule) return obj;
-    if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
-        default: obj
-    };
-    var cache = _getRequireWildcardCache(nodeInterop);
-    if (cache && cache.has(obj)) return cache.get(obj);
-    var newObj = {
-        __proto__: null
-    };
-    var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
-    for(var key in obj){
-        if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
-            var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;
-            if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
-            else newObj[key] = obj[key];
-        }
-    }
-    newObj.default = obj;
-    if (cache) cache.set(obj, newObj);
-    return newObj;
-}function _getRequireWildcardCache(nodeInterop) {
-    if (typeof WeakMap !== "function") return null;
-    var cacheBabelInterop = new WeakMap();
-    var cacheNodeInterop = new WeakMap();
-    return (_getRequireWildcardCache = function(nodeInterop) {
-        return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
-    })(nodeInterop);
-}function __commonJs(mod) {
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
   var module;
   return () => {
     if (module) {
@@ -59,15 +15,28 @@
     }
     return module.exports;
   };
-}((function(){var index_js_cjs = __commonJs((module, exports)=>{
-    "use strict";
-    console.log('runtime/index.js');
-    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+}((function(){// module_id: ../../_internal/runtime/index.js.farm-runtime
+var index_js_cjs = __commonJs({
+    "../../_internal/runtime/index.js.farm-runtime": (module, exports)=>{
+        "use strict";
+        console.log('runtime/index.js');
+        window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+    }
 });
-})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_fb79.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"05ee5ec7":function  (module, exports, farmRequire, farmDynamicRequire) {
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_fb79.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"bar.js":function  (module, exports, farmRequire, farmDynamicRequire) {
     module._m(exports);
-    var _f_foo = farmRequire("59ebf907");
-    var _f_bar = farmRequire("e185e932");
+    module.o(exports, "bar", function() {
+        return bar;
+    });
+    function bar() {
+        return 'bar';
+    }
+}
+,
+"dep.ts":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    var _f_foo = farmRequire("foo.js");
+    var _f_bar = farmRequire("bar.js");
     _f_foo.foo();
     _f_bar.bar();
     module.meta.hot.accept([
@@ -76,7 +45,7 @@
     ], ([newFooModule, newBarModule])=>{});
 }
 ,
-"59ebf907":function  (module, exports, farmRequire, farmDynamicRequire) {
+"foo.js":function  (module, exports, farmRequire, farmDynamicRequire) {
     module._m(exports);
     module.o(exports, "foo", function() {
         return foo;
@@ -86,21 +55,15 @@
     }
 }
 ,
-"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+"index.ts":function  (module, exports, farmRequire, farmDynamicRequire) {
     module._m(exports);
-    var _f_foo = farmRequire("59ebf907");
-    farmRequire("05ee5ec7");
+    var _f_foo = farmRequire("foo.js");
+    farmRequire("dep.ts");
     _f_foo.foo();
-    ;
-}
-,
-"e185e932":function  (module, exports, farmRequire, farmDynamicRequire) {
-    module._m(exports);
-    module.o(exports, "bar", function() {
-        return bar;
-    });
-    function bar() {
-        return 'bar';
+    if (module.meta.hot) {
+        module.meta.hot.accept("foo.js", (newFoo)=>{
+            newFoo?.foo();
+        });
     }
 }
-,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
\ No newline at end of file
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("index.ts");
\ No newline at end of file
@@ -0,0 +1,4 @@
+import { platform } from 'browser-pkg';
+import { read } from 'browser-pkg/lib/server';
+
+console.log(platform, read());
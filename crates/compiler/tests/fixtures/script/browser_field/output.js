@@ -0,0 +1,61 @@
+//__farm_runtime.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+})());
+
+//index.js:
+ import "./__farm_runtime.js";import "./index_016e.js";(function(_){for(var r in _){_[r].__farm_resource_pot__='index_646a.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"7d9035da":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module.exports = {};
+}
+,
+"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    var _f_browser_pkg = farmRequire("05de22bf");
+    var _f_server = farmRequire("21f1c0d8");
+    console.log(_f_browser_pkg.platform, _f_server.read());
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources(['index_016e.js']);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
+
+//index_016e.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='index_016e.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"05de22bf":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "platform", function() {
+        return platform;
+    });
+    var platform = 'browser';
+}
+,
+"21f1c0d8":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "read", function() {
+        return read;
+    });
+    var _f_fs = module.i(farmRequire("7d9035da"));
+    function read() {
+        return typeof module.f(_f_fs).readFileSync === 'function' ? 'fs' : 'browser';
+    }
+}
+,});
\ No newline at end of file
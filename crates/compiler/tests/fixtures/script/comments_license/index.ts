@@ -0,0 +1,6 @@
+/*! farm-app v1.0.0 | MIT License */
+
+import { sum } from './math';
+
+// print the sum
+console.log(sum(1, 2));
@@ -0,0 +1,11 @@
+/**
+ * @preserve math utils, copyright farm
+ */
+
+/**
+ * add two numbers
+ */
+export function sum(a: number, b: number) {
+  /* return the sum */
+  return a + b;
+}
@@ -0,0 +1,40 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_326b.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"86920496":/**
+ * @preserve math utils, copyright farm
+ */ function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "sum", function() {
+        return sum;
+    });
+    function sum(a, b) {
+        return a + b;
+    }
+}
+,
+"b5d64806":/*! farm-app v1.0.0 | MIT License */ function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    var _f_math = farmRequire("86920496");
+    console.log(_f_math.sum(1, 2));
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
\ No newline at end of file
@@ -0,0 +1,8 @@
+/*! legal notice: keep me unless comments are disabled */
+/**
+ * @license MIT
+ */
+import { add } from './math';
+
+// line comment
+console.log(add(1, 2)); /* trailing comment */
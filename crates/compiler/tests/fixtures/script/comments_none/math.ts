@@ -0,0 +1,5 @@
+/** add two numbers */
+export function add(a: number, b: number) {
+  // @preserve sum of the numbers
+  return a + b;
+}
@@ -0,0 +1,5 @@
+import answer from 'data:text/javascript,export default 42';
+import { name } from 'data:text/javascript;base64,ZXhwb3J0IGNvbnN0IG5hbWUgPSAnZmFybSc7';
+import config from 'data:application/json,{"debug":%20true}';
+
+console.log(answer, name, config.debug);
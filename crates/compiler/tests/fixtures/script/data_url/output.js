@@ -0,0 +1,53 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_8f4b.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"32ce5084":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    exports.default = 42;
+}
+,
+"39ebafe9":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "name", function() {
+        return name;
+    });
+    var name = 'farm';
+}
+,
+"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    var _f_javascript_export_default_42 = module.i(farmRequire("32ce5084"));
+    var _f_javascript_base64_ZXhwb3J0IGNvbnN0IG5hbWUgPSAnZmFybSc7 = farmRequire("39ebafe9");
+    var _f_json___debug___20true_ = module.i(farmRequire("fcc3d935"));
+    console.log(module.f(_f_javascript_export_default_42), _f_javascript_base64_ZXhwb3J0IGNvbnN0IG5hbWUgPSAnZmFybSc7.name, module.f(_f_json___debug___20true_).debug);
+}
+,
+"fcc3d935":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module.exports = {
+        "debug": true
+    };
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
\ No newline at end of file
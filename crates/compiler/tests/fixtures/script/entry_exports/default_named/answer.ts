@@ -0,0 +1,4 @@
+const answer = 42;
+
+export { answer as default };
+export const question = 'unknown';
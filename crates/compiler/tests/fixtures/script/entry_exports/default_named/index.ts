@@ -0,0 +1,4 @@
+export { default, question } from './answer';
+export function ask() {
+  return 'ask';
+}
@@ -0,0 +1,3 @@
+export { foo as renamedFoo, bar, qux } from './foo';
+export * as ns from './foo';
+export const { x, y: [z] } = { x: 1, y: [2] };
@@ -0,0 +1 @@
+console.log('dev only');
@@ -0,0 +1,16 @@
+export const render = () => 'render';
+
+if (import.meta.hot) {
+  import.meta.hot.accept(() => {
+    console.log('hot updated');
+  });
+  import('./dev-only');
+}
+
+if (import.meta.hot) {
+  console.log('hot enabled');
+} else {
+  console.log('hot disabled');
+}
+
+export const hot = import.meta.hot;
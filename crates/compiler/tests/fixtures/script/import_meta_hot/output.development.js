@@ -0,0 +1,54 @@
+//dev-only_d5d3.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='dev-only_d5d3.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"dev-only.ts":function  (module, exports, farmRequire, farmDynamicRequire) {
+    console.log('dev only');
+}
+,});
+
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){// module_id: ../../_internal/runtime/index.js.farm-runtime
+var index_js_cjs = __commonJs({
+    "../../_internal/runtime/index.js.farm-runtime": (module, exports)=>{
+        "use strict";
+        console.log('runtime/index.js');
+        window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+    }
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"index.ts":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "render", function() {
+        return render;
+    });
+    module.o(exports, "hot", function() {
+        return hot;
+    });
+    var render = ()=>'render';
+    if (module.meta.hot) {
+        module.meta.hot.accept(()=>{
+            console.log('hot updated');
+        });
+        farmDynamicRequire("dev-only.ts");
+    }
+    if (module.meta.hot) {
+        console.log('hot enabled');
+    } else {
+        console.log('hot disabled');
+    }
+    var hot = module.meta.hot;
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([{ path: 'dev-only_d5d3.js', type: 0 }],{ 'dev-only.ts': [0] });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("index.ts");var render=entry.render;export { render };var hot=entry.hot;export { hot };
\ No newline at end of file
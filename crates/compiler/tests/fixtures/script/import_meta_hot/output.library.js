@@ -0,0 +1,6 @@
+//index.mjs:
+ const render = ()=>'render';
+{
+    console.log('hot disabled');
+}const hot = undefined;
+export { hot, render };
@@ -0,0 +1,9 @@
+import { sum } from './sum';
+
+export function run(values: number[]) {
+  if (values.length > 0) {
+    return sum(values);
+  }
+
+  return 0;
+}
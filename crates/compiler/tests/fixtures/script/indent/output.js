@@ -0,0 +1,49 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){// module_id: ../../_internal/runtime/index.js.farm-runtime
+var index_js_cjs = __commonJs({
+    "../../_internal/runtime/index.js.farm-runtime": (module, exports)=>{
+        "use strict";
+        console.log('runtime/index.js');
+        window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+    }
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_21ab.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({
+  "index.ts": function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "run", function() {
+      return run;
+    });
+    var _f_sum = farmRequire("sum.ts");
+    function run(values) {
+      if (values.length > 0) {
+        return _f_sum.sum(values);
+      }
+      return 0;
+    }
+  },
+  "sum.ts": function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "sum", function() {
+      return sum;
+    });
+    function sum(values) {
+      return values.reduce((acc, value)=>acc + value, 0);
+    }
+  },
+});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("index.ts");var run=entry.run;export { run };
\ No newline at end of file
@@ -0,0 +1,3 @@
+export function sum(values: number[]) {
+  return values.reduce((acc, value) => acc + value, 0);
+}
@@ -0,0 +1,16 @@
+export const render = () => 'render';
+
+if (module.meta.hot) {
+  module.meta.hot.accept(() => {
+    console.log('hot updated');
+  });
+  import('./dev-only');
+}
+
+if (module.meta.hot) {
+  console.log('hot enabled');
+} else {
+  console.log('hot disabled');
+}
+
+export const hot = module.meta.hot;
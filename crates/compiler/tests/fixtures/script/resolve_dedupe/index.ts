@@ -0,0 +1,4 @@
+import { version } from 'shared';
+import { libSharedVersion } from 'lib';
+
+console.log(version, libSharedVersion);
@@ -0,0 +1,59 @@
+//__farm_runtime.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+})());
+
+//index.js:
+ import "./__farm_runtime.js";import "./index_4082.js";(function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    var _f_shared = farmRequire("94429251");
+    var _f_lib = farmRequire("d0bdeb3a");
+    console.log(_f_shared.version, _f_lib.libSharedVersion);
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources(['index_4082.js']);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
+
+//index_4082.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='index_4082.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"4224b3ca":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "version", function() {
+        return version;
+    });
+    var version = 'shared@2.0.0';
+}
+,
+"94429251":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "version", function() {
+        return version;
+    });
+    var version = 'shared@1.0.0';
+}
+,
+"d0bdeb3a":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "libSharedVersion", function() {
+        return libSharedVersion;
+    });
+    var _f_shared = farmRequire("4224b3ca");
+    var libSharedVersion = _f_shared.version;
+}
+,});
\ No newline at end of file
@@ -0,0 +1,10 @@
+const delay = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+// @ts-ignore
+await delay(100);
+
+export const data = { loaded: true };
+
+export default function getData() {
+  return data;
+}
@@ -0,0 +1,58 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "loadLazy", function() {
+        return loadLazy;
+    });
+    "";
+    var loadLazy = ()=>farmDynamicRequire("8bb3fc6d");
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([{ path: 'lazy_bd40.js', type: 0 }],{ '8bb3fc6d': [0] });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");var loadLazy=entry.loadLazy;export { loadLazy };
+//# sourceMappingURL=index.js.map
+
+//index.js.map:
+ {"version":3,"sources":["app://index.ts"],"sourcesContent":["import './index.css';\n\nexport const loadLazy = () => import('./lazy');\n"],"names":[],"mappings":";;;;;;;;;;;;;;;;;;;;;;;;;;;;AAE8B;AAAA"}
+
+//index_337c.css:
+ body {
+  color: red;
+}
+/*# sourceMappingURL=index_337c.css.map */
+
+//index_337c.css.map:
+ {"version":3,"sources":["app://index.css"],"sourcesContent":["body {\n  color: red;\n}\n"],"names":[],"mappings":"AAAA;AAAM;AAEN"}
+
+//lazy_bd40.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='lazy_bd40.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"8bb3fc6d":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "lazy", function() {
+        return lazy;
+    });
+    var lazy = 'lazy';
+}
+,});
+//# sourceMappingURL=lazy_bd40.js.map
+
+//lazy_bd40.js.map:
+ {"version":3,"sources":["app://lazy.ts"],"sourcesContent":["export const lazy = 'lazy';\n"],"names":[],"mappings":";;;;;;AAAoB"}
\ No newline at end of file
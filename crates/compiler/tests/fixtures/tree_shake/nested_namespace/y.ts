@@ -0,0 +1 @@
+export const something = 'something';
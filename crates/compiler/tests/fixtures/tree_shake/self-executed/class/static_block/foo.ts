@@ -0,0 +1,20 @@
+export class Registry {
+  static items = [];
+  static {
+    this.items.push('default');
+  }
+}
+
+export class Unused {
+  static count = 0;
+  static {
+    const initial = 1;
+    this.count = initial;
+  }
+}
+
+export class Logger {
+  static {
+    console.log('Logger is defined');
+  }
+}
@@ -0,0 +1,3 @@
+import { Registry } from './foo';
+
+console.log(Registry);
@@ -3,46 +3,6 @@
     return obj && obj.__esModule ? obj : {
         default: obj
     };
-}function _export_star(from, to) {
-    Object.keys(from).forEach(function(k) {
-        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
-            Object.defineProperty(to, k, {
-                enumerable: true,
-                get: function() {
-                    return from[k];
-                }
-            });
-        }
-    });
-    return from;
-}function _interop_require_wildcard(obj, nodeInterop) {
-    if (!nodeInterop && obj && obj.__esModule) return obj;
-    if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
-        default: obj
-    };
-    var cache = _getRequireWildcardCache(nodeInterop);
-    if (cache && cache.has(obj)) return cache.get(obj);
-    var newObj = {
-        __proto__: null
-    };
-    var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
-    for(var key in obj){
-        if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
-            var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;
-            if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
-            else newObj[key] = obj[key];
-        }
-    }
-    newObj.default = obj;
-    if (cache) cache.set(obj, newObj);
-    return newObj;
-}function _getRequireWildcardCache(nodeInterop) {
-    if (typeof WeakMap !== "function") return null;
-    var cacheBabelInterop = new WeakMap();
-    var cacheNodeInterop = new WeakMap();
-    return (_getRequireWildcardCache = function(nodeInterop) {
-        return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
-    })(nodeInterop);
 }function __commonJs(mod) {
   var module;
   return () => {
@@ -83,14 +43,21 @@ var index_js_cjs = __commonJs({
     const cssCode = `
 `;
     const farmId = 'style/a.css';
+    const farmOrder = 3;
     const previousStyle = document.querySelector(`style[data-farm-id="${farmId}"]`);
     const style = document.createElement('style');
     style.setAttribute('data-farm-id', farmId);
+    style.setAttribute('data-farm-order', farmOrder);
     style.innerHTML = cssCode;
     if (previousStyle) {
         previousStyle.replaceWith(style);
     } else {
-        document.head.appendChild(style);
+        const nextStyle = Array.from(document.querySelectorAll('style[data-farm-order]')).find((s)=>Number(s.getAttribute('data-farm-order')) > farmOrder);
+        if (nextStyle) {
+            nextStyle.parentNode.insertBefore(style, nextStyle);
+        } else {
+            document.head.appendChild(style);
+        }
     }
     if (module.meta.hot) {
         module.meta.hot.accept();
@@ -108,14 +75,21 @@ var index_js_cjs = __commonJs({
 }
 `;
     const farmId = 'style/b.css';
+    const farmOrder = 2;
     const previousStyle = document.querySelector(`style[data-farm-id="${farmId}"]`);
     const style = document.createElement('style');
     style.setAttribute('data-farm-id', farmId);
+    style.setAttribute('data-farm-order', farmOrder);
     style.innerHTML = cssCode;
     if (previousStyle) {
         previousStyle.replaceWith(style);
     } else {
-        document.head.appendChild(style);
+        const nextStyle = Array.from(document.querySelectorAll('style[data-farm-order]')).find((s)=>Number(s.getAttribute('data-farm-order')) > farmOrder);
+        if (nextStyle) {
+            nextStyle.parentNode.insertBefore(style, nextStyle);
+        } else {
+            document.head.appendChild(style);
+        }
     }
     if (module.meta.hot) {
         module.meta.hot.accept();
@@ -0,0 +1,5 @@
+export const a = 'a';
+export const b = 'b';
+export function c() {
+  return 'c';
+}
@@ -0,0 +1,3 @@
+import { a } from './dep';
+
+console.log(a);
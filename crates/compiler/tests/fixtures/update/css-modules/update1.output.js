@@ -12,14 +12,21 @@
 }
 `;
     const farmId = 'index.module.css?farm_css_modules';
+    const farmOrder = 2;
     const previousStyle = document.querySelector(`style[data-farm-id="${farmId}"]`);
     const style = document.createElement('style');
     style.setAttribute('data-farm-id', farmId);
+    style.setAttribute('data-farm-order', farmOrder);
     style.innerHTML = cssCode;
     if (previousStyle) {
         previousStyle.replaceWith(style);
     } else {
-        document.head.appendChild(style);
+        const nextStyle = Array.from(document.querySelectorAll('style[data-farm-order]')).find((s)=>Number(s.getAttribute('data-farm-order')) > farmOrder);
+        if (nextStyle) {
+            nextStyle.parentNode.insertBefore(style, nextStyle);
+        } else {
+            document.head.appendChild(style);
+        }
     }
     if (module.meta.hot) {
         module.meta.hot.accept();
@@ -0,0 +1,3 @@
+import './a.css';
+
+console.log('a');
@@ -0,0 +1,3 @@
+import { shared } from 'shared-lib';
+
+console.log(shared('a'));
@@ -1,10 +1,14 @@
 use std::collections::HashMap;
 
+use farmfe_core::{
+  config::{bool_or_obj::BoolOrObj, Mode, TargetEnv},
+  serde_json::{json, Value},
+};
 use farmfe_testing_helpers::fixture;
 
 mod common;
 
-use common::{assert_compiler_result, create_compiler};
+use common::{assert_compiler_result, create_compiler, create_compiler_with_args};
 
 #[test]
 fn minify_script_test() {
@@ -71,3 +75,111 @@ fn minify_html_test() {
     }
   );
 }
+
+#[test]
+fn minify_keep_names_test() {
+  fixture!(
+    "tests/fixtures/minify/keep_names/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compile = |keep_names: bool| {
+        let compiler = create_compiler_with_args(
+          cwd.to_path_buf(),
+          crate_path.clone(),
+          |mut config, plugins| {
+            config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+            config.mode = Mode::Production;
+            config.minify = Box::new(BoolOrObj::Obj(json!({ "keepNames": keep_names })));
+
+            (config, plugins)
+          },
+        );
+        compiler.compile().unwrap();
+
+        let resources_map = compiler.context().resources_map.lock();
+        String::from_utf8_lossy(&resources_map["index.js"].bytes).to_string()
+      };
+
+      let code = compile(true);
+      assert!(code.contains("function createLogger("));
+      assert!(code.contains("class UserService"));
+
+      let code = compile(false);
+      assert!(!code.contains("function createLogger("));
+      assert!(!code.contains("class UserService"));
+    }
+  );
+}
+
+#[test]
+fn minify_keep_classnames_test() {
+  fixture!(
+    "tests/fixtures/minify/keep_classnames/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compile = |minify: Value| {
+        let compiler = create_compiler_with_args(
+          cwd.to_path_buf(),
+          crate_path.clone(),
+          |mut config, plugins| {
+            config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+            config.mode = Mode::Production;
+            config.output.target_env = TargetEnv::Library;
+            config.minify = Box::new(BoolOrObj::Obj(minify));
+
+            (config, plugins)
+          },
+        );
+        compiler.compile().unwrap();
+
+        let resources_map = compiler.context().resources_map.lock();
+        String::from_utf8_lossy(&resources_map["index.js"].bytes).to_string()
+      };
+
+      let code = compile(json!({ "keepClassnames": true }));
+      assert!(code.contains("class UserService{"));
+      // the class renamed to avoid the conflict in the bundle keeps its name
+      assert!(code.contains("class UserService$1{"));
+      assert!(code.contains(
+        "Object.defineProperty(UserService$1,\"name\",{value:\"UserService\",configurable:!0});"
+      ));
+      assert!(!code.contains("createLogger"));
+
+      let code = compile(json!({ "keepFnames": true }));
+      assert!(code.contains("createLogger"));
+      assert!(!code.contains("UserService"));
+    }
+  );
+}
+
+#[test]
+fn minify_module_mode_test() {
+  fixture!(
+    "tests/fixtures/minify/module_mode/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.mode = Mode::Production;
+          config.minify = Box::new(BoolOrObj::Obj(json!({
+            "mode": "minify-module",
+            "include": ["included\\.ts$"]
+          })));
+
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      let resources_map = compiler.context().resources_map.lock();
+      let code = String::from_utf8_lossy(&resources_map["index.js"].bytes).to_string();
+
+      // only the included module is minified before it's merged into the runtime object
+      assert!(!code.contains("includedLocalValue"));
+      assert!(code.contains("const excludedLocalValue=value*2;"));
+    }
+  );
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use farmfe_compiler::RUNTIME_SUFFIX;
+use farmfe_core::{module::ModuleId, plugin::ResolveKind};
+use farmfe_testing_helpers::fixture;
+
+use crate::common::create_compiler_with_args;
+
+mod common;
+
+#[test]
+fn module_graph_snapshot() {
+  fixture!(
+    "tests/fixtures/module_graph_snapshot/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      let snapshot = compiler.module_graph_snapshot();
+
+      // the runtime modules are part of the module graph too
+      let is_runtime = |id: &ModuleId| id.relative_path().ends_with(RUNTIME_SUFFIX);
+
+      assert_eq!(snapshot.entries.get(&"index.ts".into()).unwrap(), "index");
+
+      let edges = snapshot
+        .edges
+        .iter()
+        .filter(|edge| !is_runtime(&edge.from))
+        .map(|edge| {
+          (
+            edge.from.to_string(),
+            edge.to.to_string(),
+            edge.kind.clone(),
+          )
+        })
+        .collect::<Vec<_>>();
+      let edge = |from: &str, to: &str, kind: ResolveKind| (from.to_string(), to.to_string(), kind);
+      assert_eq!(
+        edges,
+        vec![
+          edge("a.ts", "b.ts", ResolveKind::Import),
+          edge("index.ts", "a.ts", ResolveKind::Import),
+          edge("index.ts", "b.ts", ResolveKind::Import),
+          edge("index.ts", "c.ts", ResolveKind::DynamicImport),
+        ]
+      );
+
+      let modules = snapshot
+        .modules
+        .iter()
+        .filter(|module| !is_runtime(&module.id))
+        .collect::<Vec<_>>();
+      // sorted by execution order, the dependencies are executed first
+      assert_eq!(
+        modules
+          .iter()
+          .map(|module| module.id.to_string())
+          .collect::<Vec<_>>(),
+        vec!["b.ts", "a.ts", "c.ts", "index.ts"]
+      );
+      assert!(modules
+        .windows(2)
+        .all(|w| w[0].execution_order < w[1].execution_order));
+
+      let module = |id: &str| modules.iter().find(|m| m.id.to_string() == id).unwrap();
+      assert_eq!(
+        module("b.ts").size,
+        std::fs::metadata(cwd.join("b.ts")).unwrap().len() as usize
+      );
+      // the dynamic imported module is rendered in a separate resource pot
+      assert_eq!(module("a.ts").resource_pot, module("index.ts").resource_pot);
+      assert_ne!(module("c.ts").resource_pot, module("index.ts").resource_pot);
+      assert!(module("c.ts").resource_pot.is_some());
+    }
+  );
+}
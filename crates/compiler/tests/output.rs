@@ -0,0 +1,153 @@
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+use farmfe_core::{
+  config::{Mode, SourcemapConfig, TargetEnv},
+  context::CompilationContext,
+  plugin::Plugin,
+  resource::{resource_pot::ResourcePotId, ResourceType},
+  VERSION,
+};
+use farmfe_testing_helpers::fixture;
+use farmfe_toolkit::sourcemap::SourceMap;
+
+use crate::common::{
+  assert_compiler_result_with_config, create_compiler_with_args, get_fixture_configs,
+  merge_config_from_json, AssertCompilerResultConfig,
+};
+
+mod common;
+
+#[allow(dead_code)]
+#[cfg(test)]
+fn output_test(file: String, crate_path: String) {
+  let file_path_buf = PathBuf::from(file.clone());
+  let create_path_buf = PathBuf::from(crate_path);
+  let cwd = file_path_buf.parent().unwrap();
+  println!("testing test case: {cwd:?}");
+
+  for (config_from_file, output_file) in get_fixture_configs(cwd) {
+    let compiler = create_compiler_with_args(
+      cwd.to_path_buf(),
+      create_path_buf.clone(),
+      |mut config, plugins| {
+        config.input = HashMap::from([("index".to_string(), file.clone())]);
+
+        (merge_config_from_json(config, &config_from_file), plugins)
+      },
+    );
+
+    compiler.compile().unwrap();
+
+    assert_compiler_result_with_config(
+      &compiler,
+      AssertCompilerResultConfig {
+        output_file: Some(output_file),
+        ..Default::default()
+      },
+    );
+  }
+}
+
+farmfe_testing::testing! {"tests/fixtures/output/**/index.ts", output_test}
+
+#[test]
+fn version_comment() {
+  fixture!(
+    "tests/fixtures/output/checksums/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compile = |target_env: TargetEnv, version_comment: bool| {
+        let compiler = create_compiler_with_args(
+          cwd.to_path_buf(),
+          crate_path.clone(),
+          |mut config, plugins| {
+            config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+            config.mode = Mode::Production;
+            config.output.target_env = target_env.clone();
+            config.output.version_comment = version_comment;
+
+            (config, plugins)
+          },
+        );
+        compiler.compile().unwrap();
+
+        let resources_map = compiler.context().resources_map.lock();
+        let mut js_resources = resources_map
+          .values()
+          .filter(|resource| {
+            !resource.emitted && matches!(resource.resource_type, ResourceType::Js)
+          })
+          .map(|resource| String::from_utf8(resource.bytes.clone()).unwrap())
+          .collect::<Vec<_>>();
+        js_resources.sort();
+
+        js_resources
+      };
+
+      let header = format!("/* Farm v{VERSION} (production) */\n");
+
+      for target_env in [TargetEnv::Browser, TargetEnv::Library] {
+        // the entry, which contains the runtime, and the dynamic imported chunk
+        let js_resources = compile(target_env.clone(), true);
+        assert_eq!(js_resources.len(), 2, "target env: {target_env:?}");
+
+        for code in js_resources {
+          assert!(code.starts_with(&header), "target env: {target_env:?}");
+          assert_eq!(code.matches(&header).count(), 1);
+        }
+
+        assert!(compile(target_env, false)
+          .iter()
+          .all(|code| !code.contains("/* Farm v")));
+      }
+    }
+  );
+}
+
+#[test]
+fn transform_sourcemap() {
+  struct SourceRootPlugin;
+
+  impl Plugin for SourceRootPlugin {
+    fn name(&self) -> &str {
+      "source-root"
+    }
+
+    fn transform_sourcemap(
+      &self,
+      source_map: &mut SourceMap,
+      _resource_pot_id: &ResourcePotId,
+      _context: &Arc<CompilationContext>,
+    ) -> farmfe_core::error::Result<Option<()>> {
+      for i in 0..source_map.get_source_count() {
+        let source = source_map.get_source(i).unwrap().to_string();
+        source_map.set_source(i, &format!("app://{}", source.trim_start_matches('/')));
+      }
+
+      Ok(Some(()))
+    }
+  }
+
+  fixture!(
+    "tests/fixtures/transform_sourcemap/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, mut plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.output.target_env = TargetEnv::Browser;
+          config.sourcemap = Box::new(SourcemapConfig::Bool(true));
+          plugins.push(Arc::new(SourceRootPlugin) as _);
+
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      // the maps of the entry, the dynamic imported js and the css point to `app://`, the map of the entry is
+      // transformed once after the runtime is injected
+      assert_compiler_result_with_config(&compiler, AssertCompilerResultConfig::default());
+    }
+  );
+}
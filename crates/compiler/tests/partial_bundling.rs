@@ -1,8 +1,13 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
+use farmfe_core::{
+  config::Mode,
+  context::CompilationContext,
+  plugin::{Plugin, PluginTransformHookParam, PluginTransformHookResult, UpdateType},
+};
 use farmfe_testing_helpers::fixture;
 
-use crate::common::{assert_compiler_result, create_compiler};
+use crate::common::{assert_compiler_result, create_compiler, create_compiler_with_args};
 
 mod common;
 
@@ -27,3 +32,271 @@ fn partial_bundling_test() {
     }
   );
 }
+
+#[test]
+fn fail_on_circular_dependency() {
+  fixture!(
+    "tests/fixtures/circular_dependency/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.fail_on_circular_dependency = true;
+
+          (config, plugins)
+        });
+
+      let err = compiler.compile().unwrap_err();
+
+      assert!(err
+        .to_string()
+        .contains("Circular dependency detected: a.ts -> b.ts -> a.ts"));
+    }
+  );
+}
+
+#[test]
+fn vendor_chunk() {
+  fixture!("tests/fixtures/vendor_chunk/a.ts", |file, crate_path| {
+    let cwd = file.parent().unwrap();
+
+    let compile = |vendor_chunk: bool| {
+      let compiler = create_compiler_with_args(
+        cwd.to_path_buf(),
+        crate_path.clone(),
+        |mut config, plugins| {
+          config.input = HashMap::from([
+            ("a".to_string(), "./a.ts".to_string()),
+            ("b".to_string(), "./b.ts".to_string()),
+          ]);
+          config.partial_bundling.vendor_chunk = vendor_chunk;
+
+          (config, plugins)
+        },
+      );
+      compiler.compile().unwrap();
+
+      let resource_pot_map = compiler.context().resource_pot_map.read();
+      resource_pot_map
+        .resource_pots()
+        .into_iter()
+        .find(|resource_pot| resource_pot.name == "vendor")
+        .map(|resource_pot| {
+          resource_pot
+            .modules()
+            .into_iter()
+            .map(|module_id| module_id.to_string())
+            .collect::<Vec<_>>()
+        })
+    };
+
+    // the shared node_modules dependency is split out of both entries
+    assert_eq!(
+      compile(true),
+      Some(vec!["node_modules/shared-lib/index.js".to_string()])
+    );
+    assert_eq!(compile(false), None);
+  });
+}
+
+#[test]
+fn deterministic_chunk_names() {
+  fixture!(
+    "tests/fixtures/deterministic_chunk_names/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compile = || {
+        let compiler = create_compiler_with_args(
+          cwd.to_path_buf(),
+          crate_path.clone(),
+          |mut config, plugins| {
+            config.input = HashMap::from([
+              ("index".to_string(), "./index.ts".to_string()),
+              ("other".to_string(), "./other.ts".to_string()),
+            ]);
+            config.mode = Mode::Production;
+            config.output.filename = "[resourceName].[contentHash].[ext]".to_string();
+
+            (config, plugins)
+          },
+        );
+        compiler.compile().unwrap();
+
+        let resources_map = compiler.context().resources_map.lock();
+        let mut names = resources_map
+          .values()
+          .map(|resource| {
+            let code = String::from_utf8_lossy(&resource.bytes).to_string();
+            (resource.name.clone(), code)
+          })
+          .collect::<Vec<_>>();
+        names.sort();
+        names
+      };
+
+      let resources = compile();
+      let names = resources
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+
+      for _ in 0..3 {
+        let other_names = compile()
+          .into_iter()
+          .map(|(name, _)| name)
+          .collect::<Vec<_>>();
+        assert_eq!(names, other_names);
+      }
+
+      // the colliding `a` is assigned by the sorted module group ids, not by the order the groups are traversed
+      let name_of = |content: &str| {
+        resources
+          .iter()
+          .find(|(name, code)| name.ends_with(".js") && code.contains(content))
+          .map(|(name, _)| name.clone())
+          .unwrap()
+      };
+      assert!(name_of("pages/a").starts_with("a_"));
+      assert!(name_of("widgets/a").starts_with("widgets_a_"));
+    }
+  );
+}
+
+#[test]
+fn chunk_filename_hash() {
+  /// edits `a.ts` without touching the other modules
+  struct EditPlugin;
+
+  impl Plugin for EditPlugin {
+    fn name(&self) -> &str {
+      "edit"
+    }
+
+    fn transform(
+      &self,
+      param: &PluginTransformHookParam,
+      _context: &Arc<CompilationContext>,
+    ) -> farmfe_core::error::Result<Option<PluginTransformHookResult>> {
+      if !param.resolved_path.ends_with("a.ts") {
+        return Ok(None);
+      }
+
+      Ok(Some(PluginTransformHookResult {
+        content: format!("{}\nexport const edited = true;", param.content),
+        ..Default::default()
+      }))
+    }
+  }
+
+  fixture!(
+    "tests/fixtures/chunk_filename/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compile = |edit: bool| {
+        let compiler = create_compiler_with_args(
+          cwd.to_path_buf(),
+          crate_path.clone(),
+          |mut config, mut plugins| {
+            config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+            config.mode = Mode::Production;
+            config.output.chunk_filename = Some("[name].[hash].[ext]".to_string());
+
+            if edit {
+              plugins.push(Arc::new(EditPlugin) as _);
+            }
+
+            (config, plugins)
+          },
+        );
+        compiler.compile().unwrap();
+
+        let resources_map = compiler.context().resources_map.lock();
+        let name_of = |content: &str| {
+          resources_map
+            .values()
+            .find(|resource| {
+              resource.name.ends_with(".js")
+                && String::from_utf8_lossy(&resource.bytes).contains(content)
+            })
+            .map(|resource| resource.name.clone())
+            .unwrap()
+        };
+
+        (name_of("\"a\""), name_of("\"b\""))
+      };
+
+      let (a, b) = compile(false);
+      let (edited_a, edited_b) = compile(true);
+
+      assert!(
+        b.starts_with("b_") && b.len() == "b_xxxx.xxxxxxxx.js".len(),
+        "{b}"
+      );
+      assert_ne!(a, edited_a);
+      assert_eq!(b, edited_b);
+    }
+  );
+}
+
+#[test]
+fn fail_on_circular_dependency_added_by_update() {
+  fixture!(
+    "tests/fixtures/circular_dependency/update/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.mode = Mode::Development;
+          config.fail_on_circular_dependency = true;
+
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      let update_file = cwd.join("b.ts").to_string_lossy().to_string();
+      let original_ts = std::fs::read_to_string(&update_file).unwrap();
+      std::fs::write(
+        &update_file,
+        format!("import {{ a }} from './a';\n\n{original_ts}\nexport function getA() {{\n  return a;\n}}\n"),
+      )
+      .unwrap();
+
+      let result = compiler.update(
+        vec![(update_file.clone(), UpdateType::Updated)],
+        || {},
+        true,
+        true,
+      );
+      // restore b.ts
+      std::fs::write(&update_file, original_ts).unwrap();
+
+      assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("Circular dependency detected: a.ts -> b.ts -> a.ts"));
+
+      // the failed update is fully patched, removing the cycle updates the context again
+      let result = compiler
+        .update(
+          vec![(update_file.clone(), UpdateType::Updated)],
+          || {},
+          true,
+          true,
+        )
+        .unwrap();
+      assert_eq!(result.updated_module_ids, vec!["b.ts".into()]);
+      assert!(compiler
+        .context()
+        .module_graph
+        .read()
+        .dependencies_ids(&"b.ts".into())
+        .is_empty());
+    }
+  );
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "profile")]
+
+use std::collections::HashMap;
+
+use farmfe_compiler::profile::ProfileCollector;
+use farmfe_core::config::TargetEnv;
+use farmfe_testing_helpers::fixture;
+
+use crate::common::create_compiler_with_args;
+
+mod common;
+
+#[test]
+fn profile_json_trace() {
+  fixture!("tests/fixtures/profile/index.ts", |file, crate_path| {
+    let cwd = file.parent().unwrap();
+
+    let compiler =
+      create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+        config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+        config.output.target_env = TargetEnv::Browser;
+
+        (config, plugins)
+      });
+
+    let collector = ProfileCollector::start();
+    compiler.compile().unwrap();
+    let trace = collector.finish();
+
+    let names = trace["traceEvents"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|event| {
+        assert_eq!(event["ph"], "X");
+        event["name"].as_str().unwrap().to_string()
+      })
+      .collect::<Vec<_>>();
+
+    assert!(names.iter().any(|name| name == "Generate Stage"));
+    assert!(names
+      .iter()
+      .any(|name| name.starts_with("Render resource pot")));
+    assert!(names
+      .iter()
+      .any(|name| name.starts_with("Codegen module") && name.contains("index.ts")));
+  });
+}
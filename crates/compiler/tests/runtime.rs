@@ -1,20 +1,38 @@
-use std::{collections::HashMap, path::PathBuf};
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
-use farmfe_core::config::{
-  bool_or_obj::BoolOrObj, config_regex::ConfigRegex,
-  partial_bundling::PartialBundlingEnforceResourceConfig, Mode, TargetEnv,
+use farmfe_compiler::Compiler;
+use farmfe_core::{
+  config::{
+    bool_or_obj::BoolOrObj,
+    config_regex::ConfigRegex,
+    partial_bundling::PartialBundlingEnforceResourceConfig,
+    persistent_cache::{PersistentCacheConfig, PersistentCacheConfigObj},
+    preset_env::{BrowserslistQuery, PresetEnvConfig, PresetEnvConfigObj},
+    Mode, ModuleFormat, ModuleFormatConfig, RuntimeHtmlInject, RuntimeStandaloneConfig, TargetEnv,
+  },
+  context::CompilationContext,
+  module::{module_graph::ModuleGraph, ModuleId, ModuleType},
+  plugin::{Plugin, PluginFinalizeModuleHookParam},
+  resource::{
+    resource_pot::{ResourcePot, ResourcePotType},
+    Resource, ResourceType,
+  },
+  serde_json::json,
+  swc_common::DUMMY_SP,
+  swc_ecma_ast::{CallExpr, Callee, EsVersion, Expr, ExprStmt, ModuleItem, Stmt},
 };
+use farmfe_plugin_runtime::RUNTIME_SUFFIX;
+use farmfe_testing_helpers::fixture;
 mod common;
 use crate::common::{
-  assert_compiler_result_with_config, create_compiler_with_args, AssertCompilerResultConfig,
+  assert_compiler_result_with_config, create_compiler_with_args, create_config,
+  AssertCompilerResultConfig, WarningsCollector,
 };
 
 #[allow(dead_code)]
 #[cfg(test)]
 fn test(file: String, crate_path: String) {
-  use common::get_config_field;
-
-  use crate::common::try_read_config_from_json;
+  use crate::common::{get_fixture_configs, merge_config_from_json};
 
   let file_path_buf = PathBuf::from(file.clone());
   let create_path_buf = PathBuf::from(crate_path);
@@ -23,69 +41,827 @@ fn test(file: String, crate_path: String) {
 
   let entry_name = "index".to_string();
 
-  let config_entry = cwd.to_path_buf().join("config.json");
   let runtime_entry = cwd.to_path_buf().join("runtime.ts");
 
-  let config_from_file = try_read_config_from_json(config_entry);
+  for (config_from_file, output_file) in get_fixture_configs(cwd) {
+    let compiler = create_compiler_with_args(
+      cwd.to_path_buf(),
+      create_path_buf.clone(),
+      |mut config, plugins| {
+        config.mode = Mode::Production;
+
+        if runtime_entry.is_file() {
+          let runtime_entry = runtime_entry.to_string_lossy().to_string();
+          config.runtime.path = runtime_entry;
+        }
+
+        config.input = HashMap::from_iter(vec![(entry_name.clone(), file.clone())]);
+
+        config.minify = Box::new(BoolOrObj::Bool(false));
+        config.tree_shaking = Box::new(BoolOrObj::Bool(false));
+
+        config.external = vec![ConfigRegex::new("(^node:.*)"), ConfigRegex::new("^fs$")];
+        config.output.target_env = TargetEnv::Node;
+        // config.output.format = ModuleFormat::CommonJs;
+
+        // TODO: multiple bundle
+        config.partial_bundling.enforce_resources = vec![PartialBundlingEnforceResourceConfig {
+          test: vec![ConfigRegex::new("^bundle2.*")],
+          name: "bundle2".to_string(),
+        }];
+
+        (merge_config_from_json(config, &config_from_file), plugins)
+      },
+    );
+
+    compiler.compile().unwrap();
+
+    assert_compiler_result_with_config(
+      &compiler,
+      AssertCompilerResultConfig {
+        entry_name: Some(entry_name.clone()),
+        ignore_emitted_field: false,
+        output_file: Some(output_file),
+      },
+    );
+  }
+}
+
+farmfe_testing::testing! {"tests/fixtures/runtime/bundle/**/index.ts", test}
+// farmfe_testing::testing! {"tests/fixtures/runtime/bundle/cjs/export/entryExportStar/**/index.ts", test}
+// farmfe_testing::testing! {"tests/fixtures/runtime/bundle/external/import/namespace/**/index.ts", test}
+
+#[allow(dead_code)]
+#[cfg(test)]
+fn runtime_options_test(file: String, crate_path: String) {
+  use crate::common::{get_fixture_configs, merge_config_from_json};
+
+  let file_path_buf = PathBuf::from(file.clone());
+  let create_path_buf = PathBuf::from(crate_path);
+  let cwd = file_path_buf.parent().unwrap();
+  println!("testing test case: {cwd:?}");
+
+  for (config_from_file, output_file) in get_fixture_configs(cwd) {
+    let compiler = create_compiler_with_args(
+      cwd.to_path_buf(),
+      create_path_buf.clone(),
+      |mut config, plugins| {
+        config.input = HashMap::from([("index".to_string(), file.clone())]);
+        config.output.target_env = TargetEnv::Browser;
+
+        (merge_config_from_json(config, &config_from_file), plugins)
+      },
+    );
+
+    compiler.compile().unwrap();
+
+    assert_compiler_result_with_config(
+      &compiler,
+      AssertCompilerResultConfig {
+        output_file: Some(output_file),
+        ..Default::default()
+      },
+    );
+  }
+}
+
+farmfe_testing::testing! {"tests/fixtures/runtime/options/**/index.ts", runtime_options_test}
+
+#[test]
+fn standalone_runtime() {
+  fixture!(
+    "tests/fixtures/runtime/standalone/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compile = |url: Option<String>, entry_filename: &str| {
+        let compiler = create_compiler_with_args(
+          cwd.to_path_buf(),
+          crate_path.clone(),
+          |mut config, plugins| {
+            config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+            config.output.target_env = TargetEnv::Browser;
+            config.output.entry_filename = entry_filename.to_string();
+            config.runtime.standalone = Some(RuntimeStandaloneConfig { url });
+
+            (config, plugins)
+          },
+        );
+        compiler.compile().unwrap();
+
+        let resources_map = compiler.context().resources_map.lock();
+        let entry_name = entry_filename
+          .replace("[entryName]", "index")
+          .replace("[ext]", "js");
+        let entry = String::from_utf8(resources_map[&entry_name].bytes.clone()).unwrap();
+        let runtime = resources_map
+          .values()
+          .find(|r| r.name.starts_with("farm_runtime."))
+          .map(|r| (r.name.clone(), String::from_utf8(r.bytes.clone()).unwrap()));
+
+        (entry, runtime)
+      };
+
+      // the runtime is emitted as a standalone versioned file
+      let (entry, runtime) = compile(None, "[entryName].[ext]");
+      let (runtime_name, runtime_code) = runtime.expect("standalone runtime is not emitted");
+      let version = runtime_name
+        .trim_start_matches("farm_runtime.")
+        .trim_end_matches(".js");
+
+      assert!(entry.starts_with(&format!("import \"./{runtime_name}\";")));
+      assert!(runtime_code.ends_with(&format!(
+        "window['__farm_default_namespace__'].__FARM_RUNTIME_VERSION__ = '{version}';"
+      )));
+
+      // the runtime is emitted to the output root, the entry imports it relative to its own dir
+      let (entry, _) = compile(None, "js/[entryName].[ext]");
+      assert!(entry.starts_with(&format!("import \"../{runtime_name}\";")));
+
+      // another app references the shared runtime by url and checks the compatibility
+      let url = format!("https://cdn.example.com/{runtime_name}");
+      let (entry, runtime) = compile(Some(url.clone()), "[entryName].[ext]");
+
+      assert!(runtime.is_none());
+      assert!(entry.starts_with(&format!("import \"{url}\";")));
+      assert!(entry.contains(&format!(
+        "window['__farm_default_namespace__'].__FARM_RUNTIME_VERSION__ !== '{version}'"
+      )));
+    }
+  );
+}
 
-  let compiler =
-    create_compiler_with_args(cwd.to_path_buf(), create_path_buf, |mut config, plugins| {
-      config.mode = Mode::Production;
+#[test]
+fn standalone_runtime_shared_by_entries() {
+  fixture!(
+    "tests/fixtures/runtime/standalone_entries/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([
+            ("index".to_string(), "./index.ts".to_string()),
+            ("about".to_string(), "./about.ts".to_string()),
+          ]);
+          config.output.target_env = TargetEnv::Browser;
+          config.runtime.standalone = Some(RuntimeStandaloneConfig { url: None });
 
-      if runtime_entry.is_file() {
-        let runtime_entry = runtime_entry.to_string_lossy().to_string();
-        config.runtime.path = runtime_entry;
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      let resources_map = compiler.context().resources_map.lock();
+      let runtime_names = resources_map
+        .values()
+        .filter(|r| r.name.starts_with("farm_runtime."))
+        .map(|r| r.name.clone())
+        .collect::<Vec<_>>();
+
+      // a single runtime file is emitted and every entry links to it instead of inlining the runtime
+      assert_eq!(runtime_names.len(), 1);
+
+      for entry in ["index.js", "about.js"] {
+        let code = String::from_utf8(resources_map[entry].bytes.clone()).unwrap();
+        assert!(code.starts_with(&format!("import \"./{}\";", runtime_names[0])));
+        assert!(!code.contains(".__FARM_RUNTIME_VERSION__ = '"));
       }
+    }
+  );
+}
 
-      config.input = HashMap::from_iter(vec![(entry_name.clone(), file)]);
+#[test]
+fn runtime_features_of_entries() {
+  fixture!(
+    "tests/fixtures/runtime/runtime_features/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      // the real runtime guards its dynamic import machinery with the feature flags
+      let runtime_entry = crate_path
+        .join("../../packages/runtime/src/index.ts")
+        .to_string_lossy()
+        .to_string();
 
-      config.minify = Box::new(BoolOrObj::Bool(false));
-      config.tree_shaking = Box::new(BoolOrObj::Bool(false));
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([
+            ("index".to_string(), "./index.ts".to_string()),
+            ("about".to_string(), "./about.ts".to_string()),
+          ]);
+          config.runtime.path = runtime_entry;
+          config.mode = Mode::Production;
+          config.minify = Box::new(BoolOrObj::Bool(false));
+          config.output.target_env = TargetEnv::Browser;
 
-      config.external = vec![ConfigRegex::new("(^node:.*)"), ConfigRegex::new("^fs$")];
-      config.output.target_env = TargetEnv::Node;
-      // config.output.format = ModuleFormat::CommonJs;
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
 
-      // TODO: multiple bundle
-      config.partial_bundling.enforce_resources = vec![PartialBundlingEnforceResourceConfig {
-        test: vec![ConfigRegex::new("^bundle2.*")],
-        name: "bundle2".to_string(),
-      }];
+      // the runtime of `index` contains the dynamic import machinery, while `about` only contains a minimal runtime
+      assert_compiler_result_with_config(&compiler, AssertCompilerResultConfig::default());
+    }
+  );
+}
 
-      if let Some(config_from_file) = config_from_file {
-        // TODO: macro
-        if let Some(mode) = get_config_field(&config_from_file, &["mode"]) {
-          config.mode = mode;
-        }
+#[test]
+fn rename_resource() {
+  struct LocaleSuffixPlugin;
+
+  impl Plugin for LocaleSuffixPlugin {
+    fn name(&self) -> &str {
+      "locale-suffix"
+    }
+
+    fn rename_resource(
+      &self,
+      resource: &Resource,
+      _context: &Arc<CompilationContext>,
+    ) -> farmfe_core::error::Result<Option<String>> {
+      if String::from_utf8_lossy(&resource.bytes).contains("你好") {
+        return Ok(Some(resource.name.replace(".js", ".zh-CN.js")));
+      }
+
+      Ok(None)
+    }
+  }
+
+  fixture!(
+    "tests/fixtures/runtime/rename_resource/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, mut plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.output.target_env = TargetEnv::Browser;
+          plugins.push(Arc::new(LocaleSuffixPlugin) as _);
+
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      let resources_map = compiler.context().resources_map.lock();
+      let renamed = resources_map
+        .keys()
+        .find(|name| name.ends_with(".zh-CN.js"))
+        .expect("resource is not renamed")
+        .clone();
+      let original = renamed.replace(".zh-CN.js", ".js");
+
+      assert!(!resources_map.contains_key(&original));
+      assert!(compiler
+        .context()
+        .resource_pot_map
+        .read()
+        .resource_pots()
+        .iter()
+        .any(|resource_pot| resource_pot.resources().contains(&&renamed)));
+
+      drop(resources_map);
+
+      // the dynamic resources map of the entry refers to the renamed resource
+      assert_compiler_result_with_config(&compiler, AssertCompilerResultConfig::default());
+    }
+  );
+}
+
+#[test]
+fn runtime_plugins() {
+  fixture!(
+    "tests/fixtures/runtime/plugins/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      let plugin_path = cwd.join("plugin.ts").to_string_lossy().to_string();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.output.target_env = TargetEnv::Browser;
+          config.runtime.plugins = vec![plugin_path.clone()];
+
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      // the runtime plugin is a dependency of the runtime entry
+      let module_graph = compiler.context().module_graph.read();
+      let runtime_module_id = module_graph
+        .entries
+        .keys()
+        .find(|id| id.relative_path().ends_with(RUNTIME_SUFFIX))
+        .unwrap()
+        .clone();
+      let plugin_module_id = module_graph
+        .dependencies_ids(&runtime_module_id)
+        .into_iter()
+        .find(|id| id.relative_path() == format!("plugin.ts{RUNTIME_SUFFIX}"))
+        .expect("runtime plugin is not a dependency of the runtime entry");
+      let edge_info = module_graph
+        .edge_info(&runtime_module_id, &plugin_module_id)
+        .unwrap();
+      // the plugin is depended once, by the import inserted into the runtime entry
+      assert_eq!(edge_info.items().len(), 1);
+      assert_eq!(edge_info.items()[0].source, plugin_path);
+
+      drop(module_graph);
+
+      // the runtime plugin is emitted with the runtime
+      assert_compiler_result_with_config(&compiler, AssertCompilerResultConfig::default());
+    }
+  );
+}
+
+#[test]
+fn runtime_resource_content_hash() {
+  fixture!(
+    "tests/fixtures/runtime/standalone_entries/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compile = |filename: &str, namespace: &str| {
+        let compiler = create_compiler_with_args(
+          cwd.to_path_buf(),
+          crate_path.clone(),
+          |mut config, plugins| {
+            config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+            config.output.target_env = TargetEnv::Browser;
+            config.output.filename = filename.to_string();
+            config.runtime.namespace = namespace.to_string();
+
+            (config, plugins)
+          },
+        );
+        compiler.compile().unwrap();
+
+        let resources_map = compiler.context().resources_map.lock();
+        let runtime = resources_map
+          .values()
+          .find(|r| matches!(r.resource_type, ResourceType::Runtime))
+          .expect("runtime resource not found");
+
+        (runtime.name.clone(), runtime.bytes.clone())
+      };
+
+      let hashed_filename = "[resourceName].[hash].[ext]";
+      let (name, bytes) = compile(hashed_filename, "app");
+      assert_eq!(
+        name,
+        format!(
+          "FARM_RUNTIME_runtime.{}.js",
+          farmfe_toolkit::hash::sha256(&bytes, 8)
+        )
+      );
+      // the hash is stable when the runtime is unchanged, and changes with the runtime content
+      assert_eq!(compile(hashed_filename, "app").0, name);
+      assert_ne!(compile(hashed_filename, "another_app").0, name);
+
+      // the runtime name is kept when there is no hash placeholder
+      assert_eq!(
+        compile("[resourceName].[ext]", "app").0,
+        "FARM_RUNTIME_runtime"
+      );
+    }
+  );
+}
+
+#[test]
+fn html_inject_runtime() {
+  fixture!(
+    "tests/fixtures/runtime/html_inject/index.html",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compile = |html_inject: RuntimeHtmlInject| {
+        let compiler = create_compiler_with_args(
+          cwd.to_path_buf(),
+          crate_path.clone(),
+          |mut config, plugins| {
+            config.input = HashMap::from([("index".to_string(), "./index.html".to_string())]);
+            config.output.target_env = TargetEnv::Browser;
+            config.runtime.html_inject = html_inject;
+            config.runtime.nonce = Some("abc123".to_string());
 
-        if let Some(format) = get_config_field(&config_from_file, &["output", "format"]) {
-          config.output.format = format;
+            (config, plugins)
+          },
+        );
+        compiler.compile().unwrap();
+
+        compiler
+      };
+
+      // the runtime is inlined into <head> by default
+      assert_compiler_result_with_config(
+        &compile(RuntimeHtmlInject::Inline),
+        AssertCompilerResultConfig::default(),
+      );
+
+      // the runtime is emitted as a separate resource and referenced by <script src>
+      assert_compiler_result_with_config(
+        &compile(RuntimeHtmlInject::External),
+        AssertCompilerResultConfig {
+          output_file: Some("output.external.js".to_string()),
+          ..Default::default()
+        },
+      );
+    }
+  );
+}
+
+#[test]
+fn runtime_bucket() {
+  fixture!(
+    "tests/fixtures/runtime/runtime_bucket/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.output.target_env = TargetEnv::Browser;
+          config.runtime.path = cwd.join("runtime.ts").to_string_lossy().to_string();
+          config.runtime.swc_helpers_path = cwd.join("helpers").to_string_lossy().to_string();
+
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      let resource_pot_map = compiler.context().resource_pot_map.read();
+      let runtime_resource_pots = resource_pot_map
+        .resource_pots()
+        .into_iter()
+        .filter(|resource_pot| matches!(resource_pot.resource_pot_type, ResourcePotType::Runtime))
+        .collect::<Vec<_>>();
+
+      assert_eq!(runtime_resource_pots.len(), 1);
+
+      let mut runtime_modules = runtime_resource_pots[0]
+        .modules()
+        .into_iter()
+        .map(|module_id| module_id.relative_path().to_string())
+        .collect::<Vec<_>>();
+      runtime_modules.sort();
+
+      assert_eq!(
+        runtime_modules,
+        vec![
+          format!("helpers/_/_export_star.js{RUNTIME_SUFFIX}"),
+          format!("helpers/_/_interop_require_default.js{RUNTIME_SUFFIX}"),
+          format!("helpers/_/_interop_require_wildcard.js{RUNTIME_SUFFIX}"),
+          format!("module-system.ts{RUNTIME_SUFFIX}"),
+          format!("runtime.ts{RUNTIME_SUFFIX}"),
+        ]
+      );
+    }
+  );
+}
+
+#[test]
+fn runtime_bucket_collects_split_runtime_modules() {
+  /// Moves a runtime helper out of the runtime resource pot, like a plugin that splits the resource pots by itself
+  struct SplitRuntimeHelperPlugin;
+
+  impl Plugin for SplitRuntimeHelperPlugin {
+    fn name(&self) -> &str {
+      "split-runtime-helper"
+    }
+
+    fn priority(&self) -> i32 {
+      101
+    }
+
+    fn process_resource_pots(
+      &self,
+      resource_pots: &mut Vec<&mut ResourcePot>,
+      _context: &Arc<CompilationContext>,
+    ) -> farmfe_core::error::Result<Option<()>> {
+      let helper = resource_pots
+        .iter()
+        .flat_map(|resource_pot| resource_pot.modules())
+        .find(|module_id| module_id.relative_path().contains("_export_star.js"))
+        .cloned()
+        .unwrap();
+
+      for resource_pot in resource_pots.iter_mut() {
+        if matches!(resource_pot.resource_pot_type, ResourcePotType::Runtime) {
+          resource_pot.remove_module(&helper);
+        } else {
+          resource_pot.add_module(helper.clone());
         }
+      }
+
+      Ok(None)
+    }
+  }
+
+  fixture!(
+    "tests/fixtures/runtime/runtime_bucket/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, mut plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.output.target_env = TargetEnv::Browser;
+          config.runtime.path = cwd.join("runtime.ts").to_string_lossy().to_string();
+          config.runtime.swc_helpers_path = cwd.join("helpers").to_string_lossy().to_string();
+          plugins.push(Arc::new(SplitRuntimeHelperPlugin) as _);
+
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      let helper = ModuleId::from(format!("helpers/_/_export_star.js{RUNTIME_SUFFIX}").as_str());
+      let resource_pot_map = compiler.context().resource_pot_map.read();
+
+      for resource_pot in resource_pot_map.resource_pots() {
+        assert_eq!(
+          resource_pot.modules().contains(&&helper),
+          matches!(resource_pot.resource_pot_type, ResourcePotType::Runtime),
+          "{}",
+          resource_pot.id
+        );
+      }
+
+      let module_graph = compiler.context().module_graph.read();
+      let runtime_resource_pot = module_graph.module(&helper).unwrap().resource_pot.clone();
+      assert!(matches!(
+        resource_pot_map
+          .resource_pot(&runtime_resource_pot.unwrap())
+          .unwrap()
+          .resource_pot_type,
+        ResourcePotType::Runtime
+      ));
+    }
+  );
+}
+
+#[test]
+fn entry_formats() {
+  fixture!(
+    "tests/fixtures/runtime/entry_formats/main.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([
+            ("main".to_string(), "./main.ts".to_string()),
+            ("legacy".to_string(), "./legacy.ts".to_string()),
+          ]);
+          config.output.target_env = TargetEnv::Node;
+          config.output.format = ModuleFormatConfig::Entries(HashMap::from([
+            ("main".to_string(), ModuleFormat::EsModule),
+            ("legacy".to_string(), ModuleFormat::CommonJs),
+          ]));
+
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      assert_compiler_result_with_config(&compiler, AssertCompilerResultConfig::default());
+    }
+  );
+}
+
+#[test]
+fn async_functions_down_leveled_to_es2015() {
+  fixture!(
+    "tests/fixtures/runtime/async_es2015/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.output.target_env = TargetEnv::Browser;
+          config.runtime.swc_helpers_path = cwd.join("helpers").to_string_lossy().to_string();
+          config.script.target = EsVersion::Es2015;
+          config.preset_env = Box::new(PresetEnvConfig::Obj(PresetEnvConfigObj {
+            options: Box::new(json!({ "mode": "entry", "targets": "chrome 50" })),
+            ..Default::default()
+          }));
+
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      // the helper imported by both modules is bundled once
+      let module_graph = compiler.context().module_graph.read();
+      let helpers = module_graph
+        .modules()
+        .into_iter()
+        .filter(|module| module.id.relative_path().contains("_async_to_generator"))
+        .collect::<Vec<_>>();
+      assert_eq!(helpers.len(), 1);
+      assert!(!helpers[0].external);
+
+      let dependents = module_graph.dependents_ids(&helpers[0].id);
+      assert!(dependents.contains(&"index.ts".into()));
+      assert!(dependents.contains(&"loader.ts".into()));
+      drop(module_graph);
 
-        if let Some(target_env) = get_config_field(&config_from_file, &["output", "targetEnv"]) {
-          config.output.target_env = target_env;
+      assert_compiler_result_with_config(&compiler, AssertCompilerResultConfig::default());
+    }
+  );
+}
+
+#[test]
+fn runtime_with_unknown_module_type() {
+  fixture!(
+    "tests/fixtures/runtime/unknown_runtime/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      let runtime_path = cwd.join("runtime").to_string_lossy().to_string();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.runtime.path.clone_from(&runtime_path);
+
+          (config, plugins)
+        });
+
+      let err = compiler.compile().unwrap_err().to_string();
+      assert!(err.contains(&format!(
+        "Can not determine the module type of runtime file `{runtime_path}`"
+      )));
+    }
+  );
+}
+
+#[test]
+fn invalid_preset_env_browserslist_targets() {
+  fixture!(
+    "tests/fixtures/runtime/options/preset_env_targets/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      for (query, message) in [
+        ("", "The browserslist query of `presetEnv` is empty"),
+        (
+          "not a browser",
+          "Invalid browserslist query `not a browser` of `presetEnv`",
+        ),
+      ] {
+        let mut config = create_config(cwd.to_path_buf(), crate_path.clone());
+        config.preset_env = Box::new(PresetEnvConfig::Targets(BrowserslistQuery(
+          query.to_string(),
+        )));
+
+        let err = Compiler::new(config, vec![]).err().unwrap().to_string();
+        assert!(err.contains(message), "{err}");
+      }
+    }
+  );
+}
+
+#[test]
+fn warn_multiple_runtime_resource_pots() {
+  /// marks `utils.ts` as a runtime module, which is placed in a second runtime resource pot
+  struct RuntimeModuleTypePlugin;
+
+  impl Plugin for RuntimeModuleTypePlugin {
+    fn name(&self) -> &str {
+      "runtime-module-type"
+    }
+
+    fn finalize_module(
+      &self,
+      param: &mut PluginFinalizeModuleHookParam,
+      _context: &Arc<CompilationContext>,
+    ) -> farmfe_core::error::Result<Option<()>> {
+      if param.module.id.relative_path() == "utils.ts" {
+        param.module.module_type = ModuleType::Runtime;
+      }
+
+      Ok(None)
+    }
+  }
+
+  fixture!(
+    "tests/fixtures/runtime/runtime_bucket/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      let warnings_collector = Arc::new(WarningsCollector::default());
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, mut plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.output.target_env = TargetEnv::Browser;
+          config.runtime.path = cwd.join("runtime.ts").to_string_lossy().to_string();
+          config.runtime.swc_helpers_path = cwd.join("helpers").to_string_lossy().to_string();
+          plugins.push(Arc::new(RuntimeModuleTypePlugin) as _);
+          plugins.push(warnings_collector.clone() as _);
+
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      let resource_pot_map = compiler.context().resource_pot_map.read();
+      let runtime_resource_pots = resource_pot_map
+        .resource_pots()
+        .into_iter()
+        .filter(|resource_pot| matches!(resource_pot.resource_pot_type, ResourcePotType::Runtime))
+        .collect::<Vec<_>>();
+      assert_eq!(runtime_resource_pots.len(), 2);
+
+      let warnings = warnings_collector.warnings.lock();
+      let warnings = warnings
+        .iter()
+        .filter(|w| w.contains("runtime resource pots"))
+        .collect::<Vec<_>>();
+
+      assert_eq!(warnings.len(), 1);
+      assert!(warnings[0].starts_with("Found 2 runtime resource pots"));
+      assert!(warnings[0].contains("Only `FARM_RUNTIME_runtime` is used as the runtime"));
+    }
+  );
+}
+
+#[test]
+fn persistent_cache_of_rendered_modules() {
+  /// Appends `renderedAgain()` to every module after tree shaking, which only shows up in the output of the modules
+  /// that are rendered again instead of restored from the cache. The content of `index.ts` is treated as changed
+  /// so that the resource pot itself is not restored from the cache
+  struct RenderedAgainPlugin;
+
+  impl Plugin for RenderedAgainPlugin {
+    fn name(&self) -> &str {
+      "rendered-again"
+    }
+
+    fn priority(&self) -> i32 {
+      99
+    }
+
+    fn optimize_module_graph(
+      &self,
+      module_graph: &mut ModuleGraph,
+      _context: &Arc<CompilationContext>,
+    ) -> farmfe_core::error::Result<Option<()>> {
+      for module in module_graph.modules_mut() {
+        if !module.module_type.is_script() {
+          continue;
         }
 
-        if let Some(lazy_compilation) = get_config_field(&config_from_file, &["lazyCompilation"]) {
-          config.lazy_compilation = lazy_compilation;
+        if module.id.relative_path() == "index.ts" {
+          module.content_hash.push_str("-changed");
         }
+
+        module
+          .meta
+          .as_script_mut()
+          .ast
+          .body
+          .push(ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Call(CallExpr {
+              span: DUMMY_SP,
+              callee: Callee::Expr(Box::new(Expr::Ident("renderedAgain".into()))),
+              args: vec![],
+              type_args: None,
+            })),
+          })));
       }
 
-      (config, plugins)
-    });
+      Ok(Some(()))
+    }
+  }
+
+  fixture!(
+    "tests/fixtures/runtime/persistent_cache/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      let cache_dir = std::env::temp_dir().join("farm-test-persistent-cache-of-rendered-modules");
+      std::fs::remove_dir_all(&cache_dir).ok();
+
+      let compile = |plugin: Option<Arc<dyn Plugin>>| {
+        let compiler = create_compiler_with_args(
+          cwd.to_path_buf(),
+          crate_path.clone(),
+          |mut config, mut plugins| {
+            config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+            config.output.target_env = TargetEnv::Browser;
+            config.persistent_cache =
+              Box::new(PersistentCacheConfig::Obj(PersistentCacheConfigObj {
+                cache_dir: cache_dir.to_string_lossy().to_string(),
+                ..Default::default()
+              }));
+            plugins.extend(plugin);
+
+            (config, plugins)
+          },
+        );
+        compiler.compile().unwrap();
 
-  compiler.compile().unwrap();
+        compiler
+      };
 
-  assert_compiler_result_with_config(
-    &compiler,
-    AssertCompilerResultConfig {
-      entry_name: Some(entry_name),
-      ignore_emitted_field: false,
-      ..Default::default()
-    },
+      compile(None);
+      let compiler = compile(Some(Arc::new(RenderedAgainPlugin)));
+      std::fs::remove_dir_all(&cache_dir).ok();
+
+      // only the changed module is rendered again, greet.ts is restored from the cache
+      assert_compiler_result_with_config(&compiler, AssertCompilerResultConfig::default());
+    }
   );
 }
-
-farmfe_testing::testing! {"tests/fixtures/runtime/bundle/**/index.ts", test}
-// farmfe_testing::testing! {"tests/fixtures/runtime/bundle/cjs/export/entryExportStar/**/index.ts", test}
-// farmfe_testing::testing! {"tests/fixtures/runtime/bundle/external/import/namespace/**/index.ts", test}
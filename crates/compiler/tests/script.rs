@@ -1,9 +1,10 @@
-use std::{collections::HashMap, path::PathBuf};
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
 use common::{
-  assert_compiler_result_with_config, create_compiler_with_args, get_config_field,
-  try_read_config_from_json, AssertCompilerResultConfig,
+  assert_compiler_result_with_config, create_compiler_with_args, get_fixture_configs,
+  merge_config_from_json, AssertCompilerResultConfig, WarningsCollector,
 };
+use farmfe_testing_helpers::fixture;
 
 mod common;
 
@@ -15,39 +16,107 @@ fn script_test(file: String, crate_path: String) {
 
   let entry_name = "index".to_string();
 
-  let config_entry = cwd.to_path_buf().join("config.json");
+  for (config_from_file, output_file) in get_fixture_configs(cwd) {
+    let compiler = create_compiler_with_args(
+      cwd.to_path_buf(),
+      create_path_buf.clone(),
+      |mut config, plugins| {
+        config.input = HashMap::from_iter(vec![(entry_name.clone(), file.clone())]);
 
-  let config_from_file = try_read_config_from_json(config_entry);
+        (merge_config_from_json(config, &config_from_file), plugins)
+      },
+    );
 
-  let compiler =
-    create_compiler_with_args(cwd.to_path_buf(), create_path_buf, |mut config, plugins| {
-      config.input = HashMap::from_iter(vec![(entry_name.clone(), file)]);
+    compiler.compile().unwrap();
 
-      if let Some(config_form_file) = config_from_file {
-        if let Some(str) = get_config_field(&config_form_file, &["output", "publicPath"]) {
-          config.output.public_path = str;
-        }
+    assert_compiler_result_with_config(
+      &compiler,
+      AssertCompilerResultConfig {
+        entry_name: Some(entry_name.clone()),
+        ignore_emitted_field: false,
+        output_file: Some(output_file),
+      },
+    );
+  }
+}
+
+farmfe_testing::testing!("tests/fixtures/script/**/index.ts", script_test);
+
+#[test]
+fn warn_default_import_without_default_export() {
+  fixture!(
+    "tests/fixtures/default_import/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let warnings_collector = Arc::new(WarningsCollector::default());
 
-        if let Some(enable) =
-          get_config_field(&config_form_file, &["script", "nativeTopLevelAwait"])
-        {
-          config.script.native_top_level_await = enable;
-        }
-      }
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, mut plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          plugins.push(warnings_collector.clone() as _);
 
-      (config, plugins)
-    });
+          (config, plugins)
+        });
 
-  compiler.compile().unwrap();
+      compiler.compile().unwrap();
 
-  assert_compiler_result_with_config(
-    &compiler,
-    AssertCompilerResultConfig {
-      entry_name: Some(entry_name),
-      ignore_emitted_field: false,
-      ..Default::default()
-    },
+      let warnings = warnings_collector.warnings.lock();
+      let warnings = warnings
+        .iter()
+        .filter(|w| w.contains("has no default export"))
+        .collect::<Vec<_>>();
+
+      assert_eq!(warnings.len(), 2);
+      assert!(warnings
+        .iter()
+        .all(|w| w.contains("`./named-only` in index.ts targets named-only.ts")));
+    }
   );
 }
 
-farmfe_testing::testing!("tests/fixtures/script/**/index.ts", script_test);
+#[test]
+fn fail_on_reassigned_import_binding() {
+  fixture!(
+    "tests/fixtures/import_reassign/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+
+          (config, plugins)
+        });
+
+      let err = compiler.compile().unwrap_err();
+
+      assert!(err
+        .to_string()
+        .contains("Cannot reassign imported binding `count` in index.ts"));
+    }
+  );
+}
+
+#[test]
+fn fail_on_unsupported_data_url() {
+  fixture!(
+    "tests/fixtures/data_url_unsupported/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+
+          (config, plugins)
+        });
+
+      let err = compiler.compile().unwrap_err();
+
+      assert!(err
+        .to_string()
+        .contains("Unsupported MIME type `text/plain` of data URL"));
+    }
+  );
+}
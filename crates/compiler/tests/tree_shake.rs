@@ -169,3 +169,31 @@ fn tree_shake_changed_ast() {
     }
   );
 }
+
+#[test]
+fn tree_shaking_report() {
+  fixture!(
+    "tests/fixtures/tree_shaking_report/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      let report = compiler.tree_shaking_report();
+      let (_, dep) = report
+        .iter()
+        .find(|(module_id, _)| module_id.relative_path() == "dep.ts")
+        .unwrap();
+
+      assert_eq!(dep.declared_exports, vec!["a", "b", "c"]);
+      assert_eq!(dep.retained_exports, vec!["a"]);
+      assert_eq!(dep.removed_exports(), vec!["b", "c"]);
+    }
+  );
+}
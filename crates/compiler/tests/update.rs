@@ -172,6 +172,15 @@ fn update_without_dependencies_change_css() {
       assert_eq!(result.added_module_ids.len(), 0);
       assert_eq!(result.updated_module_ids, vec!["index.css".into()]);
       assert_eq!(result.removed_module_ids.len(), 0);
+      // the css module accepts itself, the recompiled css replaces the previous style without reloading the page
+      assert_eq!(
+        result.boundaries,
+        HashMap::from([("index.css".to_string(), vec![vec!["index.css".to_string()]])])
+      );
+      assert!(result.mutable_resources.contains("color: red;"));
+      assert!(result
+        .mutable_resources
+        .contains("previousStyle.replaceWith(style);"));
 
       asset_update_result_code(cwd.clone(), &result, Some("update1"));
 
@@ -188,6 +197,48 @@ fn update_without_dependencies_change_css() {
   );
 }
 
+#[test]
+fn update_with_added_style_keeps_execution_order() {
+  fixture!(
+    "tests/fixtures/update/style-order/index.html",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap().to_path_buf();
+      let compiler = create_update_compiler(
+        HashMap::from([("index".to_string(), "./index.html".to_string())]),
+        cwd.clone(),
+        crate_path,
+        false,
+      );
+
+      compiler.compile().unwrap();
+
+      let update_file = cwd.join("b.ts").to_string_lossy().to_string();
+      let original_ts = std::fs::read_to_string(&update_file).unwrap();
+      std::fs::write(&update_file, format!("import './b.css';\n\n{original_ts}")).unwrap();
+
+      let result = compiler.update(
+        vec![(update_file.clone(), UpdateType::Updated)],
+        || {},
+        true,
+        true,
+      );
+      // restore b.ts
+      std::fs::write(&update_file, original_ts).unwrap();
+      let result = result.unwrap();
+
+      assert!(result.added_module_ids.contains(&"b.css".into()));
+
+      let module_graph = compiler.context().module_graph.read();
+      let order_of = |id: &str| module_graph.module(&id.into()).unwrap().execution_order;
+      // the added style is inserted after the styles of the modules executed before it
+      assert!(order_of("b.css") > order_of("a.css"));
+      assert!(result
+        .mutable_resources
+        .contains(&format!("const farmOrder = {};", order_of("b.css"))));
+    }
+  );
+}
+
 #[test]
 fn update_with_dependencies_change_css_modules() {
   fixture!(
@@ -19,10 +19,22 @@ impl From<TargetEnv> for AssetFormatMode {
   }
 }
 
+/// How `.node` native addons are handled when `output.targetEnv` is node.
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum NativeAddonMode {
+  /// Keep the import external and copy the `.node` file to the output dir.
+  #[default]
+  Copy,
+  /// Keep the import external, the `.node` file is expected to be provided at runtime.
+  External,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", default)]
 pub struct AssetsConfig {
   pub include: Vec<String>,
+  pub native_addon: NativeAddonMode,
   /// Used internally, this option will be not exposed to user.
   pub public_dir: Option<String>,
   // TODO: v2
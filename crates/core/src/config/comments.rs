@@ -1,14 +1,17 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(untagged)]
 pub enum CommentsConfig {
-  /// true: preserve all comments. false: remove all comments
-  Bool(bool),
   /// Only preserve license comments
   #[serde(rename = "license")]
   #[default]
   License,
+  /// Remove all comments including license comments, same as `false`
+  #[serde(rename = "none")]
+  None,
+  /// true: preserve all comments. false: remove all comments
+  #[serde(untagged)]
+  Bool(bool),
 }
 
 impl CommentsConfig {
@@ -16,6 +19,25 @@ impl CommentsConfig {
     match self {
       CommentsConfig::Bool(b) => *b,
       CommentsConfig::License => true,
+      CommentsConfig::None => false,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::CommentsConfig;
+
+  #[test]
+  fn deserialize_comments_config() {
+    for (value, enabled) in [
+      ("true", true),
+      ("false", false),
+      ("\"license\"", true),
+      ("\"none\"", false),
+    ] {
+      let config: CommentsConfig = serde_json::from_str(value).unwrap();
+      assert_eq!(config.enabled(), enabled, "{value}");
     }
   }
 }
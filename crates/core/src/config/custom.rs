@@ -8,12 +8,13 @@ use super::{
   asset::AssetFormatMode,
   config_regex::ConfigRegex,
   css::NameConversion,
-  external::{ExternalConfig, ExternalObject},
+  external::{ExternalConfig, ExternalObject, ExternalPrefer},
   Config,
 };
 
 const CUSTOM_CONFIG_RUNTIME_ISOLATE: &str = "runtime.isolate";
 pub const CUSTOM_CONFIG_EXTERNAL_RECORD: &str = "external.record";
+pub const CUSTOM_CONFIG_EXTERNAL_PREFER: &str = "external.prefer";
 pub const CUSTOM_CONFIG_RESOLVE_DEDUPE: &str = "resolve.dedupe";
 pub const CUSTOM_CONFIG_CSS_MODULES_LOCAL_CONVERSION: &str = "css.modules.locals_conversion";
 pub const CUSTOM_CONFIG_ASSETS_MODE: &str = "assets.mode";
@@ -53,6 +54,10 @@ pub fn get_config_external_record(config: &Config) -> ExternalConfig {
   }
 }
 
+pub fn get_config_external_prefer(config: &Config) -> ExternalPrefer {
+  get_field_or_default_from_custom(config, CUSTOM_CONFIG_EXTERNAL_PREFER)
+}
+
 pub fn get_config_resolve_dedupe(config: &Config) -> Vec<String> {
   get_field_or_default_from_custom(config, CUSTOM_CONFIG_RESOLVE_DEDUPE)
 }
@@ -22,6 +22,17 @@ impl Default for ExternalConfigItem {
   }
 }
 
+/// Which one wins when a source matches `external` but can also be resolved locally
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExternalPrefer {
+  /// the source is always external, this is the default behavior
+  #[default]
+  External,
+  /// the local module is bundled, the source is only external when it can not be resolved
+  Local,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct ExternalConfig(pub Vec<ExternalConfigItem>);
 
@@ -21,6 +21,22 @@ pub struct MinifyOptions {
   pub exclude: Vec<ConfigRegex>,
   pub mode: MinifyMode,
   pub module_decls: bool,
+  /// preserve the names of functions and classes, so that `fn.name` is not changed by minification
+  pub keep_names: bool,
+  /// preserve the names of functions only
+  pub keep_fnames: bool,
+  /// preserve the names of classes only
+  pub keep_classnames: bool,
+}
+
+impl MinifyOptions {
+  pub fn should_keep_fnames(&self) -> bool {
+    self.keep_names || self.keep_fnames
+  }
+
+  pub fn should_keep_classnames(&self) -> bool {
+    self.keep_names || self.keep_classnames
+  }
 }
 
 impl Default for MinifyOptions {
@@ -32,6 +48,9 @@ impl Default for MinifyOptions {
       exclude: vec![ConfigRegex::new(".+\\.min\\.(js|css|html)$")],
       mode: MinifyMode::Module,
       module_decls: false,
+      keep_names: false,
+      keep_fnames: false,
+      keep_classnames: false,
     }
   }
 }
@@ -102,6 +121,8 @@ mod tests {
       "exclude": ["node_modules/"],
       "mode": "minify-resource-pot",
       "moduleDecls": false,
+      "keepNames": true,
+      "keepClassnames": true,
     });
     let minify =
       BoolOrObj::Obj(options).map(|val| serde_json::from_value::<MinifyOptions>(val).unwrap());
@@ -113,6 +134,9 @@ mod tests {
         mangle: BoolOrObj::Bool(true),
         mode: MinifyMode::ResourcePot,
         module_decls: false,
+        keep_names: true,
+        keep_fnames: false,
+        keep_classnames: true,
         ..
       })
     ));
@@ -17,6 +17,8 @@ pub const FARM_DYNAMIC_REQUIRE: &str = "farmDynamicRequire";
 pub const FARM_REQUIRE: &str = "farmRequire";
 pub const FARM_MODULE: &str = "module";
 pub const FARM_MODULE_EXPORT: &str = "exports";
+// injected into each module when `runtime.injectModuleId` is enabled, e.g `var __farm_module_id__ = "src/index.ts"`
+pub const FARM_MODULE_ID: &str = "__farm_module_id__";
 
 pub mod asset;
 pub mod bool_or_obj;
@@ -56,6 +58,8 @@ pub struct Config {
   pub sourcemap: Box<SourcemapConfig>,
   pub partial_bundling: Box<PartialBundlingConfig>,
   pub lazy_compilation: bool,
+  /// fail the build with the cycle path if the module graph contains any circular dependency
+  pub fail_on_circular_dependency: bool,
   pub core_lib_path: Option<String>,
   pub tree_shaking: Box<BoolOrObj<serde_json::Value>>,
   pub minify: Box<BoolOrObj<serde_json::Value>>,
@@ -96,6 +100,7 @@ impl Default for Config {
       sourcemap: Default::default(),
       partial_bundling: Default::default(),
       lazy_compilation: true,
+      fail_on_circular_dependency: false,
       core_lib_path: None,
       tree_shaking: Box::new(BoolOrObj::Bool(true)),
       minify: Box::new(BoolOrObj::Bool(true)),
@@ -136,11 +141,19 @@ impl TargetEnv {
   pub fn is_node(&self) -> bool {
     matches!(self, TargetEnv::Node)
       || matches!(self, TargetEnv::Custom(custom) if custom == "library-node")
+      || self.is_node_next()
   }
 
   pub fn is_library(&self) -> bool {
     matches!(self, TargetEnv::Library)
       || matches!(self, TargetEnv::Custom(custom) if custom == "library-browser" || custom == "library-node")
+      || self.is_node_next()
+  }
+
+  /// `node-next` renders native es modules for node without the Farm runtime, it shares the render path of `library-node`:
+  /// `import.meta` is kept as it is, and `require` of commonjs externals is declared by `createRequire` from `module`
+  pub fn is_node_next(&self) -> bool {
+    matches!(self, TargetEnv::Custom(custom) if custom == "node-next")
   }
 }
 
@@ -151,6 +164,25 @@ pub enum ModuleFormat {
   EsModule,
   #[serde(rename = "cjs")]
   CommonJs,
+  /// commonjs bundle wrapped by a factory that works with commonjs, amd and global variables
+  #[serde(rename = "umd")]
+  Umd,
+  /// `define(['dep'], function (dep) { ... })` for amd loaders like RequireJS
+  #[serde(rename = "amd")]
+  Amd,
+  /// `var name = (function () { ... })()` for plain `<script>`, the exports are assigned to the global variable `output.name`
+  #[serde(rename = "iife")]
+  Iife,
+  /// `System.register([...deps], function (exports, context) { ... })` for SystemJS
+  #[serde(rename = "system")]
+  System,
+}
+
+impl ModuleFormat {
+  /// only `esm` renders native es modules, the other formats render the bundle as commonjs and wrap it when needed
+  pub fn is_esm(&self) -> bool {
+    matches!(self, ModuleFormat::EsModule)
+  }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -205,6 +237,9 @@ pub struct CssPrefixerConfig {
 pub struct CssConfig {
   pub modules: Option<CssModulesConfig>,
   pub prefixer: Option<CssPrefixerConfig>,
+  /// Export the injected `<style>` element as the default export of css modules, e.g. `import style from './a.css'`.
+  /// Only applies when styles are injected at runtime, that is development mode targeting browser.
+  pub export_style_element: bool,
 }
 
 impl Default for CssConfig {
@@ -212,6 +247,7 @@ impl Default for CssConfig {
     Self {
       modules: Some(Default::default()),
       prefixer: Some(Default::default()),
+      export_style_element: false,
     }
   }
 }
@@ -228,12 +264,22 @@ pub struct ScriptParserConfig {
 pub struct ResolveConfig {
   pub alias: HashMap<String, String>,
   pub main_fields: Vec<String>,
+  /// File names tried in order when a directory is imported, for example, `["main", "index"]` resolves `./button`
+  /// to `./button/main.ts` before `./button/index.ts`. The configured extensions are appended to each name.
+  /// Directory imports are disallowed when it's empty.
   pub main_files: Vec<String>,
   pub extensions: Vec<String>,
   pub conditions: Vec<String>,
   pub symlinks: bool,
   pub strict_exports: bool,
   pub auto_external_failed_resolve: bool,
+  /// Suffixes tried before the resolved file name, `{target}` is replaced with the target env (`browser` or `node`).
+  /// For example, `[".{target}"]` resolves `./impl` to `./impl.browser.ts` when targeting browser and `./impl.node.ts` when targeting node,
+  /// and falls back to `./impl.ts` when no variant exists. Files under `node_modules` are not affected.
+  pub conditional_suffixes: Vec<String>,
+  /// Resolve the `compilerOptions.paths` aliases of the `tsconfig.json` under the root, relative to `compilerOptions.baseUrl`.
+  /// They are tried after `alias`.
+  pub tsconfig_paths: bool,
 }
 
 impl Default for ResolveConfig {
@@ -269,6 +315,8 @@ impl Default for ResolveConfig {
       symlinks: true,
       strict_exports: false,
       auto_external_failed_resolve: false,
+      conditional_suffixes: vec![],
+      tsconfig_paths: false,
     }
   }
 }
@@ -278,25 +326,112 @@ impl Default for ResolveConfig {
 pub struct RuntimeConfig {
   /// the absolute path of the runtime entry, a runtime is required for script module loading, executing and hot module updating.
   pub path: String,
+  /// inline source of the runtime entry, which is used instead of reading `path` from disk, e.g. when there is no real file system.
+  /// `path` is used as the id of the runtime entry and defaults to `<root>/__farm_inline_runtime__.ts`, relative imports of the runtime are resolved from it.
+  pub source: Option<String>,
   /// the runtime plugins
   pub plugins: Vec<String>,
   /// swc helpers path
   pub swc_helpers_path: String,
   /// namespace for the runtime
   pub namespace: String,
+  /// emit the runtime as a standalone versioned file, or reference the standalone runtime emitted by another build
+  pub standalone: Option<RuntimeStandaloneConfig>,
+  /// experimental: render the body of the matched modules as a string that is compiled by `new Function` on first require,
+  /// which defers the parse cost of rarely-used modules. Async modules are not supported and always rendered as normal.
+  pub lazy_parse: Vec<ConfigRegex>,
+  /// names of the parameters of the rendered module function
+  pub module_params: RuntimeModuleParamsConfig,
+  /// how the runtime is injected into html entries
+  pub html_inject: RuntimeHtmlInject,
+  /// nonce attribute of the `<script>` tags injected into html entries, required by sites with a strict CSP
+  pub nonce: Option<String>,
+  /// declare a `__farm_module_id__` constant holding the module id in each rendered module, for debugging and error reporting
+  pub inject_module_id: bool,
+  /// reject the loading of a dynamic resource when it does not finish within the timeout in milliseconds, no timeout by default
+  pub chunk_load_timeout_ms: Option<u64>,
 }
 
 impl Default for RuntimeConfig {
   fn default() -> Self {
     Self {
       path: String::from(""),
+      source: None,
       plugins: vec![],
       swc_helpers_path: String::from(""),
       namespace: String::from("__farm_default_namespace__"),
+      standalone: None,
+      lazy_parse: vec![],
+      module_params: RuntimeModuleParamsConfig::default(),
+      html_inject: RuntimeHtmlInject::default(),
+      nonce: None,
+      inject_module_id: false,
+      chunk_load_timeout_ms: None,
     }
   }
 }
 
+/// Parameter names of the module function `function(module, exports, farmRequire, farmDynamicRequire) {}`.
+/// The runtime passes the arguments by position, so only the rendered module code is affected.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RuntimeModuleParamsConfig {
+  pub module: String,
+  pub exports: String,
+  pub require: String,
+  pub dynamic_require: String,
+}
+
+impl Default for RuntimeModuleParamsConfig {
+  fn default() -> Self {
+    Self {
+      module: FARM_MODULE.to_string(),
+      exports: FARM_MODULE_EXPORT.to_string(),
+      require: FARM_REQUIRE.to_string(),
+      dynamic_require: FARM_DYNAMIC_REQUIRE.to_string(),
+    }
+  }
+}
+
+impl RuntimeModuleParamsConfig {
+  /// the parameter names in order
+  pub fn names(&self) -> [&str; 4] {
+    [
+      &self.module,
+      &self.exports,
+      &self.require,
+      &self.dynamic_require,
+    ]
+  }
+
+  pub fn is_default(&self) -> bool {
+    self.names()
+      == [
+        FARM_MODULE,
+        FARM_MODULE_EXPORT,
+        FARM_REQUIRE,
+        FARM_DYNAMIC_REQUIRE,
+      ]
+  }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RuntimeHtmlInject {
+  /// inline the runtime code as a `<script>` in `<head>`
+  #[default]
+  Inline,
+  /// emit the runtime as a separate resource referenced by `<script src>`
+  External,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RuntimeStandaloneConfig {
+  /// url of a shared standalone runtime. When set, the runtime is not emitted and is loaded from the url instead
+  pub url: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SourcemapConfig {
   /// Generate inline sourcemap instead of a separate file for mutable resources.
@@ -308,6 +443,10 @@ pub enum SourcemapConfig {
   All,
   #[serde(rename = "all-inline")]
   AllInline,
+  /// Generate a separate sourcemap file for mutable resources without referencing it by a `sourceMappingURL` comment,
+  /// e.g. to upload the sourcemap to an error reporting service without serving it to browsers.
+  #[serde(rename = "hidden")]
+  Hidden,
   #[serde(untagged)]
   Bool(bool),
 }
@@ -322,7 +461,7 @@ impl SourcemapConfig {
   pub fn enabled(&self, immutable: bool) -> bool {
     match self {
       Self::Bool(b) => *b && !immutable,
-      Self::Inline => !immutable,
+      Self::Inline | Self::Hidden => !immutable,
       _ => true,
     }
   }
@@ -333,6 +472,7 @@ impl SourcemapConfig {
       Self::Inline => true,
       Self::All => false,
       Self::AllInline => true,
+      Self::Hidden => false,
     }
   }
 
@@ -342,8 +482,13 @@ impl SourcemapConfig {
       Self::Inline => false,
       Self::All => true,
       Self::AllInline => true,
+      Self::Hidden => false,
     }
   }
+
+  pub fn is_hidden(&self) -> bool {
+    matches!(self, Self::Hidden)
+  }
 }
 
 mod tests {
@@ -370,6 +515,10 @@ mod tests {
     let config: SourcemapConfig = serde_json::from_str("\"all\"").expect("failed to parse");
 
     assert!(matches!(config, SourcemapConfig::All));
+
+    let config: SourcemapConfig = serde_json::from_str("\"hidden\"").expect("failed to parse");
+
+    assert!(matches!(config, SourcemapConfig::Hidden));
   }
 
   #[test]
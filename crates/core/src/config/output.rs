@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use super::{ModuleFormat, TargetEnv};
@@ -9,9 +11,45 @@ pub struct OutputConfig {
   pub public_path: String,
   pub entry_filename: String,
   pub filename: String,
+  /// File name of the js resources that don't contain an entry module, e.g. `[name].[hash].[ext]`, fallback to `filename` when it's not set.
+  /// The `[hash]` is computed from the sorted module ids and the rendered content of the chunk,
+  /// so the chunk keeps its name when other chunks change.
+  pub chunk_filename: Option<String>,
   pub assets_filename: String,
   pub target_env: TargetEnv,
-  pub format: ModuleFormat,
+  pub format: ModuleFormatConfig,
+  /// Number of spaces used to indent the modules rendered in the resource pot in development mode, at most 16 spaces.
+  /// Compact output is kept when it's not set or in production.
+  pub indent: Option<usize>,
+  /// Whether the statements of the rendered modules end with semicolons, default to `true`. When `false`, the modules are
+  /// rendered in the ASI style: the semicolons at the end of lines are omitted, and a line that would continue the previous
+  /// statement is prefixed by `;`, e.g. `;(function () {})()`. Minified output is not affected.
+  pub semicolons: bool,
+  /// Global variable name the exports are assigned to when the bundle is loaded by a plain `<script>` in `umd` format.
+  /// It's required by `iife` format when the entry has exports.
+  pub name: Option<String>,
+  /// `Object.freeze` the exports of the library entries, so that consumers can not mutate them.
+  /// It only takes effect for `cjs`, `umd` and `amd` formats, the namespace of es module is already immutable.
+  pub freeze_exports: bool,
+  /// Emit a `checksums.txt` listing the sha256 of every emitted resource, in the format of `sha256sum`,
+  /// so the output can be verified by `sha256sum -c checksums.txt` during deploy.
+  pub checksums: bool,
+  /// Emit a `manifest.json` mapping each entry module id to its entry file, the js chunks and the css files it loads,
+  /// so deploy tooling can find the hashed file names of an entry.
+  pub write_manifest: bool,
+  /// Emit the runtime as a single resource imported by each entry, instead of inlining it into every entry.
+  /// An entry that is the only consumer of its runtime still inlines it.
+  pub shared_runtime_chunk: bool,
+  /// Prepend `/* Farm vX.Y.Z (production) */` to every emitted js resource, which identifies the Farm core version
+  /// and the build mode of a deployed bundle when debugging.
+  pub version_comment: bool,
+  /// Code prepended to every emitted js resource, for example, a copyright banner. The source map is shifted accordingly.
+  pub banner: Option<String>,
+  /// Code appended to every emitted js resource, before the source map comment.
+  pub footer: Option<String>,
+  /// Expression of the global object the Farm runtime and the entry resources are attached to, e.g. `self` for web workers
+  /// or `globalThis` for Deno. Fallback to `global` when targeting node and `window` otherwise when it's not set.
+  pub global_object: Option<String>,
 }
 
 impl Default for OutputConfig {
@@ -20,12 +58,104 @@ impl Default for OutputConfig {
       entry_filename: "[entryName].[ext]".to_string(),
       // [resourceName].[contentHash].[ext]
       filename: "[resourceName].[ext]".to_string(),
+      chunk_filename: None,
       // [resourceName].[contentHash].[ext]
       assets_filename: "[resourceName].[ext]".to_string(),
       public_path: "/".to_string(),
       path: "dist".to_string(),
       target_env: TargetEnv::default(),
-      format: ModuleFormat::default(),
+      format: ModuleFormatConfig::default(),
+      indent: None,
+      semicolons: true,
+      name: None,
+      freeze_exports: false,
+      checksums: false,
+      write_manifest: false,
+      shared_runtime_chunk: false,
+      version_comment: false,
+      banner: None,
+      footer: None,
+      global_object: None,
     }
   }
 }
+
+/// Module format of the output, could be a single format for all entries, or a map from entry name to format, for example:
+/// ```json
+/// { "main": "esm", "legacy": "cjs" }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum ModuleFormatConfig {
+  Single(ModuleFormat),
+  Entries(HashMap<String, ModuleFormat>),
+}
+
+impl Default for ModuleFormatConfig {
+  fn default() -> Self {
+    Self::Single(ModuleFormat::default())
+  }
+}
+
+impl From<ModuleFormat> for ModuleFormatConfig {
+  fn from(format: ModuleFormat) -> Self {
+    Self::Single(format)
+  }
+}
+
+impl ModuleFormatConfig {
+  /// Get the format of the output of the entry. The [ModuleFormatConfig::common] format is used for
+  /// outputs that don't belong to an entry and entries that are not configured.
+  pub fn get(&self, entry_name: Option<&str>) -> ModuleFormat {
+    match (self, entry_name) {
+      (Self::Entries(entries), Some(entry_name)) => entries
+        .get(entry_name)
+        .copied()
+        .unwrap_or_else(|| self.common()),
+      _ => self.common(),
+    }
+  }
+
+  /// The format shared by all entries, fallback to [ModuleFormat::default] when entries use different formats.
+  pub fn common(&self) -> ModuleFormat {
+    match self {
+      Self::Single(format) => *format,
+      Self::Entries(entries) => {
+        let mut formats = entries.values();
+        let first = formats.next().copied().unwrap_or_default();
+
+        if formats.all(|format| *format == first) {
+          first
+        } else {
+          ModuleFormat::default()
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::{ModuleFormat, ModuleFormatConfig};
+
+  #[test]
+  fn test_module_format_config() {
+    let format: ModuleFormatConfig = serde_json::from_value(json!("cjs")).unwrap();
+    assert_eq!(format, ModuleFormatConfig::Single(ModuleFormat::CommonJs));
+    assert_eq!(format.get(Some("main")), ModuleFormat::CommonJs);
+
+    let format: ModuleFormatConfig =
+      serde_json::from_value(json!({ "main": "esm", "legacy": "cjs" })).unwrap();
+    assert_eq!(format.get(Some("main")), ModuleFormat::EsModule);
+    assert_eq!(format.get(Some("legacy")), ModuleFormat::CommonJs);
+    // entries use different formats, fallback to the default format
+    assert_eq!(format.get(Some("other")), ModuleFormat::EsModule);
+    assert_eq!(format.get(None), ModuleFormat::EsModule);
+
+    let format: ModuleFormatConfig =
+      serde_json::from_value(json!({ "main": "cjs", "legacy": "cjs" })).unwrap();
+    assert_eq!(format.common(), ModuleFormat::CommonJs);
+  }
+}
@@ -29,6 +29,9 @@ pub struct PartialBundlingConfig {
   /// Default to 0.8, immutable module will have 80% request numbers.
   /// TODO check if it is between 0 and 1
   pub immutable_modules_weight: f32,
+  /// place the `node_modules` modules shared by 2 or more entries into a separate `vendor` resource,
+  /// so they can be cached independently of the entries
+  pub vendor_chunk: bool,
 }
 
 impl Default for PartialBundlingConfig {
@@ -45,6 +48,7 @@ impl Default for PartialBundlingConfig {
       enforce_target_min_size: false,
       immutable_modules: vec![ConfigRegex::default()],
       immutable_modules_weight: 0.8,
+      vendor_chunk: false,
     }
   }
 }
@@ -6,6 +6,8 @@ use super::config_regex::ConfigRegex;
 #[serde(untagged)]
 pub enum PresetEnvConfig {
   Bool(bool),
+  /// Target the browsers matched by a browserslist query, e.g. `"> 0.5%, last 2 versions"`
+  Targets(BrowserslistQuery),
   Obj(PresetEnvConfigObj),
 }
 
@@ -13,11 +15,15 @@ impl PresetEnvConfig {
   pub fn enabled(&self) -> bool {
     match self {
       PresetEnvConfig::Bool(b) => *b,
-      PresetEnvConfig::Obj(_) => true,
+      PresetEnvConfig::Targets(_) | PresetEnvConfig::Obj(_) => true,
     }
   }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct BrowserslistQuery(pub String);
+
 impl Default for PresetEnvConfig {
   fn default() -> Self {
     PresetEnvConfig::Bool(true)
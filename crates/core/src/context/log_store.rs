@@ -24,6 +24,11 @@ impl LogStore {
     &self.warnings
   }
 
+  /// Take the warnings out of the store, e.g. to print each warning only once
+  pub fn take_warnings(&mut self) -> Vec<String> {
+    std::mem::take(&mut self.warnings)
+  }
+
   pub fn errors(&self) -> &Vec<String> {
     &self.errors
   }
@@ -83,6 +83,9 @@ pub enum CompilationError {
     source: Option<Box<dyn Error + Send + Sync>>,
   },
 
+  #[error("Circular dependency detected: {}. Circular dependencies are forbidden by `compilation.failOnCircularDependency`", cycle.join(" -> "))]
+  CircularDependencyError { cycle: Vec<String> },
+
   #[error("generate sourcemap for module `{id}` failed")]
   GenerateSourceMapError {
     id: String,
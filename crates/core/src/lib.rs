@@ -36,6 +36,7 @@ pub use rkyv_dyn;
 pub use rkyv_typename;
 pub use serde;
 pub use serde_json;
+pub use sourcemap;
 pub use swc_common;
 pub use swc_css_ast;
 pub use swc_ecma_ast;
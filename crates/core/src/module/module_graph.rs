@@ -561,8 +561,9 @@ impl ModuleGraph {
     (result, cyclic)
   }
 
-  pub fn update_execution_order_for_modules(&mut self) {
-    let (mut topo_sorted_modules, _) = self.toposort();
+  /// update the execution order of the modules by the topological order, return the cyclic modules stack found by [ModuleGraph::toposort]
+  pub fn update_execution_order_for_modules(&mut self) -> Vec<Vec<ModuleId>> {
+    let (mut topo_sorted_modules, cyclic) = self.toposort();
 
     topo_sorted_modules.reverse();
 
@@ -573,6 +574,8 @@ impl ModuleGraph {
         let module = self.module_mut(module_id).unwrap();
         module.execution_order = order;
       });
+
+    cyclic
   }
 
   pub fn internal_graph(&self) -> &StableDiGraph<Module, ModuleGraphEdge> {
@@ -12,11 +12,12 @@ use crate::{
     ModuleType,
   },
   resource::{
-    resource_pot::{ResourcePot, ResourcePotInfo, ResourcePotMetaData},
+    resource_pot::{ResourcePot, ResourcePotId, ResourcePotInfo, ResourcePotMetaData},
     Resource, ResourceType,
   },
   stats::Stats,
 };
+use sourcemap::SourceMap;
 
 pub mod constants;
 pub mod plugin_driver;
@@ -30,6 +31,8 @@ pub trait Plugin: Any + Send + Sync {
     DEFAULT_PRIORITY
   }
 
+  /// Modify the config before the compilation starts.
+  /// A plugin can contribute externals here by appending patterns to `config.external`, for example, the peer dependencies of a library.
   fn config(&self, _config: &mut Config) -> Result<Option<()>> {
     Ok(None)
   }
@@ -205,6 +208,17 @@ pub trait Plugin: Any + Send + Sync {
     Ok(None)
   }
 
+  /// Post-process the source map of a resource pot after the source map chain is collapsed and before it is serialized,
+  /// for example, rewrite the `sources` or strip the absolute paths for the error-monitoring service.
+  fn transform_sourcemap(
+    &self,
+    _source_map: &mut SourceMap,
+    _resource_pot_id: &ResourcePotId,
+    _context: &Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    Ok(None)
+  }
+
   /// Process generated resources after the file name of the resource is hashed
   fn process_generated_resources(
     &self,
@@ -214,6 +228,17 @@ pub trait Plugin: Any + Send + Sync {
     Ok(None)
   }
 
+  /// Rename the generated resource after its file name is hashed and the resource is processed,
+  /// for example, add a locale suffix based on the content. Return the final name of the resource,
+  /// the source map and the resources of the resource pot are updated to the renamed name.
+  fn rename_resource(
+    &self,
+    _resource: &Resource,
+    _context: &Arc<CompilationContext>,
+  ) -> Result<Option<String>> {
+    Ok(None)
+  }
+
   /// handle entry resource after all resources are generated and processed.
   /// For example, insert the generated resources into html
   fn handle_entry_resource(
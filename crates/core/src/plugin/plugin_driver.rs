@@ -19,9 +19,13 @@ use crate::{
     module_graph::ModuleGraph, module_group::ModuleGroupGraph, Module, ModuleId, ModuleMetaData,
     ModuleType,
   },
-  resource::resource_pot::{ResourcePot, ResourcePotInfo, ResourcePotMetaData},
+  resource::{
+    resource_pot::{ResourcePot, ResourcePotId, ResourcePotInfo, ResourcePotMetaData},
+    Resource,
+  },
   stats::{CompilationModuleGraphStats, CompilationPluginHookStats, Stats},
 };
+use sourcemap::SourceMap;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
@@ -130,6 +134,16 @@ macro_rules! hook_serial {
       Ok(())
     }
   };
+
+  ($func_name:ident, $($arg:ident: $ty:ty),+) => {
+    pub fn $func_name(&self, $($arg: $ty),+, context: &Arc<CompilationContext>) -> Result<()> {
+      for plugin in &self.plugins {
+        plugin.$func_name($($arg),+, context)?;
+      }
+
+      Ok(())
+    }
+  };
 }
 
 macro_rules! hook_parallel {
@@ -789,11 +803,32 @@ impl PluginDriver {
     _hook_context: &PluginHookContext
   );
 
+  hook_serial!(
+    transform_sourcemap,
+    source_map: &mut SourceMap,
+    resource_pot_id: &ResourcePotId
+  );
+
   hook_serial!(
     process_generated_resources,
     &mut PluginGenerateResourcesHookResult
   );
 
+  /// every plugin receives the name renamed by previous plugins
+  pub fn rename_resource(
+    &self,
+    resource: &mut Resource,
+    context: &Arc<CompilationContext>,
+  ) -> Result<()> {
+    for plugin in &self.plugins {
+      if let Some(name) = plugin.rename_resource(resource, context)? {
+        resource.name = name;
+      }
+    }
+
+    Ok(())
+  }
+
   hook_serial!(
     handle_entry_resource,
     &mut PluginHandleEntryResourceHookParams
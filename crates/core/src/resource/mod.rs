@@ -77,6 +77,19 @@ impl ResourceType {
     }
   }
 
+  /// the content type of the resource, e.g. `application/javascript` for js, used when serving the resource
+  pub fn to_mime(&self) -> String {
+    match self {
+      ResourceType::Runtime | ResourceType::Js => "application/javascript".to_string(),
+      ResourceType::Css => "text/css".to_string(),
+      ResourceType::Html => "text/html".to_string(),
+      ResourceType::SourceMap(_) => "application/json".to_string(),
+      ResourceType::Asset(ext) | ResourceType::Custom(ext) => mime_guess::from_ext(ext)
+        .first_or_octet_stream()
+        .to_string(),
+    }
+  }
+
   pub fn to_html_tag(&self) -> String {
     match self {
       ResourceType::Asset(str) => str.to_string(),
@@ -132,6 +145,13 @@ pub struct Resource {
   pub info: Option<ResourcePotInfo>,
 }
 
+impl Resource {
+  /// the content type of the resource, see [ResourceType::to_mime]
+  pub fn mime(&self) -> String {
+    self.resource_type.to_mime()
+  }
+}
+
 impl Default for Resource {
   fn default() -> Self {
     Self {
@@ -144,3 +164,36 @@ impl Default for Resource {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::{Resource, ResourceType};
+
+  #[test]
+  fn resource_mime() {
+    let resource = |resource_type| Resource {
+      resource_type,
+      ..Default::default()
+    };
+
+    assert_eq!(resource(ResourceType::Js).mime(), "application/javascript");
+    assert_eq!(
+      resource(ResourceType::Runtime).mime(),
+      "application/javascript"
+    );
+    assert_eq!(resource(ResourceType::Css).mime(), "text/css");
+    assert_eq!(resource(ResourceType::Html).mime(), "text/html");
+    assert_eq!(
+      resource(ResourceType::SourceMap("index.js".to_string())).mime(),
+      "application/json"
+    );
+    assert_eq!(
+      resource(ResourceType::Asset("png".to_string())).mime(),
+      "image/png"
+    );
+    assert_eq!(
+      resource(ResourceType::Custom("unknown".to_string())).mime(),
+      "application/octet-stream"
+    );
+  }
+}
@@ -236,6 +236,20 @@ impl JsCompiler {
   /// sync compile
   #[napi]
   pub fn compile_sync(&self) -> napi::Result<()> {
+    // write the profile scopes to a JSON trace instead of opening the profiler window
+    #[cfg(feature = "profile")]
+    if let Ok(profile_json) = std::env::var("FARM_PROFILE_JSON") {
+      let collector = farmfe_compiler::profile::ProfileCollector::start();
+      self
+        .compiler
+        .compile()
+        .map_err(|e| napi::Error::new(Status::GenericFailure, format!("{e}")))?;
+      let trace = collector.finish();
+
+      return std::fs::write(&profile_json, trace.to_string())
+        .map_err(|e| napi::Error::new(Status::GenericFailure, format!("{e}")));
+    }
+
     #[cfg(feature = "profile")]
     {
       farmfe_core::puffin::set_scopes_on(true); // Remember to call this, or puffin will be disabled!
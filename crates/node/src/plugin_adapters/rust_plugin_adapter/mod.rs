@@ -206,6 +206,17 @@ impl Plugin for RustPluginAdapter {
       .generate_resources(resource_pot, context, hook_context)
   }
 
+  fn transform_sourcemap(
+    &self,
+    source_map: &mut farmfe_core::sourcemap::SourceMap,
+    resource_pot_id: &farmfe_core::resource::resource_pot::ResourcePotId,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    self
+      .plugin
+      .transform_sourcemap(source_map, resource_pot_id, context)
+  }
+
   fn process_generated_resources(
     &self,
     resources: &mut PluginGenerateResourcesHookResult,
@@ -214,6 +225,14 @@ impl Plugin for RustPluginAdapter {
     self.plugin.process_generated_resources(resources, context)
   }
 
+  fn rename_resource(
+    &self,
+    resource: &farmfe_core::resource::Resource,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Option<String>> {
+    self.plugin.rename_resource(resource, context)
+  }
+
   fn handle_entry_resource(
     &self,
     resource: &mut farmfe_core::plugin::PluginHandleEntryResourceHookParams,
@@ -3,20 +3,39 @@
 use std::{collections::HashMap, sync::Arc};
 
 use farmfe_core::{
+  context::CompilationContext,
   enhanced_magic_string::bundle::Bundle,
+  module::{module_graph::ModuleGraph, ModuleMetaData},
   parking_lot::Mutex,
-  plugin::Plugin,
-  resource::resource_pot::{ResourcePotMetaData, ResourcePotType},
+  plugin::{Plugin, PluginFinalizeResourcesHookParams},
+  regex::{Captures, Regex},
+  resource::{
+    resource_pot::{ResourcePotMetaData, ResourcePotType},
+    ResourceOrigin, ResourceType,
+  },
+  swc_ecma_ast::{ExportSpecifier, ImportSpecifier, ModuleDecl, ModuleExportName, ModuleItem},
 };
-use resource_pot_to_bundle::{Polyfill, SharedBundle};
+use farmfe_toolkit::fs::relative_resource_path;
+use resource_pot_to_bundle::{Polyfill, SharedBundle, FARM_BUNDLE_REFERENCE_SLOT_PREFIX};
 
 pub mod resource_pot_to_bundle;
 
-const MODULE_NEED_POLYFILLS: [Polyfill; 3] = [
-  Polyfill::Wildcard,
-  Polyfill::InteropRequireDefault,
-  Polyfill::ExportStar,
-];
+/// key of the polyfills registered by [register_polyfill] in [CompilationContext::custom]
+pub const CUSTOM_POLYFILLS: &str = "bundle_custom_polyfills";
+
+/// register a polyfill snippet from a plugin before the resource pots are processed, e.g. in the `build_start` hook.
+/// the snippet is injected to the bundle that the builtin polyfills are injected to, see [resource_pot_to_bundle::SimplePolyfill::register]
+pub fn register_polyfill(context: &CompilationContext, name: &str, source: &str) {
+  let mut polyfills = context
+    .custom
+    .entry(CUSTOM_POLYFILLS.to_string())
+    .or_insert_with(|| Box::new(Vec::<(String, String)>::new()));
+
+  polyfills
+    .downcast_mut::<Vec<(String, String)>>()
+    .unwrap()
+    .push((name.to_string(), source.to_string()));
+}
 
 #[derive(Default)]
 pub struct FarmPluginBundle {
@@ -47,6 +66,22 @@ impl Plugin for FarmPluginBundle {
 
     resource_pots.sort_by_key(|item| item.id.clone());
 
+    // there should be only one runtime resource pot, the first one is used as the runtime and the others are ignored
+    let runtime_resource_pot_ids = resource_pots
+      .iter()
+      .filter(|item| matches!(item.resource_pot_type, ResourcePotType::Runtime))
+      .map(|item| item.id.clone())
+      .collect::<Vec<_>>();
+
+    if runtime_resource_pot_ids.len() > 1 {
+      context.log_store.lock().add_warning(format!(
+        "Found {} runtime resource pots: {}. Only `{}` is used as the runtime, the others are ignored.",
+        runtime_resource_pot_ids.len(),
+        runtime_resource_pot_ids.join(", "),
+        runtime_resource_pot_ids[0]
+      ));
+    }
+
     let r = resource_pots
       .iter()
       .filter(|item| {
@@ -72,9 +107,18 @@ impl Plugin for FarmPluginBundle {
         .unwrap()
         .polyfill;
 
-      MODULE_NEED_POLYFILLS
-        .iter()
-        .for_each(|item| polyfill.add(item.clone()));
+      module_need_polyfills(&module_graph)
+        .into_iter()
+        .for_each(|item| polyfill.add(item));
+
+      if let Some(custom_polyfills) = context.custom.get(CUSTOM_POLYFILLS) {
+        for (name, source) in custom_polyfills
+          .downcast_ref::<Vec<(String, String)>>()
+          .unwrap()
+        {
+          polyfill.register(name, source)?;
+        }
+      }
     }
 
     shared_bundle.render()?;
@@ -92,7 +136,9 @@ impl Plugin for FarmPluginBundle {
         defer_minify.push(resource_pot_id.clone());
 
         if matches!(resource_pot.resource_pot_type, ResourcePotType::Runtime) {
-          *self.runtime_code.lock() = Arc::new(bundle.to_string());
+          if resource_pot_id == runtime_resource_pot_ids[0] {
+            *self.runtime_code.lock() = Arc::new(bundle.to_string());
+          }
         } else {
           self.bundle_map.lock().insert(resource_pot_id, bundle);
         }
@@ -134,4 +180,112 @@ impl Plugin for FarmPluginBundle {
 
     Ok(None)
   }
+
+  /// replace the references to other bundles with the relative path of their resources,
+  /// e.g. `import { a } from "./shared.js"`, the resource names are only known after the resources are generated
+  fn finalize_resources(
+    &self,
+    param: &mut PluginFinalizeResourcesHookParams,
+    context: &Arc<CompilationContext>,
+  ) -> farmfe_core::error::Result<Option<()>> {
+    if !context.config.output.target_env.is_library() {
+      return Ok(None);
+    }
+
+    let bundle_resource_names = param
+      .resources_map
+      .values()
+      .filter(|resource| matches!(resource.resource_type, ResourceType::Js))
+      .filter_map(|resource| match &resource.origin {
+        ResourceOrigin::ResourcePot(resource_pot_id) => {
+          Some((resource_pot_id.clone(), resource.name.clone()))
+        }
+        ResourceOrigin::Module(_) => None,
+      })
+      .collect::<HashMap<_, _>>();
+    let slot_regex = Regex::new(&format!(
+      "{}([^\"'`]+)",
+      farmfe_core::regex::escape(FARM_BUNDLE_REFERENCE_SLOT_PREFIX)
+    ))
+    .unwrap();
+
+    for resource in param.resources_map.values_mut() {
+      if !matches!(resource.resource_type, ResourceType::Js) {
+        continue;
+      }
+
+      let code = String::from_utf8_lossy(&resource.bytes);
+
+      if !code.contains(FARM_BUNDLE_REFERENCE_SLOT_PREFIX) {
+        continue;
+      }
+
+      let code = slot_regex.replace_all(&code, |caps: &Captures| {
+        bundle_resource_names
+          .get(&caps[1])
+          .map(|name| relative_resource_path(&resource.name, name))
+          .unwrap_or_else(|| caps[0].to_string())
+      });
+      resource.bytes = code.into_owned().into_bytes();
+    }
+
+    Ok(None)
+  }
+}
+
+/// the interop helpers that the module declarations of the module graph need:
+/// - `import * as ns from` and `export * as ns from` need `_interop_require_wildcard`
+/// - `import a from` and `export { default } from` need `_interop_require_default`
+/// - `export * from` needs `_export_star`
+fn module_need_polyfills(module_graph: &ModuleGraph) -> Vec<Polyfill> {
+  let mut need_wildcard = false;
+  let mut need_default = false;
+  let mut need_export_star = false;
+
+  for module in module_graph.modules() {
+    let ModuleMetaData::Script(script) = &*module.meta else {
+      continue;
+    };
+
+    for item in &script.ast.body {
+      match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) => {
+          for specifier in &import_decl.specifiers {
+            match specifier {
+              ImportSpecifier::Namespace(_) => need_wildcard = true,
+              ImportSpecifier::Default(_) => need_default = true,
+              ImportSpecifier::Named(_) => {}
+            }
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export_named))
+          if export_named.src.is_some() =>
+        {
+          for specifier in &export_named.specifiers {
+            match specifier {
+              ExportSpecifier::Namespace(_) => need_wildcard = true,
+              ExportSpecifier::Default(_) => need_default = true,
+              ExportSpecifier::Named(named) => {
+                if matches!(&named.orig, ModuleExportName::Ident(ident) if ident.sym == *"default")
+                {
+                  need_default = true;
+                }
+              }
+            }
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportAll(_)) => need_export_star = true,
+        _ => {}
+      }
+    }
+  }
+
+  [
+    (need_wildcard, Polyfill::Wildcard),
+    (need_default, Polyfill::InteropRequireDefault),
+    (need_export_star, Polyfill::ExportStar),
+  ]
+  .into_iter()
+  .filter_map(|(need, polyfill)| need.then_some(polyfill))
+  .collect()
 }
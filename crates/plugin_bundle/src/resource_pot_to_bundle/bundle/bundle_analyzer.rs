@@ -21,7 +21,7 @@ use farmfe_core::{
 };
 use farmfe_toolkit::{
   common::build_source_map,
-  script::{codegen_module, swc_try_with::try_with, CodeGenCommentsConfig},
+  script::{codegen_module_with_indent, swc_try_with::try_with, CodeGenCommentsConfig},
   swc_ecma_transforms::fixer,
   swc_ecma_visit::VisitMutWith,
 };
@@ -33,9 +33,13 @@ use crate::resource_pot_to_bundle::{
     ExportSpecifierInfo, ImportSpecifierInfo, StmtAction, Variable,
   },
   polyfill::SimplePolyfill,
+  targets::amd::generate::AmdGenerate,
   targets::generate::{
     generate_bundle_import_by_bundle_reference, generate_export_by_reference_export,
+    generate_umd_wrapper,
   },
+  targets::iife::generate::IifeGenerate,
+  targets::system::generate::SystemGenerate,
   uniq_name::{BundleVariable, FindModuleExportResult},
 };
 
@@ -59,6 +63,8 @@ pub struct BundleAnalyzer<'a> {
 
   pub bundle_reference: BundleReference,
   pub polyfill: SimplePolyfill,
+  /// output format of the bundle, entry bundles use the format of their entry
+  format: ModuleFormat,
 }
 
 impl<'a> BundleAnalyzer<'a> {
@@ -68,6 +74,16 @@ impl<'a> BundleAnalyzer<'a> {
     context: &Arc<CompilationContext>,
     bundle_variable: Rc<RefCell<BundleVariable>>,
   ) -> Self {
+    let entry_name = resource_pot
+      .entry_module
+      .as_ref()
+      .and_then(|entry| module_graph.entries.get(entry));
+    let format = context
+      .config
+      .output
+      .format
+      .get(entry_name.map(|name| name.as_str()));
+
     Self {
       bundle_variable,
       resource_pot,
@@ -77,6 +93,7 @@ impl<'a> BundleAnalyzer<'a> {
       bundle_reference: BundleReference::new(),
       // bundle level polyfill
       polyfill: SimplePolyfill::default(),
+      format,
     }
   }
 
@@ -143,9 +160,22 @@ impl<'a> BundleAnalyzer<'a> {
           // import
           if let Some(import) = statement.import.as_ref() {
             if module_analyzer_manager.is_commonjs(&import.source) {
+              // `import './cjs'` of an esm module executes the commonjs module in the place of the esm execution order,
+              // so it's executed after its wrapper is declared like the named imports, instead of in the importer,
+              // otherwise its side effects (e.g. `globalThis.x = 1`) run after the esm modules that are ordered after it
+              let is_executed_in_order = import.specifiers.is_empty()
+                && !module_analyzer.is_commonjs()
+                && module_analyzer_manager.is_same_bundle(module_id, &import.source);
+
+              if is_executed_in_order {
+                self
+                  .bundle_reference
+                  .execute_module_for_cjs(ReferenceKind::Module(import.source.clone()));
+              }
+
               stmt_action.insert(StmtAction::StripCjsImport(
                 statement.id,
-                if import.specifiers.is_empty() {
+                if import.specifiers.is_empty() && !is_executed_in_order {
                   Some(import.source.clone())
                 } else {
                   None
@@ -210,7 +240,7 @@ impl<'a> BundleAnalyzer<'a> {
   ) -> Result<()> {
     farm_profile_function!("");
 
-    let is_format_to_commonjs = self.context.config.output.format == ModuleFormat::CommonJs;
+    let is_format_to_commonjs = !self.format.is_esm();
 
     for module_id in &self.ordered_modules {
       farm_profile_scope!(format!(
@@ -585,9 +615,7 @@ impl<'a> BundleAnalyzer<'a> {
                             }
 
                             if reference.all {
-                              if is_hybrid_dynamic
-                                && self.context.config.output.format == ModuleFormat::CommonJs
-                              {
+                              if is_hybrid_dynamic && is_format_to_commonjs {
                                 self.bundle_reference.add_import(
                                   &ImportSpecifierInfo::Namespace(
                                     module_analyzer_manager
@@ -655,10 +683,7 @@ impl<'a> BundleAnalyzer<'a> {
 
                             self.bundle_reference.add_declare_commonjs_import(
                               &if is_default_key {
-                                ImportSpecifierInfo::Named {
-                                  local: name,
-                                  imported: Some(local),
-                                }
+                                ImportSpecifierInfo::Default(name)
                               } else {
                                 ImportSpecifierInfo::Named {
                                   local,
@@ -922,6 +947,7 @@ impl<'a> BundleAnalyzer<'a> {
         order_index_map,
         &mut self.polyfill,
         &external_config,
+        &self.format,
       )?;
     }
 
@@ -937,18 +963,19 @@ impl<'a> BundleAnalyzer<'a> {
     // 1. if import by other bundle or entry export, should reexport some variable
     if !is_runtime_bundle {
       patch_export_to_module.extend(generate_export_by_reference_export(
+        &self.format,
         &self.resource_pot.id,
+        self.context.config.output.freeze_exports && self.resource_pot.entry_module.is_some(),
         &self.bundle_variable.borrow(),
         &mut self.bundle_reference,
         module_analyzer_manager,
-        &self.context,
         &mut self.polyfill,
       )?);
     }
 
     // 2. maybe import external、other bundle, should generate import
     patch_import_to_module.extend(generate_bundle_import_by_bundle_reference(
-      &self.context.config.output.format,
+      &self.format,
       &self.bundle_variable.borrow(),
       &self.bundle_reference,
       module_analyzer_manager,
@@ -1002,6 +1029,8 @@ impl<'a> BundleAnalyzer<'a> {
       trace_source_map_chain: Some(false),
     });
 
+    let mut sloppy_module = None;
+
     for module_id in &self.ordered_modules {
       let module = self
         .module_graph
@@ -1009,6 +1038,21 @@ impl<'a> BundleAnalyzer<'a> {
         .unwrap_or_else(|| panic!("Module not found: {module_id:?}"));
       let module_analyzer = module_analyzer_manager.module_analyzer_mut_unchecked(module_id);
 
+      if !module_analyzer.is_strict && sloppy_module.is_none() {
+        sloppy_module = Some((*module_id).clone());
+      }
+
+      // es module output is always strict, the wrapper of the commonjs module can not keep it in sloppy mode
+      if let (Some(usage), ModuleFormat::EsModule) =
+        (module_analyzer.sloppy_mode_usage, self.format)
+      {
+        return Err(CompilationError::GenericError(format!(
+          "Module `{}` contains {usage}, which is not allowed in strict mode. It can not be bundled into es module `{}`, please remove it or make the module external.",
+          module_id.to_string(),
+          self.resource_pot.id
+        )));
+      }
+
       let comments: SingleThreadedComments = module.meta.as_script().comments.clone().into();
 
       let sourcemap_enabled = self.context.config.sourcemap.enabled(module.immutable);
@@ -1024,7 +1068,7 @@ impl<'a> BundleAnalyzer<'a> {
       )?;
 
       let mut mappings = vec![];
-      let code_bytes = codegen_module(
+      let code_bytes = codegen_module_with_indent(
         &module_analyzer.ast,
         self.context.config.script.target,
         module_analyzer.cm.clone(),
@@ -1038,6 +1082,8 @@ impl<'a> BundleAnalyzer<'a> {
           comments: &comments,
           config: &self.context.config.comments,
         }),
+        None,
+        self.context.config.output.semicolons,
       )
       .map_err(|err| CompilationError::RenderScriptModuleError {
         id: module_analyzer.module_id.to_string(),
@@ -1103,9 +1149,72 @@ impl<'a> BundleAnalyzer<'a> {
       );
 
     if !self.polyfill.is_empty() && injectable_resource_pot {
-      for item in self.polyfill.to_str() {
-        bundle.prepend(&item);
+      self.polyfill.patch_polyfill(&mut bundle);
+    }
+
+    let is_runtime_bundle = matches!(
+      self.resource_pot.resource_pot_type,
+      ResourcePotType::Runtime
+    );
+
+    // es modules are always strict, the other formats are made strict by a single directive at the top of the bundle body,
+    // which is placed inside the wrapper so that it does not affect the code concatenated with the bundle
+    if config.output.target_env.is_library() && !is_runtime_bundle && !self.format.is_esm() {
+      if let Some(sloppy_module) = sloppy_module {
+        self.context.log_store.lock().add_warning(format!(
+          "Module `{}` is not strict mode code, the \"use strict\" directive is not emitted for `{}`.",
+          sloppy_module.to_string(),
+          self.resource_pot.id
+        ));
+      } else {
+        bundle.prepend("\"use strict\";\n");
+      }
+    }
+
+    // wrap the whole bundle including polyfills, so that the helpers do not leak to the global scope
+    let wrapper = match self.format {
+      ModuleFormat::Umd if self.resource_pot.entry_module.is_some() => {
+        let external_modules = AmdGenerate::external_dependencies(
+          &self.bundle_variable.borrow(),
+          &self.bundle_reference,
+          module_analyzer_manager,
+        )?
+        .into_iter()
+        .map(|(module_id, _)| module_id)
+        .collect::<Vec<_>>();
+
+        Some(generate_umd_wrapper(
+          config.output.name.as_deref(),
+          &external_modules,
+          &ExternalConfig::from(config),
+        ))
       }
+      ModuleFormat::Amd if !is_runtime_bundle => Some(AmdGenerate::generate_wrapper(
+        &self.bundle_variable.borrow(),
+        &self.bundle_reference,
+        module_analyzer_manager,
+      )?),
+      ModuleFormat::Iife if !is_runtime_bundle => Some(IifeGenerate::generate_wrapper(
+        config,
+        self.resource_pot.entry_module.is_some(),
+        self.bundle_reference.export.is_some()
+          || !self.bundle_reference.external_export_map.is_empty(),
+        &self.resource_pot.id,
+        &self.bundle_variable.borrow(),
+        &self.bundle_reference,
+        module_analyzer_manager,
+      )?),
+      ModuleFormat::System if !is_runtime_bundle => Some(SystemGenerate::generate_wrapper(
+        &self.bundle_variable.borrow(),
+        &self.bundle_reference,
+        module_analyzer_manager,
+      )?),
+      _ => None,
+    };
+
+    if let Some((header, footer)) = wrapper {
+      bundle.prepend(&format!("{header}\n"));
+      bundle.append(&format!("\n{footer}"), None);
     }
 
     Ok(bundle)
@@ -112,6 +112,14 @@ impl ExternalReferenceExport {
   }
 }
 
+pub const FARM_BUNDLE_REFERENCE_SLOT_PREFIX: &str = "__FARM_BUNDLE_REFERENCE_SLOT__:";
+
+/// the placeholder of a reference to another bundle, it's replaced by the relative path of the bundle's resource
+/// after the resources are generated, see [crate::FarmPluginBundle::finalize_resources]
+pub fn with_bundle_reference_slot_name(bundle_id: &str) -> String {
+  format!("{FARM_BUNDLE_REFERENCE_SLOT_PREFIX}{bundle_id}")
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, PartialOrd, Ord)]
 pub enum ReferenceKind {
   Bundle(String),
@@ -121,10 +129,18 @@ pub enum ReferenceKind {
 impl ReferenceKind {
   pub fn to_module_id(&self) -> ModuleId {
     match self {
-      ReferenceKind::Bundle(name) => ModuleId::from(name.as_str()),
+      ReferenceKind::Bundle(name) => ModuleId::from(with_bundle_reference_slot_name(name).as_str()),
       ReferenceKind::Module(id) => id.clone(),
     }
   }
+
+  /// the source of the generated import, other bundles are referenced by slot
+  pub fn to_import_source(&self) -> String {
+    match self {
+      ReferenceKind::Bundle(name) => with_bundle_reference_slot_name(name),
+      ReferenceKind::Module(id) => id.to_string(),
+    }
+  }
 }
 
 impl ToString for ReferenceKind {
@@ -6,7 +6,7 @@ use std::{
 
 use bundle_external::BundleReference;
 use farmfe_core::{
-  config::external::ExternalConfig,
+  config::{external::ExternalConfig, minify::MinifyOptions, ModuleFormat},
   context::CompilationContext,
   error::{CompilationError, MapCompletionError, Result},
   farm_profile_function, farm_profile_scope,
@@ -33,7 +33,7 @@ use self::reference::ReferenceMap;
 
 use super::{
   common::OptionToResult,
-  defined_idents_collector::RenameIdent,
+  defined_idents_collector::{preserve_renamed_decl_names, RenameIdent},
   modules_analyzer::module_analyzer::{
     ExportSpecifierInfo, ImportSpecifierInfo, ModuleAnalyzer, StmtAction,
   },
@@ -378,6 +378,28 @@ impl<'a> ModuleAnalyzerManager<'a> {
 
     let mut map = ReferenceMap::new(self.module_system(module_id));
 
+    // collect local exports first, a module that re-exports them across a cycle reads them from the unfinished map
+    for export in exports_stmts
+      .iter()
+      .filter(|export| export.source.is_none())
+    {
+      for specify in export.specifiers.iter() {
+        match specify {
+          ExportSpecifierInfo::All(_) => {
+            unreachable!("export All source should not be None")
+          }
+
+          ExportSpecifierInfo::Namespace(_) => {
+            unreachable!("export namespace source should not be None")
+          }
+
+          _ => {
+            map.add_local(specify);
+          }
+        }
+      }
+    }
+
     // preventing circular references
     if let Some(m) = self.module_analyzer_mut(module_id) {
       m.export_names = Some(Arc::new(map.clone()));
@@ -415,20 +437,6 @@ impl<'a> ModuleAnalyzerManager<'a> {
               map.add_local(specify);
             }
           }
-        } else {
-          match specify {
-            ExportSpecifierInfo::All(_) => {
-              unreachable!("export All source should not be None")
-            }
-
-            ExportSpecifierInfo::Namespace(_) => {
-              unreachable!("export namespace source should not be None")
-            }
-
-            _ => {
-              map.add_local(specify);
-            }
-          }
         }
       }
     }
@@ -455,6 +463,7 @@ impl<'a> ModuleAnalyzerManager<'a> {
     order_index_map: &HashMap<ModuleId, usize>,
     polyfill: &mut SimplePolyfill,
     external_config: &ExternalConfig,
+    format: &ModuleFormat,
   ) -> Result<()> {
     farm_profile_function!(format!(
       "patch module analyzer ast: {}",
@@ -474,6 +483,7 @@ impl<'a> ModuleAnalyzerManager<'a> {
       order_index_map,
       polyfill,
       external_config,
+      format,
     )?;
 
     Ok(())
@@ -654,6 +664,7 @@ impl<'a> ModuleAnalyzerManager<'a> {
     order_index_map: &HashMap<ModuleId, usize>,
     polyfill: &mut SimplePolyfill,
     external_config: &ExternalConfig,
+    format: &ModuleFormat,
   ) -> Result<()> {
     farm_profile_function!("");
 
@@ -694,6 +705,7 @@ impl<'a> ModuleAnalyzerManager<'a> {
         &mut patch_asts,
         bundle_variable,
         bundle_reference,
+        order_index_map,
         polyfill,
       );
 
@@ -721,7 +733,8 @@ impl<'a> ModuleAnalyzerManager<'a> {
             bundle_variable,
             &context.config,
             polyfill,
-            external_config
+            external_config,
+            format,
           )
         }
 
@@ -737,8 +750,18 @@ impl<'a> ModuleAnalyzerManager<'a> {
           self,
           module_id,
           bundle_variable,
+          format,
         ));
 
+        if let Some(minify_options) = Option::<MinifyOptions>::from(&*context.config.minify) {
+          preserve_renamed_decl_names(
+            &mut ast,
+            &rename_map,
+            minify_options.should_keep_fnames(),
+            minify_options.should_keep_classnames(),
+          );
+        }
+
         ast.visit_mut_with(&mut RenameIdent::new(rename_map));
 
         self.set_ast(module_id, ast);
@@ -869,6 +892,13 @@ impl<'a> ModuleAnalyzerManager<'a> {
                     .add_default(&module_analyzer.module_id, |s| {
                       bundle_variable.register_used_name_by_module_id(&module_analyzer.module_id, s, root)
                     });
+
+                  // export { default as foo } from './commonjs', commonjs module does not register default name by itself
+                  if let Some(source) = s.source.as_ref().filter(|source| self.is_commonjs(source)) {
+                    self
+                      .module_global_uniq_name
+                      .add_default(source, |s| bundle_variable.register_used_name_by_module_id(source, s, root));
+                  }
                 }
               }
             }
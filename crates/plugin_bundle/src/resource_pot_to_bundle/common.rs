@@ -6,12 +6,13 @@ use farmfe_core::{
   farm_profile_function,
   module::Module,
   swc_common::{comments::SingleThreadedComments, Mark},
-  swc_ecma_ast::{Module as EcmaAstModule, ModuleItem},
+  swc_ecma_ast::{Expr, MemberExpr, MemberProp, Module as EcmaAstModule, ModuleItem, WithStmt},
   swc_ecma_parser::{lexer::Lexer, EsSyntax as EsConfig, Parser, StringInput, Syntax},
 };
 use farmfe_toolkit::{
   common::{create_swc_source_map, Source},
-  script::swc_try_with::resolve_module_mark,
+  script::{directive, directive_prologue_len, swc_try_with::resolve_module_mark},
+  swc_ecma_visit::{Visit, VisitWith},
 };
 
 pub fn get_module_mark(
@@ -29,6 +30,60 @@ pub fn get_module_mark(
   }
 }
 
+/// find the code that only works in sloppy mode, which is not allowed in strict mode code like the bundle,
+/// e.g. `with` statement and `arguments.callee`
+pub fn find_sloppy_mode_usage(ast: &EcmaAstModule) -> Option<&'static str> {
+  struct SloppyModeFinder(Option<&'static str>);
+
+  impl Visit for SloppyModeFinder {
+    fn visit_with_stmt(&mut self, _: &WithStmt) {
+      self.0.get_or_insert("a `with` statement");
+    }
+
+    fn visit_member_expr(&mut self, n: &MemberExpr) {
+      if let (Expr::Ident(obj), MemberProp::Ident(prop)) = (&*n.obj, &n.prop) {
+        if obj.sym == "arguments" && (prop.sym == "callee" || prop.sym == "caller") {
+          self.0.get_or_insert(if prop.sym == "callee" {
+            "`arguments.callee`"
+          } else {
+            "`arguments.caller`"
+          });
+        }
+      }
+
+      n.visit_children_with(self);
+    }
+  }
+
+  let mut finder = SloppyModeFinder(None);
+  ast.visit_with(&mut finder);
+
+  finder.0
+}
+
+/// whether the module declares `"use strict"` in its directive prologue
+pub fn has_use_strict_directive(ast: &EcmaAstModule) -> bool {
+  ast.body[..directive_prologue_len(ast)]
+    .iter()
+    .any(|item| directive(item).as_deref() == Some("use strict"))
+}
+
+/// remove the `"use strict"` directives at the top of the module, they do not take effect once the module is concatenated
+/// into the bundle, the bundle is made strict by a single directive instead
+pub fn strip_use_strict_directives(ast: &mut EcmaAstModule) {
+  let prologue_len = directive_prologue_len(ast);
+
+  let body = std::mem::take(&mut ast.body);
+  ast.body = body
+    .into_iter()
+    .enumerate()
+    .filter(|(index, item)| {
+      *index >= prologue_len || directive(item).as_deref() != Some("use strict")
+    })
+    .map(|(_, item)| item)
+    .collect();
+}
+
 pub fn parse_module_item(string: &str) -> Result<ModuleItem> {
   let (_, source_file) = create_swc_source_map(Source {
     path: PathBuf::from("unknown"),
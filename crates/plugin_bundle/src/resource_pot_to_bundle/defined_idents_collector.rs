@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet};
 use farmfe_core::{
   swc_common::DUMMY_SP,
   swc_ecma_ast::{
-    AssignExpr, AssignOp, AssignTarget, BindingIdent, Expr, Id, KeyValuePatProp, KeyValueProp, MemberProp, ObjectPat, ObjectPatProp, Pat, Prop, PropName, PropOrSpread, SimpleAssignTarget
+    AssignExpr, AssignOp, AssignTarget, BindingIdent, CallExpr, Callee, ClassDecl, Decl, Expr, ExprStmt, FnDecl, Id, KeyValuePatProp, KeyValueProp, Lit, MemberExpr, MemberProp, Module, ModuleItem, ObjectLit, ObjectPat, ObjectPatProp, Pat, Prop, PropName, PropOrSpread, SimpleAssignTarget, Stmt
   },
 };
 use farmfe_toolkit::{
@@ -225,3 +225,80 @@ impl<'a> VisitMut for RenameIdent<'a> {
     n.visit_mut_children_with(self);
   }
 }
+
+/// Keep the `name` of the top level functions and classes that are renamed to avoid conflicts in the bundle when
+/// `minify.keepFnames` or `minify.keepClassnames` is enabled, the renamed idents are rendered by [RenameIdent] later.
+///
+/// ```js
+/// class Foo$1 {}
+/// Object.defineProperty(Foo$1, "name", { value: "Foo", configurable: true });
+/// ```
+pub fn preserve_renamed_decl_names(
+  ast: &mut Module,
+  rename_map: &HashMap<&Id, &Var>,
+  keep_fnames: bool,
+  keep_classnames: bool,
+) {
+  if !keep_fnames && !keep_classnames {
+    return;
+  }
+
+  let renamed_decls = ast
+    .body
+    .iter()
+    .enumerate()
+    .filter_map(|(index, item)| {
+      let ident = match item {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl { ident, .. }))) if keep_fnames => ident,
+        ModuleItem::Stmt(Stmt::Decl(Decl::Class(ClassDecl { ident, .. }))) if keep_classnames => {
+          ident
+        }
+        _ => return None,
+      };
+
+      rename_map
+        .get(&ident.to_id())
+        .filter(|var| var.render_name() != ident.sym.as_str())
+        .map(|_| (index, ident.clone()))
+    })
+    .collect::<Vec<_>>();
+
+  for (index, ident) in renamed_decls.into_iter().rev() {
+    ast.body.insert(index + 1, define_name_property(ident));
+  }
+}
+
+fn define_name_property(ident: Ident) -> ModuleItem {
+  let prop = |key: &str, value: Lit| {
+    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+      key: PropName::Ident(key.into()),
+      value: Box::new(Expr::Lit(value)),
+    })))
+  };
+  let name = ident.sym.to_string();
+
+  ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+    span: DUMMY_SP,
+    expr: Box::new(Expr::Call(CallExpr {
+      span: DUMMY_SP,
+      callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+        span: DUMMY_SP,
+        obj: Box::new(Expr::Ident("Object".into())),
+        prop: MemberProp::Ident("defineProperty".into()),
+      }))),
+      args: vec![
+        Expr::Ident(ident).into(),
+        Expr::Lit("name".into()).into(),
+        Expr::Object(ObjectLit {
+          span: DUMMY_SP,
+          props: vec![
+            prop("value", name.as_str().into()),
+            prop("configurable", true.into()),
+          ],
+        })
+        .into(),
+      ],
+      type_args: None,
+    })),
+  }))
+}
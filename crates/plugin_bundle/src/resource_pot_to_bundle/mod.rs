@@ -16,9 +16,10 @@ use farmfe_core::{
   resource::resource_pot::{ResourcePot, ResourcePotId, ResourcePotType},
   swc_ecma_ast::Id,
 };
-pub use polyfill::{Polyfill, SimplePolyfill};
+pub use polyfill::{Polyfill, PolyfillOrder, SimplePolyfill};
 
 pub use crate::resource_pot_to_bundle::bundle::bundle_analyzer::BundleAnalyzer;
+pub use crate::resource_pot_to_bundle::bundle::bundle_external::FARM_BUNDLE_REFERENCE_SLOT_PREFIX;
 
 use self::{
   bundle::ModuleAnalyzerManager, modules_analyzer::module_analyzer::ModuleAnalyzer,
@@ -213,13 +214,22 @@ impl<'a> SharedBundle<'a> {
     farm_profile_function!("");
 
     let bundle_variable = &mut self.bundle_variables.borrow_mut();
+    // the polyfills of all bundles may be merged into the runtime bundle
+    let custom_reserved_word = self
+      .bundle_map
+      .values()
+      .flat_map(|bundle| bundle.polyfill.custom_reserved_word())
+      .collect::<Vec<_>>();
 
     for resource_pot_id in &self.order_resource_pot {
       bundle_variable.set_namespace(resource_pot_id.clone());
 
       // polyfill name should make sure it doesn't conflict.
       // tip: but it cannot be rename unresolved mark
-      for name in SimplePolyfill::reserved_word() {
+      for name in SimplePolyfill::reserved_word()
+        .into_iter()
+        .chain(custom_reserved_word.iter().cloned())
+      {
         bundle_variable.add_used_name(name);
       }
     }
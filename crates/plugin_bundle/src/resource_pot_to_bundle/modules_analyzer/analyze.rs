@@ -238,6 +238,8 @@ impl<'a> Visit for AnalyzeModuleItem<'a> {
         }
       },
 
+      // `export {}` only marks the module as esm, its export info has no specifiers
+      // so the statement is stripped without rendering any export
       ModuleDecl::ExportNamed(export_named) => {
         let mut specifiers = vec![];
 
@@ -7,7 +7,7 @@ use std::{
 
 use farmfe_core::{
   context::CompilationContext,
-  error::Result,
+  error::{CompilationError, Result},
   farm_profile_function,
   module::{module_graph::ModuleGraph, Module, ModuleId, ModuleSystem, ModuleType},
   resource::resource_pot::ResourcePotId,
@@ -21,8 +21,13 @@ use farmfe_toolkit::{
 };
 
 use crate::resource_pot_to_bundle::{
-  bundle::reference::ReferenceMap, common::get_module_mark, targets::cjs::CjsModuleAnalyzer,
-  uniq_name::BundleVariable, Var,
+  bundle::reference::ReferenceMap,
+  common::{
+    find_sloppy_mode_usage, get_module_mark, has_use_strict_directive, strip_use_strict_directives,
+  },
+  targets::cjs::CjsModuleAnalyzer,
+  uniq_name::BundleVariable,
+  Var,
 };
 
 use super::analyze::{self, CollectUnresolvedIdent};
@@ -243,6 +248,10 @@ pub struct ModuleAnalyzer {
   pub mark: (Mark, Mark),
   pub module_system: ModuleSystem,
   pub module_type: ModuleType,
+  /// whether the module is strict mode code, a sloppy commonjs module keeps the bundle from being made strict
+  pub is_strict: bool,
+  /// the code that only works in sloppy mode, see [find_sloppy_mode_usage]
+  pub sloppy_mode_usage: Option<&'static str>,
 }
 
 impl Debug for ModuleAnalyzer {
@@ -278,6 +287,30 @@ impl ModuleAnalyzer {
     farm_profile_function!(format!("module analyzer {}", module.id.to_string()));
     let mut ast = module.meta.as_script().ast.clone();
 
+    let is_commonjs = matches!(
+      module.meta.as_script().module_system,
+      ModuleSystem::CommonJs | ModuleSystem::Hybrid
+    );
+    let sloppy_mode_usage = find_sloppy_mode_usage(&ast);
+
+    // es modules are concatenated into strict mode code, the code that requires sloppy mode is not allowed there
+    if !is_commonjs && !module.external {
+      if let Some(usage) = sloppy_mode_usage {
+        return Err(CompilationError::GenericError(format!(
+          "Module `{}` contains {usage}, which is not allowed in strict mode. It can not be bundled into `{}`, please remove it or make the module external.",
+          module.id.to_string(),
+          resource_pot_id
+        )));
+      }
+    }
+
+    // the wrapped commonjs modules are strict only when they declare the directive themselves, which applies to their wrapper functions
+    let is_strict = !is_commonjs || (sloppy_mode_usage.is_none() && has_use_strict_directive(&ast));
+
+    if !is_commonjs {
+      strip_use_strict_directives(&mut ast);
+    }
+
     let (cm, _) = create_swc_source_map(Source {
       path: PathBuf::from(module.id.resolved_path_with_query(&context.config.root)),
       content: module.content.clone(),
@@ -304,6 +337,8 @@ impl ModuleAnalyzer {
       mark: mark.unwrap(),
       module_system: module.meta.as_script().module_system.clone(),
       module_type: module.module_type.clone(),
+      is_strict,
+      sloppy_mode_usage,
     })
   }
 
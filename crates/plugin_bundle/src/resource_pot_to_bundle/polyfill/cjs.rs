@@ -48,3 +48,17 @@ pub fn wrap_commonjs(args: Vec<ExprOrSpread>, polyfill: &mut SimplePolyfill) ->
     type_args: None,
   }))
 }
+
+/// `_export_es_module(exports)`, see [Polyfill::ExportEsModule]
+pub fn export_es_module(exports: Box<Expr>, polyfill: &mut SimplePolyfill) -> Box<Expr> {
+  polyfill.add(Polyfill::ExportEsModule);
+  Box::new(Expr::Call(CallExpr {
+    span: DUMMY_SP,
+    callee: Callee::Expr(Box::new(Expr::Ident("_export_es_module".into()))),
+    args: vec![ExprOrSpread {
+      spread: None,
+      expr: exports,
+    }],
+    type_args: None,
+  }))
+}
@@ -1,10 +1,27 @@
-use std::collections::HashSet;
-
-use farmfe_core::{error::Result, farm_profile_scope, swc_ecma_ast::ModuleItem};
+use std::collections::HashMap;
+
+use farmfe_core::{
+  enhanced_magic_string::bundle::Bundle,
+  error::Result,
+  farm_profile_scope,
+  swc_ecma_ast::{Decl, EsVersion, ModuleItem, Stmt},
+  swc_ecma_parser::Syntax,
+};
+use farmfe_toolkit::script::parse_module;
 
 use super::common::parse_module_item;
 pub mod cjs;
 
+/// where the polyfill is injected relative to the module code of the bundle
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum PolyfillOrder {
+  /// injected before the module code, e.g. helpers that are referenced by the module code
+  #[default]
+  Before,
+  /// injected after the module code, e.g. polyfills that depend on the setup of the module code
+  After,
+}
+
 // TODO: global polyfill
 #[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub enum Polyfill {
@@ -55,6 +72,13 @@ pub enum Polyfill {
   /// const fs = _interop_require_default(node_fs);
   /// ```
   InteropRequireDefault,
+  /// mark the exports of es modules in cjs, the bundle and the wrapped modules share the helper
+  /// ```ts
+  /// Object.defineProperty(exports, "__esModule", { value: true });
+  /// // =>
+  /// _export_es_module(exports);
+  /// ```
+  ExportEsModule,
   ///
   /// support use require in esm
   ///
@@ -67,16 +91,34 @@ pub enum Polyfill {
   NodeEsmGlobalRequireHelper,
 
   ///
-  /// browser external load
+  /// declare `require` for the native es modules of `node-next`, the `require` calls are kept as they are
+  ///
+  /// ```ts
+  /// import { createRequire } from 'module';
+  /// var require = createRequire(import.meta.url);
+  /// ```
+  ///
+  NodeEsmCreateRequire,
+
+  ///
+  /// browser external load, the external is read from the global object of `output.globalObject`
   /// ```ts
   /// const events = require("events");
   /// // =>
   /// loadExternalRequire('events');
   /// ```
   ///
-  BrowserExternalRequire,
+  BrowserExternalRequire(String),
+
+  /// polyfill registered by [SimplePolyfill::register], e.g. a `globalThis` shim
+  Custom(CustomPolyfillName),
 }
 
+/// the name of a polyfill registered by [SimplePolyfill::register]. It's only created by the registration,
+/// so the snippet of a [Polyfill::Custom] is always registered to the [SimplePolyfill] that contains it
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct CustomPolyfillName(String);
+
 impl Polyfill {
   fn to_str(&self) -> Vec<String> {
     match self {
@@ -179,6 +221,15 @@ function _interop_require_default(obj) {
         default: obj
     };
 }
+"#),
+      ],
+      Polyfill::ExportEsModule => vec![
+        (r#"
+function _export_es_module(exports) {
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+}
 "#),
       ],
       Polyfill::NodeEsmGlobalRequireHelper => vec![
@@ -187,28 +238,40 @@ import __farmNodeModule from 'module';
 global.nodeRequire = __farmNodeModule.createRequire(import.meta.url);
 "#,
       ],
-      Polyfill::BrowserExternalRequire => vec![
+      Polyfill::NodeEsmCreateRequire => vec![
         r#"
-function loadExternalRequire(name) {
-  var _g = (window || {});
+import { createRequire } from 'module';
+var require = createRequire(import.meta.url);
+"#,
+      ],
+      Polyfill::BrowserExternalRequire(global_object) => {
+        return vec![format!(
+          r#"
+function loadExternalRequire(name) {{
+  var _g = ({global_object} || {{}});
   var m = _g[name];
-  var assign = function() {
+  var assign = function() {{
     var args = Array.prototype.slice.call(arguments);
     var target = args.shift();
     var hasOwnProperty = Object.hasOwnProperty;
-    for(var i = 0; i < args.length; i ++) {
-      for(var key in args[i]) {
-        if(!hasOwnProperty.call(target, key)) {
+    for(var i = 0; i < args.length; i ++) {{
+      for(var key in args[i]) {{
+        if(!hasOwnProperty.call(target, key)) {{
           target[key] = args[i][key];
-        }
-      }
-    }
+        }}
+      }}
+    }}
     return target;
-  }
-  return m ? m.default && !m.__esModule ? assign({}, m, {__esModule: true}) : (assign({}, m)) : m;
-};
-        "#,
-      ],
+  }}
+  return m ? m.default && !m.__esModule ? assign({{}}, m, {{__esModule: true}}) : (assign({{}}, m)) : m;
+}};
+        "#
+        )
+        .trim()
+        .to_string()]
+      }
+      // the source of custom polyfills is stored in [SimplePolyfill]
+      Polyfill::Custom(_) => vec![],
     }
     .into_iter()
     .map(|item| item.trim().into())
@@ -229,6 +292,11 @@ function loadExternalRequire(name) {
     vec![]
   }
 
+  /// the default injection order of the polyfill, can be overridden by [SimplePolyfill::add_with_order]
+  fn order(&self) -> PolyfillOrder {
+    PolyfillOrder::Before
+  }
+
   fn name(&self) -> Vec<String> {
     (match self {
       Polyfill::WrapCommonJs => vec!["__commonJs"],
@@ -236,8 +304,11 @@ function loadExternalRequire(name) {
       Polyfill::Wildcard => vec!["_getRequireWildcardCache", "_interop_require_wildcard"],
       Polyfill::ExportStar => vec!["_export_star"],
       Polyfill::InteropRequireDefault => vec!["_interop_require_default"],
+      Polyfill::ExportEsModule => vec!["_export_es_module"],
       Polyfill::NodeEsmGlobalRequireHelper => vec!["__farmNodeModule"],
-      Polyfill::BrowserExternalRequire => vec!["loadExternalRequire"],
+      Polyfill::NodeEsmCreateRequire => vec!["createRequire", "require"],
+      Polyfill::BrowserExternalRequire(_) => vec!["loadExternalRequire"],
+      Polyfill::Custom(_) => vec![],
     })
     .into_iter()
     .map(|item| item.into())
@@ -245,46 +316,119 @@ function loadExternalRequire(name) {
   }
 }
 
+#[derive(Debug, Clone)]
+struct CustomPolyfill {
+  source: String,
+  ast: Vec<ModuleItem>,
+  /// top level names declared by the snippet
+  names: Vec<String>,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct SimplePolyfill {
-  polyfills: HashSet<Polyfill>,
+  polyfills: HashMap<Polyfill, PolyfillOrder>,
+  custom: HashMap<String, CustomPolyfill>,
 }
 
 impl SimplePolyfill {
   pub fn new(polyfill: Vec<Polyfill>) -> Self {
-    let mut polyfills = HashSet::new();
+    let mut polyfills = HashMap::new();
 
-    polyfills.extend(polyfill);
+    polyfills.extend(polyfill.into_iter().map(|item| {
+      let order = item.order();
+      (item, order)
+    }));
 
-    Self { polyfills }
+    Self {
+      polyfills,
+      custom: HashMap::new(),
+    }
+  }
+
+  /// register a custom polyfill snippet, the snippet is parsed once and injected like the builtin polyfills.
+  /// the top level names declared by the snippet are reserved when the modules are linked
+  ///
+  /// ```ts
+  /// var _global_this = typeof globalThis !== "undefined" ? globalThis : window;
+  /// ```
+  pub fn register(&mut self, name: &str, source: &str) -> Result<()> {
+    if !self.custom.contains_key(name) {
+      let source = source.trim().to_string();
+      let ast = parse_module(
+        name,
+        &source,
+        Syntax::Es(Default::default()),
+        EsVersion::EsNext,
+      )?
+      .ast
+      .body;
+      let names = ast.iter().flat_map(declared_names).collect();
+
+      self
+        .custom
+        .insert(name.to_string(), CustomPolyfill { source, ast, names });
+    }
+
+    self.add(Polyfill::Custom(CustomPolyfillName(name.to_string())));
+
+    Ok(())
   }
 
   pub fn add(&mut self, polyfill: Polyfill) {
-    if self.polyfills.contains(&polyfill) {
+    let order = polyfill.order();
+    self.add_with_order(polyfill, order);
+  }
+
+  /// add polyfill with the order hint, the first added order wins when the polyfill is added multiple times
+  pub fn add_with_order(&mut self, polyfill: Polyfill, order: PolyfillOrder) {
+    if self.polyfills.contains_key(&polyfill) {
       return;
     }
 
     let dependents = polyfill.dependents();
 
-    self.polyfills.insert(polyfill);
+    self.polyfills.insert(polyfill, order);
 
     dependents.into_iter().for_each(|dep| self.add(dep));
   }
 
   pub fn contain(&self, polyfill: &Polyfill) -> bool {
-    self.polyfills.contains(polyfill)
+    self.polyfills.contains_key(polyfill)
+  }
+
+  fn sorted_polyfills(&self, order: Option<PolyfillOrder>) -> Vec<&Polyfill> {
+    let mut polyfills = self
+      .polyfills
+      .iter()
+      .filter(|(_, item_order)| order.is_none() || order == Some(**item_order))
+      .map(|(polyfill, _)| polyfill)
+      .collect::<Vec<_>>();
+
+    polyfills.sort();
+
+    polyfills
+  }
+
+  fn polyfill_ast(&self, polyfill: &Polyfill) -> Result<Vec<ModuleItem>> {
+    match polyfill {
+      Polyfill::Custom(CustomPolyfillName(name)) => Ok(self.custom[name].ast.clone()),
+      _ => polyfill.to_ast(),
+    }
+  }
+
+  fn polyfill_str(&self, polyfill: &Polyfill) -> Vec<String> {
+    match polyfill {
+      Polyfill::Custom(CustomPolyfillName(name)) => vec![self.custom[name].source.clone()],
+      _ => polyfill.to_str(),
+    }
   }
 
   pub fn to_ast(&self) -> Result<Vec<ModuleItem>> {
     farm_profile_scope!("polyfill to ast");
     let mut asts = vec![];
 
-    let mut polyfills = self.polyfills.iter().collect::<Vec<_>>();
-
-    polyfills.sort();
-
-    for polyfill in &polyfills {
-      asts.extend(polyfill.to_ast()?)
+    for polyfill in self.sorted_polyfills(None) {
+      asts.extend(self.polyfill_ast(polyfill)?)
     }
 
     Ok(asts)
@@ -294,15 +438,29 @@ impl SimplePolyfill {
     farm_profile_scope!("polyfill to str");
     let mut str_list = vec![];
 
-    let mut polyfills = self.polyfills.iter().collect::<Vec<_>>();
+    for polyfill in self.sorted_polyfills(None) {
+      str_list.extend(self.polyfill_str(polyfill))
+    }
 
-    polyfills.sort();
+    str_list
+  }
+
+  /// inject polyfills to the bundle, [PolyfillOrder::Before] polyfills are prepended to the module code
+  /// and [PolyfillOrder::After] polyfills are appended to it.
+  pub fn patch_polyfill(&self, bundle: &mut Bundle) {
+    farm_profile_scope!("patch polyfill");
 
-    for polyfill in &polyfills {
-      str_list.extend(polyfill.to_str())
+    for polyfill in self.sorted_polyfills(Some(PolyfillOrder::Before)) {
+      for item in self.polyfill_str(polyfill) {
+        bundle.prepend(&item);
+      }
     }
 
-    str_list
+    for polyfill in self.sorted_polyfills(Some(PolyfillOrder::After)) {
+      for item in self.polyfill_str(polyfill) {
+        bundle.append(&format!("\n{item}"), None);
+      }
+    }
   }
 
   pub fn is_empty(&self) -> bool {
@@ -316,14 +474,116 @@ impl SimplePolyfill {
       Polyfill::Wildcard,
       Polyfill::ExportStar,
       Polyfill::InteropRequireDefault,
+      Polyfill::ExportEsModule,
       Polyfill::NodeEsmGlobalRequireHelper,
+      Polyfill::NodeEsmCreateRequire,
     ]
     .into_iter()
     .flat_map(|polyfill| polyfill.name())
     .collect()
   }
 
+  /// the names declared by the registered polyfills, they should not conflict with the module names like [SimplePolyfill::reserved_word]
+  pub fn custom_reserved_word(&self) -> Vec<String> {
+    self
+      .custom
+      .values()
+      .flat_map(|polyfill| polyfill.names.clone())
+      .collect()
+  }
+
   pub fn extends(&mut self, polyfill: &SimplePolyfill) {
-    self.polyfills.extend(polyfill.polyfills.clone());
+    for (name, custom) in &polyfill.custom {
+      self
+        .custom
+        .entry(name.clone())
+        .or_insert_with(|| custom.clone());
+    }
+
+    for (item, order) in &polyfill.polyfills {
+      self.add_with_order(item.clone(), *order);
+    }
+  }
+}
+
+fn declared_names(item: &ModuleItem) -> Vec<String> {
+  match item {
+    ModuleItem::Stmt(Stmt::Decl(Decl::Fn(fn_decl))) => vec![fn_decl.ident.sym.to_string()],
+    ModuleItem::Stmt(Stmt::Decl(Decl::Class(class_decl))) => {
+      vec![class_decl.ident.sym.to_string()]
+    }
+    ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => var_decl
+      .decls
+      .iter()
+      .filter_map(|decl| decl.name.as_ident().map(|ident| ident.sym.to_string()))
+      .collect(),
+    _ => vec![],
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use farmfe_core::enhanced_magic_string::{
+    bundle::{Bundle, BundleOptions},
+    magic_string::MagicString,
+  };
+
+  use super::{CustomPolyfillName, Polyfill, PolyfillOrder, SimplePolyfill};
+
+  #[test]
+  fn patch_polyfill_with_order() {
+    let mut polyfill = SimplePolyfill::default();
+    polyfill.add(Polyfill::InteropRequireDefault);
+    polyfill.add_with_order(Polyfill::ExportStar, PolyfillOrder::After);
+    // the first added order wins
+    polyfill.add(Polyfill::ExportStar);
+
+    let mut bundle = Bundle::new(BundleOptions::default());
+    bundle
+      .add_source(MagicString::new("console.log('module');", None), None)
+      .unwrap();
+
+    polyfill.patch_polyfill(&mut bundle);
+
+    let code = bundle.to_string();
+    let before = code.find("function _interop_require_default").unwrap();
+    let module = code.find("console.log('module');").unwrap();
+    let after = code.find("function _export_star").unwrap();
+
+    assert!(before < module);
+    assert!(module < after);
+  }
+
+  #[test]
+  fn register_custom_polyfill() {
+    let shim = r#"var _global_this = typeof globalThis !== "undefined" ? globalThis : window;"#;
+
+    let mut polyfill = SimplePolyfill::default();
+    polyfill.register("global_this", shim).unwrap();
+    polyfill.add(Polyfill::InteropRequireDefault);
+
+    // the same polyfill is registered by another bundle and merged
+    let mut other = SimplePolyfill::default();
+    other.register("global_this", shim).unwrap();
+    polyfill.extends(&other);
+    polyfill.register("global_this", shim).unwrap();
+
+    assert!(polyfill.contain(&Polyfill::Custom(CustomPolyfillName(
+      "global_this".to_string()
+    ))));
+    assert_eq!(polyfill.custom_reserved_word(), vec!["_global_this"]);
+    assert_eq!(polyfill.to_ast().unwrap().len(), 2);
+
+    let mut bundle = Bundle::new(BundleOptions::default());
+    bundle
+      .add_source(MagicString::new("console.log(_global_this);", None), None)
+      .unwrap();
+
+    polyfill.patch_polyfill(&mut bundle);
+
+    let code = bundle.to_string();
+    assert_eq!(code.matches(shim).count(), 1);
+    assert!(code.find(shim).unwrap() < code.find("console.log(_global_this);").unwrap());
+    assert!(code.contains("function _interop_require_default"));
   }
 }
@@ -0,0 +1,243 @@
+use farmfe_core::{
+  error::Result,
+  module::{ModuleId, ModuleSystem},
+  serde_json,
+  swc_common::DUMMY_SP,
+  swc_ecma_ast::{
+    CallExpr, Callee, Expr, ExprOrSpread, KeyValueProp, Lit, MemberExpr, MemberProp, ModuleItem,
+    ObjectLit, Prop, PropName, PropOrSpread, ReturnStmt, Stmt,
+  },
+};
+
+use crate::resource_pot_to_bundle::{
+  bundle::{
+    bundle_external::{BundleReference, ExternalReferenceExport, ReferenceKind},
+    ModuleAnalyzerManager,
+  },
+  common::OptionToResult,
+  targets::generate::wrap_object_freeze,
+  uniq_name::BundleVariable,
+};
+
+// define(["foo"], function (foo_ns) {
+//   var name = foo_ns.name;
+//   ...
+//   return { name: name, default: foo_default };
+// });
+
+pub struct AmdGenerate {}
+
+impl AmdGenerate {
+  /// collect the exports of the bundle to the object returned by the amd factory
+  /// ```js
+  /// export { a, b as c };
+  /// export default foo;
+  /// export * from "external";
+  /// // =>
+  /// return Object.assign({}, external_ns, { a: a, c: b, default: foo, __esModule: true });
+  /// ```
+  pub fn generate_export(
+    exports: Vec<(Option<&ModuleId>, &ExternalReferenceExport)>,
+    freeze_exports: bool,
+    bundle_variable: &BundleVariable,
+    module_analyzer_manager: &ModuleAnalyzerManager,
+  ) -> Result<Vec<ModuleItem>> {
+    let Some(return_value) =
+      Self::exports_object(exports, true, bundle_variable, module_analyzer_manager)?
+    else {
+      return Ok(vec![]);
+    };
+
+    Ok(vec![ModuleItem::Stmt(Stmt::Return(ReturnStmt {
+      span: DUMMY_SP,
+      arg: Some(if freeze_exports {
+        wrap_object_freeze(return_value)
+      } else {
+        return_value
+      }),
+    }))])
+  }
+
+  /// the object that contains all the exports of the bundle, None if the bundle has no exports.
+  /// `__esModule: true` is added for es modules when `es_module_flag` is true
+  pub fn exports_object(
+    exports: Vec<(Option<&ModuleId>, &ExternalReferenceExport)>,
+    es_module_flag: bool,
+    bundle_variable: &BundleVariable,
+    module_analyzer_manager: &ModuleAnalyzerManager,
+  ) -> Result<Option<Box<Expr>>> {
+    let mut props = vec![];
+    let mut export_star_sources = vec![];
+    let mut is_es_module = false;
+
+    let key_value = |key: &str, value: Expr| {
+      PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+        key: PropName::Ident(key.into()),
+        value: Box::new(value),
+      })))
+    };
+
+    for (source, export) in exports {
+      let mut ordered_keys = export.named.keys().collect::<Vec<_>>();
+
+      ordered_keys.sort_by_key(|a| bundle_variable.name(**a));
+
+      for exported in ordered_keys {
+        let local = &export.named[exported];
+        if bundle_variable.var_by_index(*local).removed {
+          continue;
+        }
+
+        props.push(key_value(
+          &bundle_variable.name(*exported),
+          Expr::Ident(bundle_variable.render_name(*local).as_str().into()),
+        ));
+      }
+
+      if let Some(namespace) = export.namespace.as_ref() {
+        props.push(key_value(
+          &bundle_variable.name(*namespace),
+          Expr::Ident(bundle_variable.render_name(*namespace).as_str().into()),
+        ));
+      }
+
+      if let Some(source) = source {
+        if export.all.0
+          && (module_analyzer_manager.is_external(source)
+            || module_analyzer_manager.is_commonjs(source))
+        {
+          let ns = module_analyzer_manager
+            .module_global_uniq_name
+            .namespace_name(source)
+            .to_result("export to amd cannot find variable")?;
+
+          export_star_sources.push(Expr::Ident(bundle_variable.render_name(ns).as_str().into()));
+        }
+      }
+
+      if let Some(default) = export.default.as_ref() {
+        props.push(key_value(
+          "default",
+          Expr::Ident(bundle_variable.render_name(*default).as_str().into()),
+        ));
+      }
+
+      is_es_module |= matches!(
+        export.module_system,
+        ModuleSystem::EsModule | ModuleSystem::Hybrid
+      );
+    }
+
+    if props.is_empty() && export_star_sources.is_empty() {
+      return Ok(None);
+    }
+
+    if is_es_module && es_module_flag {
+      props.push(key_value("__esModule", Expr::Lit(Lit::Bool(true.into()))));
+    }
+
+    let exports_obj = Expr::Object(ObjectLit {
+      span: DUMMY_SP,
+      props,
+    });
+
+    // Object.assign({}, external_ns, { ... })
+    let exports_value = if export_star_sources.is_empty() {
+      exports_obj
+    } else {
+      Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+          span: DUMMY_SP,
+          obj: Box::new(Expr::Ident("Object".into())),
+          prop: MemberProp::Ident("assign".into()),
+        }))),
+        args: [Expr::Object(ObjectLit {
+          span: DUMMY_SP,
+          props: vec![],
+        })]
+        .into_iter()
+        .chain(export_star_sources)
+        .chain([exports_obj])
+        .map(|expr| ExprOrSpread {
+          spread: None,
+          expr: Box::new(expr),
+        })
+        .collect(),
+        type_args: None,
+      })
+    };
+
+    Ok(Some(Box::new(exports_value)))
+  }
+
+  /// external modules imported by the bundle, paired with the namespace variable the factory receives them as.
+  /// side effect only imports do not need a parameter, they are put at the end
+  pub fn external_dependencies(
+    bundle_variable: &BundleVariable,
+    bundle_reference: &BundleReference,
+    module_analyzer_manager: &ModuleAnalyzerManager,
+  ) -> Result<Vec<(ModuleId, Option<String>)>> {
+    let mut dependencies = vec![];
+    let mut side_effect_dependencies = vec![];
+
+    let mut ordered_import = bundle_reference.import_map.keys().collect::<Vec<_>>();
+    ordered_import.sort();
+
+    for source in ordered_import {
+      let module_id = match source {
+        ReferenceKind::Bundle(_) => continue,
+        ReferenceKind::Module(m) => m,
+      };
+
+      let import = &bundle_reference.import_map[source];
+
+      if import.named.is_empty() && import.namespace.is_none() && import.default.is_none() {
+        side_effect_dependencies.push((module_id.clone(), None));
+        continue;
+      }
+
+      let namespace = module_analyzer_manager
+        .module_global_uniq_name
+        .namespace_name(module_id)
+        .to_result(format!(
+          "cannot find namespace of external module {}",
+          module_id.to_string()
+        ))?;
+
+      dependencies.push((module_id.clone(), Some(bundle_variable.name(namespace))));
+    }
+
+    dependencies.extend(side_effect_dependencies);
+
+    Ok(dependencies)
+  }
+
+  /// the external modules imported by the bundle are loaded through the dependency array of `define`,
+  /// and passed to the factory as the namespace variables used by [super::super::cjs::generate::CjsGenerate::generate_import]
+  pub fn generate_wrapper(
+    bundle_variable: &BundleVariable,
+    bundle_reference: &BundleReference,
+    module_analyzer_manager: &ModuleAnalyzerManager,
+  ) -> Result<(String, String)> {
+    let dependencies =
+      Self::external_dependencies(bundle_variable, bundle_reference, module_analyzer_manager)?;
+
+    Ok((
+      format!(
+        "define([{}], function ({}) {{",
+        dependencies
+          .iter()
+          .map(|(module_id, _)| serde_json::to_string(&module_id.to_string()).unwrap())
+          .collect::<Vec<_>>()
+          .join(", "),
+        dependencies
+          .iter()
+          .filter_map(|(_, param)| param.clone())
+          .collect::<Vec<_>>()
+          .join(", ")
+      ),
+      "});".to_string(),
+    ))
+  }
+}
@@ -1,13 +1,14 @@
 use std::collections::HashMap;
 
 use farmfe_core::{
+  config::ModuleFormat,
   error::Result,
-  module::{ModuleId, ModuleSystem},
+  module::ModuleId,
   swc_common::DUMMY_SP,
   swc_ecma_ast::{
     AssignExpr, AssignOp, AssignTarget, BindingIdent, CallExpr, Callee, Decl, Expr, ExprOrSpread,
-    ExprStmt, KeyValueProp, Lit, MemberExpr, MemberProp, ModuleItem, ObjectLit, Pat, Prop,
-    PropName, PropOrSpread, SimpleAssignTarget, Stmt, VarDecl, VarDeclKind, VarDeclarator,
+    ExprStmt, Lit, MemberExpr, MemberProp, ModuleItem, Pat, SimpleAssignTarget, Stmt, VarDecl,
+    VarDeclKind, VarDeclarator,
   },
 };
 
@@ -126,48 +127,6 @@ impl CjsGenerate {
       ));
     };
 
-    if matches!(
-      export.module_system,
-      ModuleSystem::EsModule | ModuleSystem::Hybrid
-    ) {
-      // Object.defineProperty(exports, '__esModule', {
-      //   value: true,
-      // });
-
-      stmts.push(ModuleItem::Stmt(Stmt::Expr(ExprStmt {
-        span: DUMMY_SP,
-        expr: Box::new(Expr::Call(CallExpr {
-          span: DUMMY_SP,
-          callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
-            span: DUMMY_SP,
-            obj: Box::new(Expr::Ident("Object".into())),
-            prop: MemberProp::Ident("defineProperty".into()),
-          }))),
-          args: vec![
-            ExprOrSpread {
-              spread: None,
-              expr: Box::new(Expr::Ident("exports".into())),
-            },
-            ExprOrSpread {
-              spread: None,
-              expr: Box::new(Expr::Lit("__esModule".into())),
-            },
-            ExprOrSpread {
-              spread: None,
-              expr: Box::new(Expr::Object(ObjectLit {
-                span: DUMMY_SP,
-                props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-                  key: PropName::Ident("value".into()),
-                  value: Box::new(Expr::Lit(Lit::Bool(true.into()))),
-                })))],
-              })),
-            },
-          ],
-          type_args: None,
-        })),
-      })));
-    }
-
     Ok(stmts)
   }
 
@@ -189,7 +148,11 @@ impl CjsGenerate {
   /// const foo_default = _interop_require_default(require("foo"));
   /// ```
   ///
+  /// In amd, iife and system format, the dependency is injected to the namespace variable by the wrapper of the bundle,
+  /// so `require("foo")` is omitted and the namespace is only reassigned when it has to be wrapped.
+  ///
   pub fn generate_import(
+    format: &ModuleFormat,
     bundle_variable: &BundleVariable,
     import_map: &HashMap<ReferenceKind, ExternalReferenceImport>,
     module_analyzer_manager: &ModuleAnalyzerManager,
@@ -218,52 +181,81 @@ impl CjsGenerate {
           .unwrap(),
       );
 
+      // the system loader always provides the namespace of the dependency, no interop is needed
+      let is_system = matches!(format, ModuleFormat::System);
+
       // import * as foo_ns from "foo";
       // import foo from "foo";
       // =>
       // var foo_ns = _interop_require_wildcard(require("foo"));
       // var foo_default = foo_ns.default;
       let try_wrap_namespace = |expr: Box<Expr>, polyfill: &mut SimplePolyfill| {
-        if import.namespace.is_some() {
+        if import.namespace.is_some() && !is_system {
           return wrap_require_wildcard(expr, polyfill);
         }
 
         expr
       };
       let try_wrap_require_default = |expr: Box<Expr>, polyfill: &mut SimplePolyfill| {
-        if import.default.is_some() {
+        if import.default.is_some() && !is_system {
           return wrap_require_default(expr, polyfill);
         }
 
         expr
       };
 
+      let is_injected = matches!(
+        format,
+        ModuleFormat::Amd | ModuleFormat::Iife | ModuleFormat::System
+      );
+
       // if both namespace and default are imported, we need to import the namespace first
-      stmts.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
-        span: DUMMY_SP,
-        kind: farmfe_core::swc_ecma_ast::VarDeclKind::Var,
-        declare: false,
-        decls: vec![VarDeclarator {
-          span: DUMMY_SP,
-          name: Pat::Ident(BindingIdent {
-            id: namespace_name.as_str().into(),
-            type_ann: None,
-          }),
-          init: Some(try_wrap_namespace(
-            Box::new(Expr::Call(CallExpr {
+      if is_injected {
+        // foo_ns = _interop_require_wildcard(foo_ns);
+        if import.namespace.is_some() && !is_system {
+          stmts.push(ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Assign(AssignExpr {
               span: DUMMY_SP,
-              callee: Callee::Expr(Box::new(Expr::Ident("require".into()))),
-              args: vec![ExprOrSpread {
-                spread: None,
-                expr: Box::new(Expr::Lit(Lit::Str(module_id.to_string().as_str().into()))),
-              }],
-              type_args: None,
+              op: AssignOp::Assign,
+              left: AssignTarget::Simple(SimpleAssignTarget::Ident(BindingIdent {
+                id: namespace_name.as_str().into(),
+                type_ann: None,
+              })),
+              right: try_wrap_namespace(
+                Box::new(Expr::Ident(namespace_name.as_str().into())),
+                polyfill,
+              ),
             })),
-            polyfill,
-          )),
-          definite: false,
-        }],
-      })))));
+          })));
+        }
+      } else {
+        stmts.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+          span: DUMMY_SP,
+          kind: farmfe_core::swc_ecma_ast::VarDeclKind::Var,
+          declare: false,
+          decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(BindingIdent {
+              id: namespace_name.as_str().into(),
+              type_ann: None,
+            }),
+            init: Some(try_wrap_namespace(
+              Box::new(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Callee::Expr(Box::new(Expr::Ident("require".into()))),
+                args: vec![ExprOrSpread {
+                  spread: None,
+                  expr: Box::new(Expr::Lit(Lit::Str(module_id.to_string().as_str().into()))),
+                }],
+                type_args: None,
+              })),
+              polyfill,
+            )),
+            definite: false,
+          }],
+        })))));
+      }
 
       let mut decls: Vec<VarDeclarator> = vec![];
 
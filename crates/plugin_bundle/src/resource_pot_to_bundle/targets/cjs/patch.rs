@@ -1,15 +1,19 @@
-use std::sync::Arc;
+use std::{
+  collections::{HashMap, HashSet},
+  sync::Arc,
+};
 
 use farmfe_core::{
-  config::{external::ExternalConfig, Config, Mode},
+  config::{external::ExternalConfig, Config, Mode, ModuleFormat},
   context::CompilationContext,
   error::Result,
   module::{module_graph::ModuleGraph, ModuleId, ModuleSystem},
   swc_common::{comments::SingleThreadedComments, util::take::Take, Mark, DUMMY_SP},
   swc_ecma_ast::{
-    ArrowExpr, BindingIdent, BlockStmt, BlockStmtOrExpr, Decl, EsVersion, Expr, ExprOrSpread,
-    Ident, KeyValueProp, Module as EcmaAstModule, ModuleItem, ObjectLit, Pat, Prop, PropName,
-    PropOrSpread, Stmt, VarDecl, VarDeclKind, VarDeclarator,
+    ArrowExpr, BindingIdent, BlockStmt, BlockStmtOrExpr, CallExpr, Callee, Decl, EsVersion, Expr,
+    ExprOrSpread, ExprStmt, Ident, KeyValueProp, Lit, MemberExpr, MemberProp,
+    Module as EcmaAstModule, ModuleItem, ObjectLit, Pat, Prop, PropName, PropOrSpread, Stmt,
+    VarDecl, VarDeclKind, VarDeclarator,
   },
 };
 use farmfe_toolkit::{
@@ -26,7 +30,10 @@ use farmfe_toolkit::{
 
 use crate::resource_pot_to_bundle::{
   bundle::{bundle_external::BundleReference, ModuleAnalyzerManager, ModuleGlobalUniqName},
-  polyfill::{cjs::wrap_commonjs, Polyfill, SimplePolyfill},
+  polyfill::{
+    cjs::{export_es_module, wrap_commonjs},
+    Polyfill, SimplePolyfill,
+  },
   uniq_name::BundleVariable,
 };
 
@@ -135,6 +142,18 @@ impl CjsPatch {
     ));
   }
 
+  /// the es module flag defined by the commonjs transform is replaced by the shared helper,
+  /// see [Polyfill::ExportEsModule]
+  fn replace_es_module_flag(ast: &mut EcmaAstModule, polyfill: &mut SimplePolyfill) {
+    for item in ast.body.iter_mut() {
+      if let ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) = item {
+        if is_es_module_flag(expr) {
+          *expr = export_es_module(Box::new(Expr::Ident("exports".into())), polyfill);
+        }
+      }
+    }
+  }
+
   /// transform hybrid and commonjs module to esm
   pub fn patch_cjs_module(
     module_analyzer_manager: &mut ModuleAnalyzerManager,
@@ -144,6 +163,7 @@ impl CjsPatch {
     patch_ast: &mut Vec<ModuleItem>,
     bundle_variable: &BundleVariable,
     bundle_reference: &BundleReference,
+    order_index_map: &HashMap<ModuleId, usize>,
     polyfill: &mut SimplePolyfill,
   ) {
     let module_analyzer = module_analyzer_manager.module_analyzer_mut_unchecked(module_id);
@@ -158,6 +178,7 @@ impl CjsPatch {
         unresolved_mark,
         context.config.script.target,
       );
+      CjsPatch::replace_es_module_flag(&mut module_analyzer.ast, polyfill);
     }
 
     // if commonjs module, should wrap function
@@ -179,20 +200,100 @@ impl CjsPatch {
       );
     }
 
-    if let Some(import) = bundle_reference
-      .redeclare_commonjs_import
-      .get(&module_id.clone().into())
-    {
-      patch_ast.extend(CjsModuleAnalyzer::redeclare_commonjs_export(
-        module_id,
-        bundle_variable,
-        &module_analyzer_manager.module_global_uniq_name,
-        import,
-        polyfill,
-      ));
+    // the commonjs modules of a cycle require each other when they are executed, calling one of them
+    // before the wrappers of the others are declared fails, so the redeclared imports of the cycle are
+    // deferred after the last wrapper of the cycle, then the cycle is executed like node and the
+    // imports read the finalized exports
+    let mut redeclare_modules =
+      Self::cyclic_commonjs_modules(module_id, module_graph, module_analyzer_manager);
+
+    if !redeclare_modules.is_empty() {
+      // modules with larger order index are rendered first
+      let is_last_rendered = redeclare_modules
+        .iter()
+        .all(|m| order_index_map.get(m) >= order_index_map.get(module_id));
+
+      if !is_last_rendered {
+        return;
+      }
+
+      redeclare_modules.sort_by(|a, b| order_index_map.get(b).cmp(&order_index_map.get(a)));
+    }
+
+    redeclare_modules.push(module_id.clone());
+
+    for redeclare_module_id in &redeclare_modules {
+      if let Some(import) = bundle_reference
+        .redeclare_commonjs_import
+        .get(&redeclare_module_id.clone().into())
+      {
+        patch_ast.extend(CjsModuleAnalyzer::redeclare_commonjs_export(
+          redeclare_module_id,
+          bundle_variable,
+          &module_analyzer_manager.module_global_uniq_name,
+          import,
+          polyfill,
+        ));
+      }
     }
   }
 
+  /// the other commonjs modules of the same bundle that are in a dependency cycle with `module_id`
+  fn cyclic_commonjs_modules(
+    module_id: &ModuleId,
+    module_graph: &ModuleGraph,
+    module_analyzer_manager: &ModuleAnalyzerManager,
+  ) -> Vec<ModuleId> {
+    let Some(module_analyzer) = module_analyzer_manager.module_analyzer(module_id) else {
+      return vec![];
+    };
+
+    if !module_analyzer.is_commonjs() {
+      return vec![];
+    }
+
+    let is_same_bundle_commonjs = |id: &ModuleId| {
+      module_analyzer_manager
+        .module_analyzer(id)
+        .is_some_and(|m| m.is_commonjs() && m.resource_pot_id == module_analyzer.resource_pot_id)
+    };
+
+    let reachable = |next: &dyn Fn(&ModuleId) -> Vec<ModuleId>| {
+      let mut visited = HashSet::new();
+      let mut stack = next(module_id);
+
+      while let Some(id) = stack.pop() {
+        if !is_same_bundle_commonjs(&id) || !visited.insert(id.clone()) {
+          continue;
+        }
+
+        stack.extend(next(&id));
+      }
+
+      visited
+    };
+
+    let dependencies = reachable(&|id| {
+      module_graph
+        .dependencies(id)
+        .into_iter()
+        .map(|(dep, _)| dep)
+        .collect()
+    });
+
+    if !dependencies.contains(module_id) {
+      return vec![];
+    }
+
+    let dependents = reachable(&|id| module_graph.dependents_ids(id));
+
+    dependencies
+      .intersection(&dependents)
+      .filter(|id| *id != module_id)
+      .cloned()
+      .collect()
+  }
+
   pub fn replace_cjs_require(
     mark: (Mark, Mark),
     ast: &mut EcmaAstModule,
@@ -203,6 +304,7 @@ impl CjsPatch {
     config: &Config,
     polyfill: &mut SimplePolyfill,
     external_config: &ExternalConfig,
+    format: &ModuleFormat,
   ) {
     let mut replacer: CJSReplace = CJSReplace {
       unresolved_mark: mark.0,
@@ -213,9 +315,32 @@ impl CjsPatch {
       bundle_variable,
       config,
       polyfill,
-      external_config
+      external_config,
+      format,
     };
 
     ast.visit_mut_with(&mut replacer);
   }
 }
+
+/// `Object.defineProperty(exports, "__esModule", { value: true })`
+fn is_es_module_flag(expr: &Expr) -> bool {
+  let Expr::Call(CallExpr {
+    callee: Callee::Expr(callee),
+    args,
+    ..
+  }) = expr
+  else {
+    return false;
+  };
+
+  matches!(
+    &**callee,
+    Expr::Member(MemberExpr { obj, prop: MemberProp::Ident(prop), .. })
+      if matches!(&**obj, Expr::Ident(obj) if obj.sym == *"Object") && prop.sym == *"defineProperty"
+  ) && matches!(
+    args.as_slice(),
+    [exports, name, _] if matches!(&*exports.expr, Expr::Ident(exports) if exports.sym == *"exports")
+      && matches!(&*name.expr, Expr::Lit(Lit::Str(name)) if name.value == *"__esModule")
+  )
+}
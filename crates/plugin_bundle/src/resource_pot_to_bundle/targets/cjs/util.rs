@@ -5,6 +5,7 @@ use farmfe_core::{
   swc_ecma_ast::{CallExpr, Callee, Expr, ExprOrSpread, Lit, MemberExpr, MemberProp},
 };
 use farmfe_toolkit::{
+  html::get_global_object,
   script::is_commonjs_require,
   swc_ecma_visit::{VisitMut, VisitMutWith},
 };
@@ -58,6 +59,7 @@ pub struct CJSReplace<'a> {
   pub config: &'a Config,
   pub polyfill: &'a mut SimplePolyfill,
   pub external_config: &'a ExternalConfig,
+  pub format: &'a ModuleFormat,
 }
 
 impl<'a> VisitMut for CJSReplace<'a> {
@@ -88,17 +90,24 @@ impl<'a> VisitMut for CJSReplace<'a> {
                 && self.config.output.target_env.is_node()
               {
                 // node esm
-                if matches!(self.config.output.format, ModuleFormat::EsModule) {
-                  self.polyfill.add(Polyfill::NodeEsmGlobalRequireHelper);
-                  call_expr.callee = Callee::Expr(Box::new(Expr::Member(MemberExpr {
-                    span: DUMMY_SP,
-                    obj: Box::new(Expr::Ident("global".into())),
-                    prop: MemberProp::Ident("nodeRequire".into()),
-                  })));
+                if matches!(self.format, ModuleFormat::EsModule) {
+                  if self.config.output.target_env.is_node_next() {
+                    // `require` is declared by `createRequire` at the top of the bundle
+                    self.polyfill.add(Polyfill::NodeEsmCreateRequire);
+                  } else {
+                    self.polyfill.add(Polyfill::NodeEsmGlobalRequireHelper);
+                    call_expr.callee = Callee::Expr(Box::new(Expr::Member(MemberExpr {
+                      span: DUMMY_SP,
+                      obj: Box::new(Expr::Ident("global".into())),
+                      prop: MemberProp::Ident("nodeRequire".into()),
+                    })));
+                  }
                 }
               } else {
                 // browser
-                self.polyfill.add(Polyfill::BrowserExternalRequire);
+                self.polyfill.add(Polyfill::BrowserExternalRequire(
+                  get_global_object(&self.config.output).to_string(),
+                ));
 
                 let replace_source = self
                   .external_config
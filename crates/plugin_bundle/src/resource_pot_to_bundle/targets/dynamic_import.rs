@@ -1,18 +1,17 @@
 use farmfe_core::{
+  config::ModuleFormat,
   module::ModuleId,
   swc_common::DUMMY_SP,
   swc_ecma_ast::{CallExpr, Callee, Expr, ExprOrSpread, Ident, Lit, MemberExpr, MemberProp},
 };
 use farmfe_toolkit::swc_ecma_visit::{VisitMut, VisitMutWith};
 
-use crate::resource_pot_to_bundle::{bundle::ModuleAnalyzerManager, uniq_name::BundleVariable};
-
-const FARM_BUNDLE_REFERENCE_SLOT_PREFIX: &str = "__FARM_BUNDLE_REFERENCE_SLOT__:";
+use crate::resource_pot_to_bundle::{
+  bundle::{bundle_external::with_bundle_reference_slot_name, ModuleAnalyzerManager},
+  uniq_name::BundleVariable,
+};
 
-// TODO: replace bundle reference slot
-fn with_bundle_reference_slot_name(bundle_id: &String) -> String {
-  format!("{FARM_BUNDLE_REFERENCE_SLOT_PREFIX}{bundle_id}")
-}
+use super::system::generate::SYSTEM_CONTEXT;
 
 ///
 /// ```ts
@@ -34,6 +33,11 @@ fn with_bundle_reference_slot_name(bundle_id: &String) -> String {
 /// import("./dynamic")
 /// // =>
 /// import("./bundle-1")
+///
+/// // other bundle or external in system format
+/// import("./dynamic")
+/// // =>
+/// context.import("./bundle-1")
 /// ```
 ///
 ///
@@ -41,6 +45,7 @@ pub struct ReplaceDynamicVisit<'a> {
   module_manager: &'a ModuleAnalyzerManager<'a>,
   module_id: &'a ModuleId,
   bundle_variable: &'a BundleVariable,
+  format: &'a ModuleFormat,
 }
 
 impl<'a> ReplaceDynamicVisit<'a> {
@@ -149,6 +154,21 @@ impl<'a> VisitMut for ReplaceDynamicVisit<'a> {
     if !visited {
       n.visit_mut_children_with(self);
     }
+
+    // the remaining `import()` loads other bundles or externals, which should be loaded by the system loader
+    if let Expr::Call(CallExpr {
+      callee: callee @ Callee::Import(_),
+      ..
+    }) = n
+    {
+      if matches!(self.format, ModuleFormat::System) {
+        *callee = Callee::Expr(Box::new(Expr::Member(MemberExpr {
+          span: DUMMY_SP,
+          obj: Box::new(Expr::Ident(SYSTEM_CONTEXT.into())),
+          prop: MemberProp::Ident("import".into()),
+        })));
+      }
+    }
   }
 }
 
@@ -156,10 +176,12 @@ pub fn replace_dynamic_import<'a>(
   module_manager: &'a ModuleAnalyzerManager<'a>,
   module_id: &'a ModuleId,
   bundle_variable: &'a BundleVariable,
+  format: &'a ModuleFormat,
 ) -> ReplaceDynamicVisit<'a> {
   ReplaceDynamicVisit {
     module_manager,
     module_id,
     bundle_variable,
+    format,
   }
 }
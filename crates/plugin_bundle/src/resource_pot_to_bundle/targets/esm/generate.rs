@@ -5,9 +5,9 @@ use farmfe_core::{
   module::ModuleId,
   swc_common::DUMMY_SP,
   swc_ecma_ast::{
-    ExportAll, ExportDefaultExpr, ExportNamedSpecifier, ExportNamespaceSpecifier, Expr, ImportDecl,
-    ImportDefaultSpecifier, ImportNamedSpecifier, ImportStarAsSpecifier, ModuleDecl, ModuleItem,
-    NamedExport, Str,
+    ExportAll, ExportDefaultExpr, ExportNamedSpecifier, ExportNamespaceSpecifier, ExportSpecifier,
+    Expr, ImportDecl, ImportDefaultSpecifier, ImportNamedSpecifier, ImportPhase, ImportSpecifier,
+    ImportStarAsSpecifier, ModuleDecl, ModuleExportName, ModuleItem, NamedExport, Str,
   },
 };
 
@@ -49,27 +49,19 @@ impl EsmGenerate {
         Some(exported_name.as_str().into())
       };
 
-      specifiers.push(farmfe_core::swc_ecma_ast::ExportSpecifier::Named(
-        ExportNamedSpecifier {
-          span: DUMMY_SP,
-          orig: farmfe_core::swc_ecma_ast::ModuleExportName::Ident(
-            named_render_name.as_str().into(),
-          ),
-          exported: exported_name.map(farmfe_core::swc_ecma_ast::ModuleExportName::Ident),
-          is_type_only: false,
-        },
-      ));
+      specifiers.push(ExportSpecifier::Named(ExportNamedSpecifier {
+        span: DUMMY_SP,
+        orig: ModuleExportName::Ident(named_render_name.as_str().into()),
+        exported: exported_name.map(ModuleExportName::Ident),
+        is_type_only: false,
+      }));
     }
 
     if let Some(namespace) = export.namespace.as_ref() {
-      specifiers.push(farmfe_core::swc_ecma_ast::ExportSpecifier::Namespace(
-        ExportNamespaceSpecifier {
-          span: DUMMY_SP,
-          name: farmfe_core::swc_ecma_ast::ModuleExportName::Ident(
-            bundle_variable.name(*namespace).as_str().into(),
-          ),
-        },
-      ));
+      specifiers.push(ExportSpecifier::Namespace(ExportNamespaceSpecifier {
+        span: DUMMY_SP,
+        name: ModuleExportName::Ident(bundle_variable.name(*namespace).as_str().into()),
+      }));
     }
 
     if let Some(source) = source {
@@ -95,6 +87,8 @@ impl EsmGenerate {
       )));
     }
 
+    // `export default x` only exports the value of `x`, the binding preserving `export { x as default }`
+    // comes from the named exports when the source module was already written in that form
     if let Some(default) = export.default.as_ref() {
       stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(
         ExportDefaultExpr {
@@ -124,18 +118,18 @@ impl EsmGenerate {
         continue;
       }
 
-      let create_import = |specifiers: Vec<farmfe_core::swc_ecma_ast::ImportSpecifier>| {
+      let create_import = |specifiers: Vec<ImportSpecifier>| {
         ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
           span: DUMMY_SP,
           specifiers,
           src: Box::new(Str {
             span: DUMMY_SP,
-            value: source.to_string().as_str().into(),
+            value: source.to_import_source().as_str().into(),
             raw: None,
           }),
           type_only: false,
           with: None,
-          phase: farmfe_core::swc_ecma_ast::ImportPhase::Evaluation,
+          phase: ImportPhase::Evaluation,
         }))
       };
 
@@ -147,38 +141,32 @@ impl EsmGenerate {
         let local = &import.named[imported];
         let local_named = bundle_variable.render_name(*local);
 
-        specifiers.push(farmfe_core::swc_ecma_ast::ImportSpecifier::Named(
-          ImportNamedSpecifier {
-            span: DUMMY_SP,
-            local: local_named.as_str().into(),
-            imported: if imported == &local_named {
-              None
-            } else {
-              Some(farmfe_core::swc_ecma_ast::ModuleExportName::Ident(
-                imported.as_str().into(),
-              ))
-            },
-            is_type_only: false,
+        specifiers.push(ImportSpecifier::Named(ImportNamedSpecifier {
+          span: DUMMY_SP,
+          local: local_named.as_str().into(),
+          imported: if imported == &local_named {
+            None
+          } else {
+            Some(ModuleExportName::Ident(imported.as_str().into()))
           },
-        ));
+          is_type_only: false,
+        }));
       }
 
       if let Some(namespace) = import.namespace.as_ref() {
-        stmts.push(create_import(vec![
-          farmfe_core::swc_ecma_ast::ImportSpecifier::Namespace(ImportStarAsSpecifier {
+        stmts.push(create_import(vec![ImportSpecifier::Namespace(
+          ImportStarAsSpecifier {
             span: DUMMY_SP,
             local: bundle_variable.render_name(*namespace).as_str().into(),
-          }),
-        ]));
+          },
+        )]));
       }
 
       if let Some(default) = import.default.as_ref() {
-        specifiers.push(farmfe_core::swc_ecma_ast::ImportSpecifier::Default(
-          ImportDefaultSpecifier {
-            span: DUMMY_SP,
-            local: bundle_variable.render_name(*default).as_str().into(),
-          },
-        ));
+        specifiers.push(ImportSpecifier::Default(ImportDefaultSpecifier {
+          span: DUMMY_SP,
+          local: bundle_variable.render_name(*default).as_str().into(),
+        }));
       }
 
       if !specifiers.is_empty() {
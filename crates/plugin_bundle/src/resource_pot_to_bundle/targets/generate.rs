@@ -1,15 +1,14 @@
-use std::{collections::HashMap, sync::Arc};
+use std::collections::{BTreeMap, HashMap};
 
 use farmfe_core::{
-  config::ModuleFormat,
-  context::CompilationContext,
+  config::{external::ExternalConfig, ModuleFormat},
   error::Result,
   module::{ModuleId, ModuleSystem},
   swc_common::DUMMY_SP,
   swc_ecma_ast::{
-    self, ArrayLit, BindingIdent, Bool, CallExpr, Decl, Expr, ExprOrSpread, Ident, KeyValueProp,
-    ModuleItem, ObjectLit, Pat, Prop, PropName, PropOrSpread, Stmt, Str, VarDecl, VarDeclKind,
-    VarDeclarator,
+    self, ArrayLit, BindingIdent, BlockStmt, Bool, CallExpr, Callee, Decl, Expr, ExprOrSpread,
+    ExprStmt, GetterProp, Ident, KeyValueProp, MemberExpr, MemberProp, ModuleItem, ObjectLit, Pat,
+    Prop, PropName, PropOrSpread, ReturnStmt, Stmt, Str, VarDecl, VarDeclKind, VarDeclarator,
   },
 };
 
@@ -20,11 +19,14 @@ use crate::resource_pot_to_bundle::{
     ModuleAnalyzerManager,
   },
   modules_analyzer::module_analyzer::ImportSpecifierInfo,
-  polyfill::{Polyfill, SimplePolyfill},
+  polyfill::{cjs::export_es_module, Polyfill, SimplePolyfill},
   uniq_name::BundleVariable,
 };
 
-use super::{cjs::generate::CjsGenerate, esm::generate::EsmGenerate};
+use super::{
+  amd::generate::AmdGenerate, cjs::generate::CjsGenerate, esm::generate::EsmGenerate,
+  system::generate::SystemGenerate,
+};
 
 /// namespace
 pub fn generate_namespace_by_reference_map(
@@ -194,19 +196,18 @@ pub fn generate_export_as_object_prop(
 
     let local_ident = bundle_variable.render_name(*local);
 
-    // maybe as short, but need legacy
-    props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-      key: PropName::Ident(exported_name.as_str().into()),
-      value: Box::new(Expr::Ident(Ident::from(local_ident.as_str()))),
-    }))));
+    props.push(create_getter_prop(
+      PropName::Ident(exported_name.as_str().into()),
+      &local_ident,
+    ));
   }
 
   if let Some(default) = reference_export.default {
     let default_ident = bundle_variable.render_name(default);
-    props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-      key: PropName::Str(Str::from("default")),
-      value: Box::new(Expr::Ident(Ident::from(default_ident.as_str()))),
-    }))));
+    props.push(create_getter_prop(
+      PropName::Str(Str::from("default")),
+      &default_ident,
+    ));
   }
 
   if let Some(ns) = reference_export.namespace {
@@ -215,86 +216,203 @@ pub fn generate_export_as_object_prop(
     let ns_key = namespace.origin_name();
     let ns_value = namespace.render_name();
 
-    props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-      key: PropName::Str(ns_key.as_str().into()),
-      value: Box::new(Expr::Ident(ns_value.as_str().into())),
-    }))));
+    props.push(create_getter_prop(
+      PropName::Str(ns_key.as_str().into()),
+      &ns_value,
+    ));
   }
 }
 
+/// `get key() { return local; }`, the namespace object should keep live binding of the exported variable
+fn create_getter_prop(key: PropName, local: &str) -> PropOrSpread {
+  PropOrSpread::Prop(Box::new(Prop::Getter(GetterProp {
+    span: DUMMY_SP,
+    key,
+    type_ann: None,
+    body: Some(BlockStmt {
+      span: DUMMY_SP,
+      stmts: vec![Stmt::Return(ReturnStmt {
+        span: DUMMY_SP,
+        arg: Some(Box::new(Expr::Ident(Ident::from(local)))),
+      })],
+    }),
+  })))
+}
+
+/// Object.freeze(expr)
+pub fn wrap_object_freeze(expr: Box<Expr>) -> Box<Expr> {
+  Box::new(Expr::Call(CallExpr {
+    span: DUMMY_SP,
+    callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+      span: DUMMY_SP,
+      obj: Box::new(Expr::Ident("Object".into())),
+      prop: MemberProp::Ident("freeze".into()),
+    }))),
+    args: vec![ExprOrSpread { spread: None, expr }],
+    type_args: None,
+  }))
+}
+
 /// generate bundle export
 pub fn generate_export_by_reference_export(
+  format: &ModuleFormat,
   resource_pot_id: &str,
+  freeze_exports: bool,
   bundle_variable: &BundleVariable,
   bundle_reference: &mut BundleReference,
   module_analyzer_manager: &ModuleAnalyzerManager,
-  context: &Arc<CompilationContext>,
   polyfill: &mut SimplePolyfill,
 ) -> Result<Vec<ModuleItem>> {
   let mut patch_export_to_module = vec![];
 
+  let mut ordered_external_export = bundle_reference
+    .external_export_map
+    .keys()
+    .collect::<Vec<_>>();
+
+  ordered_external_export.sort_by_key(|a| a.to_string());
+
+  // all exports of the bundle are exposed at once, returned by the amd factory or the iife, or passed to `exports` of system
+  if matches!(
+    format,
+    ModuleFormat::Amd | ModuleFormat::Iife | ModuleFormat::System
+  ) {
+    let external_export_sources = ordered_external_export
+      .iter()
+      .map(|source| source.to_module_id())
+      .collect::<Vec<_>>();
+
+    let exports = bundle_reference
+      .export
+      .iter()
+      .map(|export| (None, export))
+      .chain(
+        ordered_external_export
+          .iter()
+          .zip(external_export_sources.iter())
+          .map(|(source, module_id)| {
+            (
+              Some(module_id),
+              &bundle_reference.external_export_map[*source],
+            )
+          }),
+      )
+      .collect();
+
+    if matches!(format, ModuleFormat::System) {
+      return SystemGenerate::generate_export(exports, bundle_variable, module_analyzer_manager);
+    }
+
+    return AmdGenerate::generate_export(
+      exports,
+      freeze_exports,
+      bundle_variable,
+      module_analyzer_manager,
+    );
+  }
+
   if let Some(export) = bundle_reference.export.as_ref() {
     patch_export_to_module.extend(generate_export_as_module_export(
+      format,
       resource_pot_id,
       None,
       export,
       bundle_variable,
       module_analyzer_manager,
-      context,
       polyfill,
     )?);
   }
 
-  let mut ordered_external_export = bundle_reference
-    .external_export_map
-    .keys()
-    .collect::<Vec<_>>();
-
-  ordered_external_export.sort_by_key(|a| a.to_string());
-
   for source in ordered_external_export {
     let export = &bundle_reference.external_export_map[source];
 
     patch_export_to_module.extend(generate_export_as_module_export(
+      format,
       resource_pot_id,
       Some(&source.to_module_id()),
       export,
       bundle_variable,
       module_analyzer_manager,
-      context,
       polyfill,
     )?);
   }
 
+  // the exports of all the sources are assigned to the same `exports`, it's marked as es module once
+  let is_es_module_export = bundle_reference
+    .export
+    .iter()
+    .chain(bundle_reference.external_export_map.values())
+    .any(|export| {
+      matches!(
+        export.module_system,
+        ModuleSystem::EsModule | ModuleSystem::Hybrid
+      )
+    });
+
+  if is_es_module_export && matches!(format, ModuleFormat::CommonJs | ModuleFormat::Umd) {
+    patch_export_to_module.push(ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+      span: DUMMY_SP,
+      expr: export_es_module(Box::new(Expr::Ident("exports".into())), polyfill),
+    })));
+  }
+
+  // Object.freeze(module.exports);
+  if freeze_exports
+    && !patch_export_to_module.is_empty()
+    && matches!(format, ModuleFormat::CommonJs | ModuleFormat::Umd)
+  {
+    patch_export_to_module.push(ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+      span: DUMMY_SP,
+      expr: wrap_object_freeze(Box::new(Expr::Member(MemberExpr {
+        span: DUMMY_SP,
+        obj: Box::new(Expr::Ident("module".into())),
+        prop: MemberProp::Ident("exports".into()),
+      }))),
+    })));
+  }
+
   Ok(patch_export_to_module)
 }
 
 pub fn generate_export_as_module_export(
+  format: &ModuleFormat,
   _resource_pot_name: &str,
   source: Option<&ModuleId>,
   export: &ExternalReferenceExport,
   bundle_variable: &BundleVariable,
   module_analyzer_manager: &ModuleAnalyzerManager,
-  context: &Arc<CompilationContext>,
   polyfill: &mut SimplePolyfill,
 ) -> Result<Vec<ModuleItem>> {
   let mut ordered_keys = export.named.keys().collect::<Vec<_>>();
 
   ordered_keys.sort_by_key(|a| bundle_variable.name(**a));
 
-  match (&export.module_system, context.config.output.format) {
+  match (&export.module_system, format) {
     // hybrid dynamic es module cannot support, if hybrid, only export static export
     (_, ModuleFormat::EsModule) => {
       EsmGenerate::generate_export(source, export, bundle_variable, module_analyzer_manager)
     }
 
-    (_, ModuleFormat::CommonJs) => CjsGenerate::generate_export(
+    (_, ModuleFormat::CommonJs | ModuleFormat::Umd) => CjsGenerate::generate_export(
       source,
       export,
       bundle_variable,
       module_analyzer_manager,
       polyfill,
     ),
+
+    (_, ModuleFormat::Amd | ModuleFormat::Iife) => AmdGenerate::generate_export(
+      vec![(source, export)],
+      false,
+      bundle_variable,
+      module_analyzer_manager,
+    ),
+
+    (_, ModuleFormat::System) => SystemGenerate::generate_export(
+      vec![(source, export)],
+      bundle_variable,
+      module_analyzer_manager,
+    ),
   }
 }
 
@@ -309,23 +427,81 @@ pub fn generate_bundle_import_by_bundle_reference(
 ) -> Result<Vec<ModuleItem>> {
   let mut patch_import_to_module = vec![];
 
-  match format {
-    ModuleFormat::CommonJs => {
-      patch_import_to_module.extend(CjsGenerate::generate_import(
-        bundle_variable,
-        &bundle_reference.import_map,
-        module_analyzer_manager,
-        polyfill,
-      )?);
-    }
-
-    ModuleFormat::EsModule => {
-      patch_import_to_module.extend(EsmGenerate::generate_import(
-        bundle_variable,
-        &bundle_reference.import_map,
-      )?);
-    }
+  if format.is_esm() {
+    patch_import_to_module.extend(EsmGenerate::generate_import(
+      bundle_variable,
+      &bundle_reference.import_map,
+    )?);
+  } else {
+    patch_import_to_module.extend(CjsGenerate::generate_import(
+      format,
+      bundle_variable,
+      &bundle_reference.import_map,
+      module_analyzer_manager,
+      polyfill,
+    )?);
   }
 
   Ok(patch_import_to_module)
 }
+
+/// umd wrapper of the bundle, the bundle code is rendered as commonjs and placed between the header and the footer.
+/// the factory is called with `module`, `exports` and `require` of the environment:
+/// 1. commonjs: `module.exports` of the current module
+/// 2. amd: `define(['module', 'exports', 'require'], factory)`
+/// 3. plain `<script>`: the exports are assigned to the global variable `name`, and `require(id)` reads the global variable
+/// that `id` is mapped to by the `external` config, e.g. `{ react: 'React' }`, falling back to `id` itself
+pub fn generate_umd_wrapper(
+  name: Option<&str>,
+  external_modules: &[ModuleId],
+  external_config: &ExternalConfig,
+) -> (String, String) {
+  let assign_global = name
+    .map(|name| {
+      format!(
+        "global[{}] = m.exports;",
+        farmfe_core::serde_json::to_string(name).unwrap()
+      )
+    })
+    .unwrap_or_default();
+
+  let global_names = external_modules
+    .iter()
+    .map(|module_id| {
+      let source = module_id.to_string();
+      let global_name = external_config
+        .find_match(&source)
+        .map(|v| v.source(&source))
+        // it's maybe from plugin
+        .unwrap_or(source.clone());
+
+      (source, global_name)
+    })
+    .collect::<BTreeMap<_, _>>();
+
+  let global_require = if global_names.is_empty() {
+    "global[id]".to_string()
+  } else {
+    format!(
+      "global[{}[id] || id]",
+      farmfe_core::serde_json::to_string(&global_names).unwrap()
+    )
+  };
+
+  let header = format!(
+    r#"(function (global, factory) {{
+  if (typeof exports === 'object' && typeof module !== 'undefined') {{
+    factory(module, exports, require);
+  }} else if (typeof define === 'function' && define.amd) {{
+    define(['module', 'exports', 'require'], factory);
+  }} else {{
+    global = typeof globalThis !== 'undefined' ? globalThis : global || self;
+    var m = {{ exports: {{}} }};
+    factory(m, m.exports, function (id) {{ return {global_require}; }});
+    {assign_global}
+  }}
+}})(this, function (module, exports, require) {{"#
+  );
+
+  (header, "});".to_string())
+}
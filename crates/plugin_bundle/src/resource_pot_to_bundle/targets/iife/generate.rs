@@ -0,0 +1,96 @@
+use farmfe_core::{
+  config::{external::ExternalConfig, Config},
+  error::{CompilationError, Result},
+  serde_json,
+  swc_ecma_ast::Ident,
+};
+
+use crate::resource_pot_to_bundle::{
+  bundle::{bundle_external::BundleReference, ModuleAnalyzerManager},
+  uniq_name::BundleVariable,
+};
+
+use super::super::amd::generate::AmdGenerate;
+
+// var MyLib = (function (foo_ns) {
+//   var name = foo_ns.name;
+//   ...
+//   return { name: name };
+// })(this["foo"]);
+
+pub struct IifeGenerate {}
+
+impl IifeGenerate {
+  /// the exports are returned by the function like amd, see [AmdGenerate::generate_export],
+  /// and assigned to the global variable `name`. external modules are read from the global variable that the module id is mapped to
+  /// by the `external` config, e.g. `{ react: 'React' }`, or from the global variable named by the module id.
+  /// the exports of the other bundles are assigned to the global variable named by the resource pot id.
+  /// a bare iife is emitted when the bundle has no exports.
+  pub fn generate_wrapper(
+    config: &Config,
+    is_entry: bool,
+    has_exports: bool,
+    resource_pot_id: &str,
+    bundle_variable: &BundleVariable,
+    bundle_reference: &BundleReference,
+    module_analyzer_manager: &ModuleAnalyzerManager,
+  ) -> Result<(String, String)> {
+    let external_config = ExternalConfig::from(config);
+
+    let (params, args): (Vec<_>, Vec<_>) = AmdGenerate::external_dependencies(
+      bundle_variable,
+      bundle_reference,
+      module_analyzer_manager,
+    )?
+    .into_iter()
+    .filter_map(|(module_id, param)| {
+      param.map(|param| {
+        let source = module_id.to_string();
+        let global_name = external_config
+          .find_match(&source)
+          .map(|v| v.source(&source))
+          // it's maybe from plugin
+          .unwrap_or(source.clone());
+
+        (
+          param,
+          format!("this[{}]", serde_json::to_string(&global_name).unwrap()),
+        )
+      })
+    })
+    .unzip();
+
+    let header = format!("(function ({}) {{", params.join(", "));
+    let footer = format!("}})({});", args.join(", "));
+
+    if !has_exports {
+      return Ok((header, footer));
+    }
+
+    if !is_entry {
+      return Ok((
+        format!(
+          "this[{}] = {header}",
+          serde_json::to_string(resource_pot_id).unwrap()
+        ),
+        footer,
+      ));
+    }
+
+    let name = config.output.name.as_deref().ok_or_else(|| {
+      CompilationError::GenericError(format!(
+        "`output.name` is required when `output.format` is `iife`, it's used as the global variable name of the exports of `{resource_pot_id}`"
+      ))
+    })?;
+
+    // a name that can not be declared, like `my-lib`, is assigned as a property of the global object
+    if Ident::verify_symbol(name).is_err() {
+      return Ok((
+        format!("this[{}] = {header}", serde_json::to_string(name).unwrap()),
+        footer,
+      ));
+    }
+
+    Ok((format!("var {name} = {header}"), footer))
+  }
+}
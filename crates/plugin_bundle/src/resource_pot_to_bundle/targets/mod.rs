@@ -1,4 +1,7 @@
+pub mod amd;
 pub mod cjs;
 pub mod dynamic_import;
 pub mod esm;
 pub mod generate;
+pub mod iife;
+pub mod system;
@@ -0,0 +1,113 @@
+use farmfe_core::{
+  error::Result,
+  module::ModuleId,
+  serde_json,
+  swc_common::DUMMY_SP,
+  swc_ecma_ast::{CallExpr, Callee, Expr, ExprOrSpread, ExprStmt, ModuleItem, Stmt},
+};
+
+use crate::resource_pot_to_bundle::{
+  bundle::{
+    bundle_external::{BundleReference, ExternalReferenceExport},
+    ModuleAnalyzerManager,
+  },
+  uniq_name::BundleVariable,
+};
+
+use super::super::amd::generate::AmdGenerate;
+
+/// the parameter of the `System.register` declaration that receives the exports
+pub const SYSTEM_EXPORTS: &str = "exports";
+/// the parameter of the `System.register` declaration that provides `context.import` and `context.meta`
+pub const SYSTEM_CONTEXT: &str = "context";
+
+// System.register(["foo"], function (exports, context) {
+//   var foo_ns;
+//   return {
+//     setters: [function (module) { foo_ns = module; }],
+//     execute: function () {
+//       var name = foo_ns.name;
+//       ...
+//       exports({ name: name });
+//     }
+//   };
+// });
+
+pub struct SystemGenerate {}
+
+impl SystemGenerate {
+  /// export all the bindings through the `exports` function of the declaration
+  /// ```js
+  /// export { a, b as c };
+  /// // =>
+  /// exports({ a: a, c: b });
+  /// ```
+  pub fn generate_export(
+    exports: Vec<(Option<&ModuleId>, &ExternalReferenceExport)>,
+    bundle_variable: &BundleVariable,
+    module_analyzer_manager: &ModuleAnalyzerManager,
+  ) -> Result<Vec<ModuleItem>> {
+    let Some(exports_object) =
+      AmdGenerate::exports_object(exports, false, bundle_variable, module_analyzer_manager)?
+    else {
+      return Ok(vec![]);
+    };
+
+    Ok(vec![ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+      span: DUMMY_SP,
+      expr: Box::new(Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: Callee::Expr(Box::new(Expr::Ident(SYSTEM_EXPORTS.into()))),
+        args: vec![ExprOrSpread {
+          spread: None,
+          expr: exports_object,
+        }],
+        type_args: None,
+      })),
+    }))])
+  }
+
+  /// the external modules are declared as dependencies, and assigned to their namespace variables by the setters
+  pub fn generate_wrapper(
+    bundle_variable: &BundleVariable,
+    bundle_reference: &BundleReference,
+    module_analyzer_manager: &ModuleAnalyzerManager,
+  ) -> Result<(String, String)> {
+    let dependencies = AmdGenerate::external_dependencies(
+      bundle_variable,
+      bundle_reference,
+      module_analyzer_manager,
+    )?;
+
+    let deps = dependencies
+      .iter()
+      .map(|(module_id, _)| serde_json::to_string(&module_id.to_string()).unwrap())
+      .collect::<Vec<_>>();
+    let namespaces = dependencies
+      .iter()
+      .filter_map(|(_, namespace)| namespace.clone())
+      .collect::<Vec<_>>();
+    let setters = dependencies
+      .iter()
+      .map(|(_, namespace)| match namespace {
+        Some(namespace) => format!("function (module) {{ {namespace} = module; }}"),
+        None => "function () {}".to_string(),
+      })
+      .collect::<Vec<_>>();
+
+    let declare_namespaces = if namespaces.is_empty() {
+      String::new()
+    } else {
+      format!("\n  var {};", namespaces.join(", "))
+    };
+
+    Ok((
+      format!(
+        "System.register([{}], function ({SYSTEM_EXPORTS}, {SYSTEM_CONTEXT}) {{{declare_namespaces}\n  return {{\n    setters: [{}],\n    execute: function () {{",
+        deps.join(", "),
+        setters.join(", ")
+      ),
+      "    }\n  };\n});".to_string(),
+    ))
+  }
+}
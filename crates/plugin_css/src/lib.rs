@@ -698,7 +698,7 @@ impl Plugin for FarmPluginCss {
       if context.config.sourcemap.enabled(resource_pot.immutable) {
         // css_code.push_str(format!("\n/*# sourceMappingURL={} */", sourcemap_filename).as_str());
         if !resource_pot.meta.rendered_map_chain.is_empty() {
-          let collapsed_sourcemap = collapse_sourcemap_chain(
+          let mut collapsed_sourcemap = collapse_sourcemap_chain(
             resource_pot
               .meta
               .rendered_map_chain
@@ -707,6 +707,11 @@ impl Plugin for FarmPluginCss {
               .collect(),
             Default::default(),
           );
+          context.plugin_driver.transform_sourcemap(
+            &mut collapsed_sourcemap,
+            &resource_pot.id,
+            context,
+          )?;
           let mut buf = vec![];
           collapsed_sourcemap
             .to_writer(&mut buf)
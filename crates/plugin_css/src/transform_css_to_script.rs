@@ -43,6 +43,11 @@ pub fn transform_css_to_script_modules(
     })
     .try_for_each(|module_id: ModuleId| {
       let mut cache_store_key = None;
+      // the execution order is used to keep the style insertion order the same as the module execution order
+      let execution_order = {
+        let module_graph = context.module_graph.read();
+        module_graph.module(&module_id).unwrap().execution_order
+      };
 
       if context.config.persistent_cache.enabled() {
         let content_hash = {
@@ -55,9 +60,11 @@ pub fn transform_css_to_script_modules(
           name: module_id.to_string() + "-transform_css_to_script_modules",
           key: sha256(
             format!(
-              "transform_css_to_script_modules_{}_{}",
+              "transform_css_to_script_modules_{}_{}_{}_{}",
               content_hash,
-              module_id.to_string()
+              module_id.to_string(),
+              execution_order,
+              context.config.css.export_style_element
             )
             .as_bytes(),
             32,
@@ -131,7 +138,14 @@ pub fn transform_css_to_script_modules(
           .source_map_chain = vec![];
       }
 
-      let css_code = wrapper_style_load(&css_code, module_id.to_string(), &css_deps, src_map);
+      let css_code = wrapper_style_load(
+        &css_code,
+        module_id.to_string(),
+        execution_order,
+        &css_deps,
+        src_map,
+        context.config.css.export_style_element,
+      );
       let css_code = Arc::new(css_code);
       let (cm, _) = create_swc_source_map(Source {
         path: PathBuf::from(module_id.to_string()),
@@ -249,26 +263,39 @@ pub fn transform_css_deps(module_id: &ModuleId, context: &Arc<CompilationContext
   load_statements.join(" ")
 }
 
+/// Wrap the css code to a script module that injects a `<style>` element.
+/// `order` is the execution order of the css module, styles are inserted by `data-farm-order`
+/// so the cascade is deterministic no matter which resource is loaded first.
+/// The `<style>` element is the default export of the module when `export_style_element` is true.
 pub fn wrapper_style_load(
   code: &str,
   id: String,
+  order: usize,
   css_deps: &String,
   src_map: Option<String>,
+  export_style_element: bool,
 ) -> String {
   format!(
     r#"
 const cssCode = `{}`;
 const farmId = '{}';
+const farmOrder = {};
 {}
 const previousStyle = document.querySelector(`style[data-farm-id="${{farmId}}"]`);
 const style = document.createElement('style');
 style.setAttribute('data-farm-id', farmId);
+style.setAttribute('data-farm-order', farmOrder);
 style.innerHTML = cssCode;
 if (previousStyle) {{
 previousStyle.replaceWith(style);
 }} else {{
+const nextStyle = Array.from(document.querySelectorAll('style[data-farm-order]')).find((s) => Number(s.getAttribute('data-farm-order')) > farmOrder);
+if (nextStyle) {{
+nextStyle.parentNode.insertBefore(style, nextStyle);
+}} else {{
 document.head.appendChild(style);
 }}
+}}
 
 if (module.meta.hot) {{
   module.meta.hot.accept();
@@ -276,6 +303,7 @@ if (module.meta.hot) {{
     style.remove();
   }});
 }}
+{}
 "#,
     format!(
       "{}\n{}",
@@ -290,6 +318,12 @@ if (module.meta.hot) {{
       }
     ),
     id.replace('\\', "\\\\"),
+    order,
     css_deps,
+    if export_style_element {
+      "export default style;"
+    } else {
+      ""
+    },
   )
 }
@@ -29,7 +29,7 @@ use farmfe_toolkit::common::{create_swc_source_map, MinifyBuilder, Source};
 use farmfe_toolkit::minify::minify_html_module;
 use farmfe_toolkit::{
   fs::read_file_utf8,
-  get_dynamic_resources_map::get_dynamic_resources_map,
+  get_dynamic_resources_map::{get_dynamic_resources_map, get_resource_dependencies},
   html::{codegen_html_document, parse_html_document},
   script::{module_type_from_id, swc_try_with::try_with},
 };
@@ -379,6 +379,11 @@ impl Plugin for FarmPluginTransformHtml {
           .is_some_and(|m| matches!(m.module_type, ModuleType::Html))
       })
       .collect::<Vec<_>>();
+    let resource_dependencies = get_resource_dependencies(
+      &context.resource_pot_map.read(),
+      params.resources_map,
+      &module_graph,
+    );
 
     let mut resources_to_inject = HashMap::new();
 
@@ -477,6 +482,7 @@ impl Plugin for FarmPluginTransformHtml {
         css_resources,
         script_entries,
         dynamic_resources_map,
+        resource_dependencies.clone(),
         ResourcesInjectorOptions {
           mode: context.config.mode.clone(),
           public_path: context.config.output.public_path.clone(),
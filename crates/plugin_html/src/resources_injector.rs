@@ -1,15 +1,16 @@
 use std::{borrow::Cow, collections::HashMap, rc::Rc, sync::Arc};
 
 use farmfe_core::{
-  config::{custom::get_config_runtime_isolate, Mode, FARM_MODULE_SYSTEM},
+  config::{custom::get_config_runtime_isolate, Mode, RuntimeHtmlInject, FARM_MODULE_SYSTEM},
   context::CompilationContext,
   module::ModuleId,
-  resource::{Resource, ResourceType},
+  resource::{Resource, ResourceOrigin, ResourceType},
   swc_html_ast::{Child, Document, Element},
 };
 use farmfe_toolkit::{
   get_dynamic_resources_map::get_dynamic_resources_code,
-  html::{create_element, get_farm_global_this},
+  html::{create_attribute, create_element, get_farm_global_this},
+  standalone_runtime::StandaloneRuntime,
   swc_html_visit::{VisitMut, VisitMutWith},
 };
 
@@ -34,6 +35,7 @@ pub struct ResourcesInjector<'a> {
   css_resources: Vec<String>,
   script_entries: Vec<String>,
   dynamic_resources_map: HashMap<ModuleId, Vec<(String, ResourceType)>>,
+  resource_dependencies: HashMap<String, Vec<String>>,
   options: ResourcesInjectorOptions,
   farm_global_this: String,
   already_injected_resources: &'a mut Vec<String>,
@@ -50,6 +52,7 @@ impl<'a> ResourcesInjector<'a> {
     css_resources: Vec<String>,
     script_entries: Vec<String>,
     dynamic_resources_map: HashMap<ModuleId, Vec<(String, ResourceType)>>,
+    resource_dependencies: HashMap<String, Vec<String>>,
     options: ResourcesInjectorOptions,
     already_injected_resources: &'a mut Vec<String>,
   ) -> Self {
@@ -60,15 +63,26 @@ impl<'a> ResourcesInjector<'a> {
       script_resources,
       script_entries,
       dynamic_resources_map,
-      farm_global_this: get_farm_global_this(
-        &options.namespace,
-        &options.context.config.output.target_env,
-      ),
+      resource_dependencies,
+      farm_global_this: get_farm_global_this(&options.namespace, &options.context.config.output),
       options,
       already_injected_resources,
     }
   }
 
+  /// create a `<script>` with the nonce configured by `runtime.nonce`
+  fn create_script(&self, code: Option<&str>, attrs: Vec<(&str, &str)>) -> Element {
+    let mut script = create_element("script", code, attrs);
+
+    if let Some(nonce) = &self.options.context.config.runtime.nonce {
+      script
+        .attributes
+        .push(create_attribute("nonce", Some(nonce)));
+    }
+
+    script
+  }
+
   pub fn inject(&mut self, ast: &mut Document) {
     ast.visit_mut_with(self);
   }
@@ -83,26 +97,53 @@ impl<'a> ResourcesInjector<'a> {
 
   // Support isolate runtime resource (https://github.com/farm-fe/farm/issues/434)
   fn inject_runtime_resources(&mut self, element: &mut Element) {
-    if get_config_runtime_isolate(&self.options.context) {
-      let (name, resource) = create_farm_runtime_output_resource(
-        Cow::Borrowed(self.runtime_code.as_bytes()),
-        FARM_RUNTIME_INJECT_RESOURCE,
-        &self.options.context,
-        &self.already_injected_resources,
-      );
-
-      let script_element = create_element("script", None, vec![("src", &format!("/{name}"))]);
-      element.children.push(Child::Element(script_element));
+    if let Some(standalone_config) = &self.options.context.config.runtime.standalone {
+      let standalone_runtime = StandaloneRuntime::new(&self.runtime_code, &self.farm_global_this);
+      let url = standalone_runtime.url(standalone_config, &self.options.public_path);
+
+      element.children.push(Child::Element(
+        self.create_script(None, vec![("src", &url)]),
+      ));
+      element.children.push(Child::Element(self.create_script(
+        Some(&standalone_runtime.version_check_code(&self.farm_global_this)),
+        vec![],
+      )));
 
-      if let Some(resource) = resource {
-        self.additional_inject_resources.push(resource);
+      if standalone_config.url.is_none()
+        && !self
+          .already_injected_resources
+          .contains(&standalone_runtime.name)
+      {
+        self.additional_inject_resources.push(Resource {
+          name: standalone_runtime.name.clone(),
+          bytes: standalone_runtime.code.into_bytes(),
+          emitted: false,
+          resource_type: ResourceType::Js,
+          origin: ResourceOrigin::ResourcePot(standalone_runtime.name),
+          info: None,
+        });
       }
+    } else if get_config_runtime_isolate(&self.options.context) {
+      let runtime_code = self.runtime_code.clone();
+      self.inject_script_resource(
+        element,
+        Cow::Borrowed(runtime_code.as_bytes()),
+        FARM_RUNTIME_INJECT_RESOURCE,
+        "/",
+      );
+    } else if self.options.context.config.runtime.html_inject == RuntimeHtmlInject::External {
+      let runtime_code = self.runtime_code.clone();
+      let public_path = self.options.public_path.clone();
+      self.inject_script_resource(
+        element,
+        Cow::Borrowed(runtime_code.as_bytes()),
+        FARM_RUNTIME_INJECT_RESOURCE,
+        &public_path,
+      );
     } else {
-      element.children.push(Child::Element(create_element(
-        "script",
-        Some(&self.runtime_code),
-        vec![],
-      )));
+      element.children.push(Child::Element(
+        self.create_script(Some(&self.runtime_code), vec![]),
+      ));
     }
   }
 
@@ -118,8 +159,7 @@ impl<'a> ResourcesInjector<'a> {
       .collect::<Vec<_>>()
       .join(",");
 
-    element.children.push(Child::Element(create_element(
-      "script",
+    element.children.push(Child::Element(self.create_script(
       Some(&format!(
         r#"{}.{}.setInitialLoadedResources([{}]);"#,
         self.farm_global_this, FARM_MODULE_SYSTEM, initial_resources_code
@@ -129,8 +169,11 @@ impl<'a> ResourcesInjector<'a> {
   }
 
   fn inject_dynamic_resources_map(&mut self, element: &mut Element) {
-    let (dynamic_resources, dynamic_module_resources_map) =
-      get_dynamic_resources_code(&self.dynamic_resources_map, self.options.mode.clone());
+    let (dynamic_resources, dynamic_module_resources_map) = get_dynamic_resources_code(
+      &self.dynamic_resources_map,
+      &self.resource_dependencies,
+      self.options.mode.clone(),
+    );
 
     if dynamic_resources.is_empty() {
       return;
@@ -142,28 +185,16 @@ impl<'a> ResourcesInjector<'a> {
     );
 
     if get_config_runtime_isolate(&self.options.context) {
-      let (name, resource) = create_farm_runtime_output_resource(
+      self.inject_script_resource(
+        element,
         Cow::Owned(finalize_code.into_bytes()),
         FARM_DYNAMIC_RESOURCES_MAP_RESOURCE,
-        &self.options.context,
-        &self.already_injected_resources,
+        "/",
       );
-
-      element.children.push(Child::Element(create_element(
-        "script",
-        None,
-        vec![("src", &format!("/{name}"))],
-      )));
-
-      if let Some(resource) = resource {
-        self.additional_inject_resources.push(resource);
-      }
     } else {
-      element.children.push(Child::Element(create_element(
-        "script",
-        Some(&finalize_code),
-        vec![],
-      )));
+      element.children.push(Child::Element(
+        self.create_script(Some(&finalize_code), vec![]),
+      ));
     }
   }
 
@@ -177,16 +208,13 @@ impl<'a> ResourcesInjector<'a> {
       FARM_GLOBAL_THIS = self.farm_global_this,
     );
 
-    element.children.push(Child::Element(create_element(
-      "script",
-      Some(&code),
-      vec![],
-    )));
+    element
+      .children
+      .push(Child::Element(self.create_script(Some(&code), vec![])));
   }
 
   fn inject_other_entry_file(&self, element: &mut Element) {
-    element.children.push(Child::Element(create_element(
-      "script",
+    element.children.push(Child::Element(self.create_script(
       Some(&format!(
         r#"{}.{}.setPublicPaths(['{}']);"#,
         self.farm_global_this, FARM_MODULE_SYSTEM, self.options.public_path
@@ -194,8 +222,7 @@ impl<'a> ResourcesInjector<'a> {
       vec![],
     )));
 
-    element.children.push(Child::Element(create_element(
-      "script",
+    element.children.push(Child::Element(self.create_script(
       Some(&format!(
         r#"{}.{}.bootstrap();"#,
         self.farm_global_this, FARM_MODULE_SYSTEM
@@ -204,8 +231,7 @@ impl<'a> ResourcesInjector<'a> {
     )));
 
     for entry in &self.script_entries {
-      element.children.push(Child::Element(create_element(
-        "script",
+      element.children.push(Child::Element(self.create_script(
         Some(&format!(
           r#"{}.{}.require("{}")"#,
           self.farm_global_this, FARM_MODULE_SYSTEM, entry
@@ -231,19 +257,32 @@ impl<'a> ResourcesInjector<'a> {
         self.farm_global_this, FARM_MODULE_SYSTEM, entry
       ))
     }
-    // create resource
-    let (name, resource) = create_farm_runtime_output_resource(
+    self.inject_script_resource(
+      element,
       Cow::Owned(finalize_code.into_bytes()),
       FARM_MODULE_SYSTEM_RESOURCE,
+      "/",
+    );
+  }
+
+  /// emit `code` as a separate resource named after `resource_name` and load it by a `<script src>` under `public_path`
+  fn inject_script_resource(
+    &mut self,
+    element: &mut Element,
+    code: Cow<[u8]>,
+    resource_name: &str,
+    public_path: &str,
+  ) {
+    let (name, resource) = create_farm_runtime_output_resource(
+      code,
+      resource_name,
       &self.options.context,
-      &self.already_injected_resources
+      self.already_injected_resources,
     );
-    // inject script
-    element.children.push(Child::Element(create_element(
-      "script",
-      None,
-      vec![("src", &format!("/{name}"))],
-    )));
+
+    element.children.push(Child::Element(
+      self.create_script(None, vec![("src", &format!("{public_path}{name}"))]),
+    ));
 
     if let Some(resource) = resource {
       self.additional_inject_resources.push(resource);
@@ -299,8 +338,7 @@ impl<'a> VisitMut for ResourcesInjector<'a> {
       }
     } else if element.tag_name.to_string() == "body" {
       for script in &self.script_resources {
-        element.children.push(Child::Element(create_element(
-          "script",
+        element.children.push(Child::Element(self.create_script(
           None,
           vec![
             ("src", &format!("{}{}", self.options.public_path, script)),
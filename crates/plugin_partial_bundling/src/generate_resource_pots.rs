@@ -27,16 +27,16 @@ pub fn generate_resource_pots(
 ) -> Vec<ResourcePot> {
   let mut resource_pots = vec![];
   let mut handled_module_group_buckets = HashSet::new();
-  let mut used_resource_pot_names = HashSet::new();
+  let mut base_resource_pot_names = generate_resource_pot_names(
+    module_group_buckets
+      .iter()
+      .map(|module_group_bucket| &module_group_bucket.module_group_id),
+    module_graph,
+  );
 
   for mut module_group_bucket in module_group_buckets {
     let module_group_id = module_group_bucket.module_group_id;
-    let base_resource_pot_name = generate_resource_pot_name(
-      module_group_id.clone(),
-      &used_resource_pot_names,
-      &module_graph,
-    );
-    used_resource_pot_names.insert(base_resource_pot_name.clone());
+    let base_resource_pot_name = base_resource_pot_names.remove(&module_group_id).unwrap();
 
     let mut module_group_module_pots = ModuleGroupModulePots::new(module_group_id.clone());
 
@@ -82,6 +82,32 @@ pub fn generate_resource_pots(
   resource_pots
 }
 
+/// Generate the base resource pot names of the module groups. The names are assigned in a stable order,
+/// entries first and then the other module groups sorted by id, so the same module group always gets the same name
+/// whatever the order the module groups are traversed in, and reproducible builds produce identical filenames.
+fn generate_resource_pot_names<'a>(
+  module_group_ids: impl Iterator<Item = &'a ModuleGroupId>,
+  module_graph: &ModuleGraph,
+) -> HashMap<ModuleGroupId, String> {
+  let mut module_group_ids = module_group_ids.collect::<Vec<_>>();
+  module_group_ids.sort_by_key(|id| (!module_graph.entries.contains_key(*id), id.to_string()));
+
+  let mut used_resource_pot_names = HashSet::new();
+  let mut resource_pot_names = HashMap::new();
+
+  for module_group_id in module_group_ids {
+    let name = generate_resource_pot_name(
+      module_group_id.clone(),
+      &used_resource_pot_names,
+      module_graph,
+    );
+    used_resource_pot_names.insert(name.clone());
+    resource_pot_names.insert(module_group_id.clone(), name);
+  }
+
+  resource_pot_names
+}
+
 /// Generate resource pot id from module group id.
 /// 1. If module_group_id is entry module group, then the resource pot id is the name defined in config.
 /// 2. If module_group_id is not entry module group, then the resource pot id is the module group id's filename(without extension).
@@ -124,7 +150,7 @@ mod tests {
   use farmfe_core::module::{module_graph::ModuleGraph, module_group::ModuleGroupId, Module};
   use std::collections::HashSet;
 
-  use crate::generate_resource_pots::generate_resource_pot_name;
+  use crate::generate_resource_pots::{generate_resource_pot_name, generate_resource_pot_names};
 
   #[test]
   fn test_generate_resource_pot_name() {
@@ -164,4 +190,28 @@ mod tests {
       "test_src_api"
     );
   }
+
+  #[test]
+  fn test_generate_resource_pot_names() {
+    let mut module_graph = ModuleGraph::new();
+    let entry = Module::new("src/a.ts".into());
+    module_graph
+      .entries
+      .insert(entry.id.clone(), "index".to_string());
+    module_graph.add_module(entry);
+
+    let group_ids: Vec<ModuleGroupId> = vec![
+      "src/widgets/a.ts".into(),
+      "src/pages/a.ts".into(),
+      "src/a.ts".into(),
+    ];
+
+    let names = generate_resource_pot_names(group_ids.iter(), &module_graph);
+    let reversed_names = generate_resource_pot_names(group_ids.iter().rev(), &module_graph);
+
+    assert_eq!(names, reversed_names);
+    assert_eq!(names[&"src/a.ts".into()], "index");
+    assert_eq!(names[&"src/pages/a.ts".into()], "a");
+    assert_eq!(names[&"src/widgets/a.ts".into()], "widgets_a");
+  }
 }
@@ -3,16 +3,17 @@ use std::path::PathBuf;
 use farmfe_core::{
   config::{
     config_regex::ConfigRegex,
-    preset_env::{PresetEnvConfig, PresetEnvConfigObj},
+    preset_env::{BrowserslistQuery, PresetEnvConfig, PresetEnvConfigObj},
     Config,
   },
+  error::{CompilationError, Result},
   plugin::Plugin,
   serde_json,
   swc_common::{comments::SingleThreadedComments, Mark},
 };
 use farmfe_toolkit::{
   common::{create_swc_source_map, Source},
-  preset_env_base::query::Query,
+  preset_env_base::query::{targets_to_versions, Query},
   script::swc_try_with::try_with,
   swc_ecma_preset_env::{self, preset_env, Mode, Targets},
   swc_ecma_transforms::Assumptions,
@@ -29,7 +30,7 @@ pub struct FarmPluginPolyfill {
 }
 
 impl FarmPluginPolyfill {
-  pub fn new(config: &Config) -> Self {
+  pub fn new(config: &Config) -> Result<Self> {
     let (config, include, exclude, assumptions) = match &*config.preset_env {
       PresetEnvConfig::Bool(_) => {
         let PresetEnvConfigObj {
@@ -50,6 +51,22 @@ impl FarmPluginPolyfill {
           Default::default(),
         )
       }
+      PresetEnvConfig::Targets(query) => {
+        let PresetEnvConfigObj {
+          include, exclude, ..
+        } = PresetEnvConfigObj::default();
+
+        (
+          swc_ecma_preset_env::Config {
+            mode: Some(Mode::Usage),
+            targets: Some(Targets::Versions(resolve_browserslist_query(query)?)),
+            ..Default::default()
+          },
+          include,
+          exclude,
+          Default::default(),
+        )
+      }
       PresetEnvConfig::Obj(obj) => {
         let options = &obj.options;
         let mut user_config: swc_ecma_preset_env::Config =
@@ -69,16 +86,33 @@ impl FarmPluginPolyfill {
       }
     };
 
-    Self {
+    Ok(Self {
       config,
       include,
       exclude,
       assumptions,
       enforce_exclude: vec![ConfigRegex::new("node_modules/core-js")],
-    }
+    })
   }
 }
 
+/// Resolve the browsers matched by the query to their minimum versions, which decide the transforms and helpers of preset env
+fn resolve_browserslist_query(query: &BrowserslistQuery) -> Result<swc_ecma_preset_env::Versions> {
+  let query = query.0.trim();
+
+  if query.is_empty() {
+    return Err(CompilationError::GenericError(
+      "The browserslist query of `presetEnv` is empty, please specify the targets like `> 0.5%, last 2 versions` or set `presetEnv` to true to use the default targets".to_string(),
+    ));
+  }
+
+  targets_to_versions(Some(Targets::Query(Query::Single(query.to_string())))).map_err(|e| {
+    CompilationError::GenericError(format!(
+      "Invalid browserslist query `{query}` of `presetEnv`: {e:#}"
+    ))
+  })
+}
+
 impl Plugin for FarmPluginPolyfill {
   fn name(&self) -> &str {
     "FarmPluginPolyfill"
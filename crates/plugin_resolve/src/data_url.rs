@@ -0,0 +1,101 @@
+//! Imports of `data:` URLs, e.g. `import x from 'data:text/javascript,export default 1'`.
+//!
+//! The data URL is resolved to `virtual:__FARM_DATA_URL__:data:...` and decoded to an inline module when it's loaded,
+//! the module type is decided by the MIME type of the data URL.
+use farmfe_core::{
+  error::{CompilationError, Result},
+  module::ModuleType,
+  plugin::{PluginLoadHookResult, PluginResolveHookResult},
+};
+use farmfe_toolkit::hash::base64_decode;
+
+pub const DATA_URL_PREFIX: &str = "data:";
+/// data URLs are resolved to `virtual:__FARM_DATA_URL__:data:...`, the data URL is decoded when the module is loaded
+pub const DATA_URL_MODULE_PREFIX: &str = "virtual:__FARM_DATA_URL__:";
+
+pub fn is_data_url(source: &str) -> bool {
+  source.starts_with(DATA_URL_PREFIX)
+}
+
+pub fn resolve_data_url(source: &str) -> PluginResolveHookResult {
+  PluginResolveHookResult {
+    resolved_path: format!("{DATA_URL_MODULE_PREFIX}{source}"),
+    external: false,
+    side_effects: true,
+    ..Default::default()
+  }
+}
+
+/// Decode the data URL of `resolved_path`, return [None] if `resolved_path` is not a resolved data URL
+pub fn load_data_url(resolved_path: &str) -> Option<Result<PluginLoadHookResult>> {
+  let data_url = resolved_path.strip_prefix(DATA_URL_MODULE_PREFIX)?;
+
+  Some(
+    decode_data_url(data_url).map_err(|message| CompilationError::LoadError {
+      resolved_path: data_url.to_string(),
+      source: Some(message.into()),
+    }),
+  )
+}
+
+/// `data:[<mediatype>][;base64],<data>`
+fn decode_data_url(data_url: &str) -> std::result::Result<PluginLoadHookResult, String> {
+  let (header, data) = data_url[DATA_URL_PREFIX.len()..]
+    .split_once(',')
+    .ok_or_else(|| "Invalid data URL, the `,` before the data is missing".to_string())?;
+
+  let mut params = header.split(';');
+  let mime = params.next().unwrap_or_default().trim().to_lowercase();
+  let is_base64 = params.any(|param| param.trim().eq_ignore_ascii_case("base64"));
+
+  let module_type = match mime.as_str() {
+    "text/javascript" | "application/javascript" | "text/ecmascript" | "application/ecmascript" => {
+      ModuleType::Js
+    }
+    "application/json" => ModuleType::Custom("json".to_string()),
+    "text/css" => ModuleType::Css,
+    _ => {
+      return Err(format!(
+        "Unsupported MIME type `{mime}` of data URL, only javascript, json and css are supported"
+      ))
+    }
+  };
+
+  let content = if is_base64 {
+    base64_decode(data.as_bytes())
+  } else {
+    String::from_utf8(percent_decode(data))
+      .map_err(|_| "The data of data URL is not valid utf-8".to_string())?
+  };
+
+  Ok(PluginLoadHookResult {
+    content,
+    module_type,
+    source_map: None,
+  })
+}
+
+/// Decode the `%XX` escapes of `data`, invalid escapes are kept as is
+fn percent_decode(data: &str) -> Vec<u8> {
+  let bytes = data.as_bytes();
+  let mut decoded = Vec::with_capacity(bytes.len());
+  let mut i = 0;
+
+  while i < bytes.len() {
+    let escaped = (bytes[i] == b'%')
+      .then(|| bytes.get(i + 1..i + 3))
+      .flatten()
+      .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+      .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+
+    if let Some(byte) = escaped {
+      decoded.push(byte);
+      i += 3;
+    } else {
+      decoded.push(bytes[i]);
+      i += 1;
+    }
+  }
+
+  decoded
+}
@@ -5,21 +5,31 @@ use std::{
 };
 
 use farmfe_core::{
-  config::{custom::get_config_resolve_dedupe, external::ExternalConfig, Config},
-  context::CompilationContext,
+  config::{
+    asset::NativeAddonMode,
+    custom::{get_config_external_prefer, get_config_resolve_dedupe},
+    external::{ExternalConfig, ExternalPrefer},
+    Config,
+  },
+  context::{CompilationContext, EmitFileParams},
   error::Result,
   farm_profile_function, farm_profile_scope,
+  module::ModuleType,
   plugin::{
-    Plugin, PluginHookContext, PluginResolveHookParam, PluginResolveHookResult, ResolveKind,
+    Plugin, PluginHookContext, PluginLoadHookParam, PluginLoadHookResult, PluginResolveHookParam,
+    PluginResolveHookResult, ResolveKind,
   },
+  resource::ResourceType,
   serde_json,
 };
 
-use farmfe_toolkit::resolve::DYNAMIC_EXTENSION_PRIORITY;
+use data_url::{is_data_url, load_data_url, resolve_data_url};
+use farmfe_toolkit::{fs::read_file_raw, resolve::DYNAMIC_EXTENSION_PRIORITY};
 use farmfe_utils::parse_query;
 use once_cell::sync::OnceCell;
-use resolver::{parse_package_source, ResolveOptions, Resolver};
+use resolver::{parse_package_source, ResolveOptions, Resolver, BROWSER_EMPTY_MODULE_PREFIX};
 
+pub mod data_url;
 pub mod resolver;
 
 pub struct FarmPluginResolve {
@@ -27,6 +37,7 @@ pub struct FarmPluginResolve {
   resolver: Resolver,
   external_config: OnceCell<ExternalConfig>,
   dedupe: HashSet<String>,
+  external_prefer: ExternalPrefer,
 }
 
 impl FarmPluginResolve {
@@ -36,7 +47,48 @@ impl FarmPluginResolve {
       root: config.root.clone(),
       resolver: Resolver::new(),
       external_config: OnceCell::new(),
+      external_prefer: get_config_external_prefer(config),
+    }
+  }
+
+  /// Native addons can not be bundled, so `.node` files are kept external when targeting node.
+  /// The external id is the file name of the addon, and the addon is copied to the output dir when `assets.nativeAddon` is `copy`.
+  fn handle_native_addon(
+    &self,
+    result: PluginResolveHookResult,
+    context: &Arc<CompilationContext>,
+  ) -> Result<PluginResolveHookResult> {
+    let resolved_path = Path::new(&result.resolved_path);
+
+    if result.external
+      || !context.config.output.target_env.is_node()
+      || resolved_path.extension().and_then(|ext| ext.to_str()) != Some("node")
+    {
+      return Ok(result);
     }
+
+    let name = resolved_path
+      .file_name()
+      .unwrap()
+      .to_string_lossy()
+      .to_string();
+
+    if matches!(context.config.assets.native_addon, NativeAddonMode::Copy) {
+      context.emit_file(EmitFileParams {
+        resolved_path: result.resolved_path.clone(),
+        name: name.clone(),
+        content: read_file_raw(&result.resolved_path)?,
+        resource_type: ResourceType::Asset("node".to_string()),
+      });
+    }
+
+    Ok(PluginResolveHookResult {
+      resolved_path: format!("./{name}"),
+      external: true,
+      side_effects: false,
+      query: vec![],
+      meta: HashMap::new(),
+    })
   }
 }
 
@@ -78,18 +130,24 @@ impl Plugin for FarmPluginResolve {
       };
 
     // Entry module and internal modules should not be external
-    if !matches!(param.kind, ResolveKind::Entry(_)) {
-      farm_profile_scope!("plugin_resolve::resolve::check_external".to_string());
-      // check external first, if the source is set as external, return it immediately
-      if external_config.is_external(source) {
-        return Ok(Some(PluginResolveHookResult {
-          resolved_path: param.source.clone(),
-          external: true,
-          side_effects: false,
-          query,
-          meta: HashMap::new(),
-        }));
-      }
+    let external_result = (!matches!(param.kind, ResolveKind::Entry(_))
+      && external_config.is_external(source))
+    .then(|| PluginResolveHookResult {
+      resolved_path: param.source.clone(),
+      external: true,
+      side_effects: false,
+      query: query.clone(),
+      meta: HashMap::new(),
+    });
+
+    // check external first, if the source is set as external and external is preferred, return it immediately
+    if external_result.is_some() && self.external_prefer == ExternalPrefer::External {
+      return Ok(external_result);
+    }
+
+    // data URLs are loaded as inline modules, `?` and `#` are part of the data
+    if is_data_url(&param.source) {
+      return Ok(Some(resolve_data_url(&param.source)));
     }
 
     let dynamic_extensions =
@@ -127,6 +185,15 @@ impl Plugin for FarmPluginResolve {
       result.map(|result| PluginResolveHookResult { query, ..result })
     };
 
+    if let Some(result) = resolve_result {
+      resolve_result = Some(self.handle_native_addon(result, context)?);
+    }
+
+    // local is preferred, the source is external only when it can not be resolved locally
+    if resolve_result.is_none() && external_result.is_some() {
+      return Ok(external_result);
+    }
+
     if resolve_result.is_none() && context.config.resolve.auto_external_failed_resolve {
       resolve_result = Some(PluginResolveHookResult {
         resolved_path: param.source.clone(),
@@ -139,4 +206,26 @@ impl Plugin for FarmPluginResolve {
 
     Ok(resolve_result)
   }
+
+  fn load(
+    &self,
+    param: &PluginLoadHookParam,
+    _context: &Arc<CompilationContext>,
+    _hook_context: &PluginHookContext,
+  ) -> Result<Option<PluginLoadHookResult>> {
+    // modules ignored by the browser field are replaced by an empty module
+    if param.resolved_path.starts_with(BROWSER_EMPTY_MODULE_PREFIX) {
+      return Ok(Some(PluginLoadHookResult {
+        content: "module.exports = {};".to_string(),
+        module_type: ModuleType::Js,
+        source_map: None,
+      }));
+    }
+
+    if let Some(result) = load_data_url(param.resolved_path) {
+      return result.map(Some);
+    }
+
+    Ok(None)
+  }
 }
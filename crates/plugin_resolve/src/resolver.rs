@@ -8,6 +8,7 @@ use std::{
 use farmfe_core::regex;
 use farmfe_core::{
   common::PackageJsonInfo,
+  config::TargetEnv,
   context::CompilationContext,
   farm_profile_function,
   parking_lot::Mutex,
@@ -18,6 +19,7 @@ use farmfe_core::{
 
 use farmfe_toolkit::resolve::{follow_symlinks, load_package_json, package_json_loader::Options};
 use farmfe_utils::relative;
+use once_cell::sync::OnceCell;
 
 use crate::resolver::browser::try_browser_map;
 use crate::resolver::exports::resolve_exports_or_imports;
@@ -27,9 +29,11 @@ use crate::resolver::utils::{
 };
 
 use self::browser::{BrowserMapResult, BrowserMapType};
+use self::tsconfig_paths::TsconfigPaths;
 
 mod browser;
 mod exports;
+mod tsconfig_paths;
 mod utils;
 
 pub use utils::parse_package_source;
@@ -50,10 +54,14 @@ pub struct ResolveOptions {
 pub struct Resolver {
   /// the key is (source, base_dir) and the value is the resolved result
   resolve_cache: Mutex<HashMap<ResolveCacheKey, Option<PluginResolveHookResult>>>,
+  /// paths of the tsconfig.json under the root, loaded on first use
+  tsconfig_paths: OnceCell<Option<TsconfigPaths>>,
 }
 
 pub const NODE_MODULES: &str = "node_modules";
-const BROWSER_SUBPATH_EXTERNAL_ID: &str = "__FARM_BROWSER_SUBPATH_EXTERNAL__";
+const BROWSER_SUBPATH_EMPTY_ID: &str = "__FARM_BROWSER_SUBPATH_EMPTY__";
+/// modules ignored by `"browser": { "xxx": false }` are resolved to `virtual:__FARM_BROWSER_EMPTY_MODULE__:xxx` and loaded as empty module
+pub const BROWSER_EMPTY_MODULE_PREFIX: &str = "virtual:__FARM_BROWSER_EMPTY_MODULE__:";
 const REGEX_PREFIX: &str = "$__farm_regex:";
 const HIGHEST_PRIORITY_FIELD: &str = "exports";
 
@@ -61,6 +69,7 @@ impl Resolver {
   pub fn new() -> Self {
     Self {
       resolve_cache: Mutex::new(HashMap::new()),
+      tsconfig_paths: OnceCell::new(),
     }
   }
 
@@ -103,6 +112,7 @@ impl Resolver {
   /// * **Relative Path**: './xxx' or '../xxx'
   /// * **Absolute Path**: '/root/xxx' or 'c:\\root\\xxx'
   /// * **Configured Alias**: '@/pages/xxx'
+  /// * **Tsconfig Paths**: `compilerOptions.paths` of tsconfig.json when `resolve.tsconfigPaths` is enabled
   /// * **Package**:
   ///   * **exports**: refer to [exports](https://nodejs.org/api/packages.html#packages_conditional_exports), if source is end with '.js', also try to find '.ts' file
   ///   * **browser**: refer to [package-browser-field-spec](https://github.com/defunctzombie/package-browser-field-spec)
@@ -128,6 +138,7 @@ impl Resolver {
 
     self
       .try_alias(source, base_dir.clone(), kind, options, context)
+      .or_else(|| self.try_tsconfig_paths(source, base_dir.clone(), kind, options, context))
       .or_else(|| {
         self.try_relative_or_absolute_path(source, base_dir.clone(), kind, options, context)
       })
@@ -205,13 +216,8 @@ impl Resolver {
             }
           });
         }
-        BrowserMapResult::External => {
-          return Some(PluginResolveHookResult {
-            resolved_path: browser_map_type.to_string(),
-            external: true,
-            side_effects: false,
-            ..Default::default()
-          });
+        BrowserMapResult::Empty => {
+          return Some(browser_empty_module_result(&browser_map_type.to_string()));
         }
       }
     }
@@ -377,26 +383,29 @@ impl Resolver {
   }
 
   /// Try resolve as a file with the configured extensions.
-  /// If `/root/index` exists, return `/root/index`, otherwise try `/root/index.[configured extension]` in order, once any extension exists (like `/root/index.ts`), return it immediately
+  /// If `/root/index` exists, return `/root/index`, otherwise try `/root/index.[configured extension]` in order, once any extension exists (like `/root/index.ts`), return it immediately.
+  /// Variants of the file matched by `resolve.conditionalSuffixes` (like `/root/index.browser.ts`) are preferred.
   fn try_file(
     &self,
-    file: &PathBuf,
+    file: &Path,
+    options: &ResolveOptions,
+    context: &Arc<CompilationContext>,
+  ) -> Option<String> {
+    self
+      .try_conditional_file(file, options, context)
+      .or_else(|| self.try_file_with_extensions(file, options, context))
+  }
+
+  fn try_file_with_extensions(
+    &self,
+    file: &Path,
     options: &ResolveOptions,
     context: &Arc<CompilationContext>,
   ) -> Option<String> {
-    // TODO add a test that for directory imports like `import 'comps/button'` where comps/button is a dir
     if file.exists() && file.is_file() {
       Some(file.to_string_lossy().to_string())
     } else {
-      let append_extension = |file: &PathBuf, ext: &str| {
-        let file_name = file.file_name().unwrap().to_string_lossy().to_string();
-        file.with_file_name(format!("{file_name}.{ext}"))
-      };
-      let extensions = if let Some(ext) = &options.dynamic_extensions {
-        ext
-      } else {
-        &context.config.resolve.extensions
-      };
+      let extensions = self.extensions(options, context);
       let ext = extensions.iter().find(|&ext| {
         let new_file = append_extension(file, ext);
         new_file.exists() && new_file.is_file()
@@ -406,6 +415,64 @@ impl Resolver {
     }
   }
 
+  /// Try the variants of the file with `resolve.conditionalSuffixes`, for example, with suffix `.{target}`
+  /// and browser target, `/root/impl` resolves to `/root/impl.browser.[ext]` and `/root/impl.js` resolves to `/root/impl.browser.js`
+  fn try_conditional_file(
+    &self,
+    file: &Path,
+    options: &ResolveOptions,
+    context: &Arc<CompilationContext>,
+  ) -> Option<String> {
+    let suffixes = &context.config.resolve.conditional_suffixes;
+
+    // the suffix convention is only applied to the source code of the project
+    if suffixes.is_empty() || file.components().any(|c| c.as_os_str() == "node_modules") {
+      return None;
+    }
+
+    let file_name = file.file_name()?.to_string_lossy().to_string();
+    let target = conditional_target(&context.config.output.target_env);
+    let extensions = self.extensions(options, context);
+    // the source is imported with the extension, like `./impl.js`
+    let file_ext = file
+      .extension()
+      .map(|ext| ext.to_string_lossy().to_string())
+      .filter(|ext| extensions.contains(ext));
+
+    for suffix in suffixes {
+      let suffix = suffix.replace("{target}", target);
+
+      if let Some(ext) = &file_ext {
+        let stem = file_name.strip_suffix(&format!(".{ext}")).unwrap();
+        let variant = file.with_file_name(format!("{stem}{suffix}.{ext}"));
+
+        if variant.is_file() {
+          return Some(variant.to_string_lossy().to_string());
+        }
+      }
+
+      let variant = file.with_file_name(format!("{file_name}{suffix}"));
+
+      if let Some(found) = self.try_file_with_extensions(&variant, options, context) {
+        return Some(found);
+      }
+    }
+
+    None
+  }
+
+  fn extensions<'a>(
+    &self,
+    options: &'a ResolveOptions,
+    context: &'a Arc<CompilationContext>,
+  ) -> &'a Vec<String> {
+    if let Some(ext) = &options.dynamic_extensions {
+      ext
+    } else {
+      &context.config.resolve.extensions
+    }
+  }
+
   fn try_alias(
     &self,
     source: &str,
@@ -464,6 +531,41 @@ impl Resolver {
     None
   }
 
+  fn try_tsconfig_paths(
+    &self,
+    source: &str,
+    base_dir: PathBuf,
+    kind: &ResolveKind,
+    options: &ResolveOptions,
+    context: &Arc<CompilationContext>,
+  ) -> Option<PluginResolveHookResult> {
+    if !context.config.resolve.tsconfig_paths
+      || is_source_relative(source)
+      || is_source_absolute(source)
+    {
+      return None;
+    }
+
+    farm_profile_function!("try_tsconfig_paths".to_string());
+    let tsconfig_paths = self
+      .tsconfig_paths
+      .get_or_init(|| TsconfigPaths::load(Path::new(&context.config.root)))
+      .as_ref()?;
+
+    tsconfig_paths
+      .candidates(source)
+      .into_iter()
+      .find_map(|candidate| {
+        self.try_relative_or_absolute_path(
+          &candidate.to_string_lossy(),
+          base_dir.clone(),
+          kind,
+          options,
+          context,
+        )
+      })
+  }
+
   fn try_node_modules(
     &self,
     source: &str,
@@ -577,13 +679,10 @@ impl Resolver {
         };
 
         if let Some(resolved_path) = resolved_path {
-          let result = if resolved_path == BROWSER_SUBPATH_EXTERNAL_ID {
-            PluginResolveHookResult {
-              resolved_path,
-              external: true,
-              side_effects: false,
-              ..Default::default()
-            }
+          let result = if resolved_path == BROWSER_SUBPATH_EMPTY_ID {
+            browser_empty_module_result(source)
+          } else if let Some(source) = resolved_path.strip_prefix(BROWSER_EMPTY_MODULE_PREFIX) {
+            browser_empty_module_result(source)
           } else {
             let side_effects = load_package_json(
               package_path,
@@ -658,7 +757,7 @@ impl Resolver {
             )
             .map(|browser_map_result| match browser_map_result {
               BrowserMapResult::Str(mapped_value) => mapped_value,
-              BrowserMapResult::External => BROWSER_SUBPATH_EXTERNAL_ID.to_string(),
+              BrowserMapResult::Empty => BROWSER_SUBPATH_EMPTY_ID.to_string(),
             })
           } else {
             None
@@ -669,8 +768,8 @@ impl Resolver {
       subpath.to_string()
     };
 
-    if relative_path == BROWSER_SUBPATH_EXTERNAL_ID {
-      Some(BROWSER_SUBPATH_EXTERNAL_ID.to_string())
+    if relative_path == BROWSER_SUBPATH_EMPTY_ID {
+      Some(BROWSER_SUBPATH_EMPTY_ID.to_string())
     } else {
       self.try_relative_path(&relative_path, package_path, kind, options, context)
     }
@@ -811,3 +910,26 @@ impl Resolver {
     }
   }
 }
+
+fn append_extension(file: &Path, ext: &str) -> PathBuf {
+  let file_name = file.file_name().unwrap().to_string_lossy().to_string();
+  file.with_file_name(format!("{file_name}.{ext}"))
+}
+
+/// the value of `{target}` in `resolve.conditionalSuffixes`
+fn conditional_target(target_env: &TargetEnv) -> &str {
+  if target_env.is_node() {
+    "node"
+  } else {
+    "browser"
+  }
+}
+
+fn browser_empty_module_result(source: &str) -> PluginResolveHookResult {
+  PluginResolveHookResult {
+    resolved_path: format!("{BROWSER_EMPTY_MODULE_PREFIX}{source}"),
+    external: false,
+    side_effects: false,
+    ..Default::default()
+  }
+}
@@ -5,7 +5,8 @@ use crate::resolver::utils::get_field_value_from_package_json_info;
 
 pub enum BrowserMapResult {
   Str(String),
-  External,
+  /// `false` in the browser field, the module should be replaced by an empty module
+  Empty,
 }
 
 #[derive(Clone)]
@@ -45,7 +46,7 @@ pub fn try_browser_map(
       {
         match value {
           Value::String(str) => return Some(BrowserMapResult::Str(str.clone())),
-          Value::Bool(false) => return Some(BrowserMapResult::External),
+          Value::Bool(false) => return Some(BrowserMapResult::Empty),
           _ => {}
         }
       }
@@ -0,0 +1,202 @@
+use std::path::{Path, PathBuf};
+
+use farmfe_core::{
+  relative_path::RelativePath,
+  serde_json::{from_str, Map, Value},
+};
+
+pub const TSCONFIG_FILE: &str = "tsconfig.json";
+
+/// `compilerOptions.paths` of `tsconfig.json`, for example:
+/// ```json
+/// {
+///   "compilerOptions": {
+///     "baseUrl": "./src",
+///     "paths": { "@/*": ["./*"], "utils": ["./shared/utils"] }
+///   }
+/// }
+/// ```
+/// `extends` is not followed, the paths must be declared in the `tsconfig.json` under the root.
+#[derive(Debug)]
+pub struct TsconfigPaths {
+  base_url: PathBuf,
+  /// (pattern, targets), a pattern contains at most one `*`
+  paths: Vec<(String, Vec<String>)>,
+}
+
+impl TsconfigPaths {
+  /// Load the paths from `tsconfig.json` under `root`, returns None if the file does not exist or has no `paths`.
+  pub fn load(root: &Path) -> Option<Self> {
+    let tsconfig_path = root.join(TSCONFIG_FILE);
+    let content = std::fs::read_to_string(tsconfig_path).ok()?;
+    let tsconfig: Map<String, Value> = from_str(&strip_jsonc(&content)).ok()?;
+    let compiler_options = tsconfig.get("compilerOptions")?.as_object()?;
+
+    let paths = compiler_options
+      .get("paths")?
+      .as_object()?
+      .iter()
+      .map(|(pattern, targets)| {
+        let targets = targets
+          .as_array()
+          .map(|targets| {
+            targets
+              .iter()
+              .filter_map(|target| target.as_str().map(|s| s.to_string()))
+              .collect()
+          })
+          .unwrap_or_default();
+
+        (pattern.clone(), targets)
+      })
+      .collect();
+
+    // paths are resolved relative to the tsconfig.json when baseUrl is not set
+    let base_url = compiler_options
+      .get("baseUrl")
+      .and_then(|base_url| base_url.as_str())
+      .map(|base_url| RelativePath::new(base_url).to_logical_path(root))
+      .unwrap_or_else(|| root.to_path_buf());
+
+    Some(Self { base_url, paths })
+  }
+
+  /// Absolute paths that `source` may be mapped to, following the matching rules of typescript:
+  /// exact patterns win, otherwise the wildcard pattern with the longest prefix is used.
+  pub fn candidates(&self, source: &str) -> Vec<PathBuf> {
+    let mut matched: Option<(&Vec<String>, &str, usize)> = None;
+
+    for (pattern, targets) in &self.paths {
+      if let Some((prefix, suffix)) = pattern.split_once('*') {
+        if source.len() >= prefix.len() + suffix.len()
+          && source.starts_with(prefix)
+          && source.ends_with(suffix)
+          && matched.map_or(true, |(_, _, len)| prefix.len() > len)
+        {
+          let star = &source[prefix.len()..source.len() - suffix.len()];
+          matched = Some((targets, star, prefix.len()));
+        }
+      } else if pattern == source {
+        matched = Some((targets, "", usize::MAX));
+        break;
+      }
+    }
+
+    matched
+      .map(|(targets, star, _)| {
+        targets
+          .iter()
+          .map(|target| {
+            RelativePath::new(&target.replacen('*', star, 1)).to_logical_path(&self.base_url)
+          })
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+}
+
+/// strip the comments and trailing commas of tsconfig.json, which are allowed by typescript but not by json
+fn strip_jsonc(content: &str) -> String {
+  let mut result = String::with_capacity(content.len());
+  let mut chars = content.chars().peekable();
+  let mut in_string = false;
+
+  while let Some(c) = chars.next() {
+    if in_string {
+      result.push(c);
+
+      if c == '\\' {
+        if let Some(escaped) = chars.next() {
+          result.push(escaped);
+        }
+      } else if c == '"' {
+        in_string = false;
+      }
+
+      continue;
+    }
+
+    match (c, chars.peek()) {
+      ('"', _) => {
+        in_string = true;
+        result.push(c);
+      }
+      ('/', Some('/')) => {
+        while chars.peek().is_some_and(|c| *c != '\n') {
+          chars.next();
+        }
+      }
+      ('/', Some('*')) => {
+        chars.next();
+        let mut prev = ' ';
+        for c in chars.by_ref() {
+          if prev == '*' && c == '/' {
+            break;
+          }
+          prev = c;
+        }
+      }
+      ('}' | ']', _) => {
+        // drop the trailing comma before `}` or `]`
+        let trimmed_len = result.trim_end().len();
+        if result[..trimmed_len].ends_with(',') {
+          result.remove(trimmed_len - 1);
+        }
+        result.push(c);
+      }
+      _ => result.push(c),
+    }
+  }
+
+  result
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn strip_tsconfig_jsonc() {
+    let content = r#"{
+      // comment
+      "compilerOptions": {
+        /* block comment */
+        "paths": { "@/*": ["./src/*"], },
+        "baseUrl": "./", // trailing comment
+      },
+    }"#;
+    let value: Value = from_str(&strip_jsonc(content)).unwrap();
+
+    assert_eq!(value["compilerOptions"]["baseUrl"], "./");
+    assert_eq!(value["compilerOptions"]["paths"]["@/*"][0], "./src/*");
+  }
+
+  #[test]
+  fn match_paths() {
+    let tsconfig_paths = TsconfigPaths {
+      base_url: PathBuf::from("/root"),
+      paths: vec![
+        ("@/*".to_string(), vec!["./src/*".to_string()]),
+        (
+          "@/components/*".to_string(),
+          vec!["components/*".to_string()],
+        ),
+        ("utils".to_string(), vec!["shared/utils".to_string()]),
+      ],
+    };
+
+    assert_eq!(
+      tsconfig_paths.candidates("@/pages/a"),
+      vec![PathBuf::from("/root/src/pages/a")]
+    );
+    assert_eq!(
+      tsconfig_paths.candidates("@/components/button"),
+      vec![PathBuf::from("/root/components/button")]
+    );
+    assert_eq!(
+      tsconfig_paths.candidates("utils"),
+      vec![PathBuf::from("/root/shared/utils")]
+    );
+    assert!(tsconfig_paths.candidates("lodash").is_empty());
+  }
+}
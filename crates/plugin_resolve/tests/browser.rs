@@ -5,7 +5,7 @@ use farmfe_core::{
   context::CompilationContext,
   plugin::ResolveKind,
 };
-use farmfe_plugin_resolve::resolver::{ResolveOptions, Resolver};
+use farmfe_plugin_resolve::resolver::{ResolveOptions, Resolver, BROWSER_EMPTY_MODULE_PREFIX};
 use farmfe_testing_helpers::fixture;
 
 /// See browser field spec (https://github.com/defunctzombie/package-browser-field-spec)
@@ -162,8 +162,12 @@ fn resolve_browser_ignore() {
       assert!(resolved.is_some());
       let resolved = resolved.unwrap();
 
-      assert_eq!(resolved.resolved_path, "module-a".to_string());
-      assert!(resolved.external);
+      assert_eq!(
+        resolved.resolved_path,
+        format!("{BROWSER_EMPTY_MODULE_PREFIX}module-a")
+      );
+      assert!(!resolved.external);
+      assert!(!resolved.side_effects);
 
       let resolved = resolver.resolve(
         "./only.js",
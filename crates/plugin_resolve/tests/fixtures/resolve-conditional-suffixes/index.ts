@@ -0,0 +1 @@
+import impl from './impl';
@@ -1,7 +1,7 @@
 use std::{collections::HashMap, sync::Arc};
 
 use farmfe_core::{
-  config::{Config, ResolveConfig},
+  config::{Config, OutputConfig, ResolveConfig, TargetEnv},
   context::CompilationContext,
   plugin::ResolveKind,
 };
@@ -486,3 +486,145 @@ fn resolve_package_subpath() {
     }
   );
 }
+
+#[test]
+fn resolve_conditional_suffixes() {
+  fixture!(
+    "tests/fixtures/resolve-conditional-suffixes/index.ts",
+    |file, _| {
+      let cwd = file.parent().unwrap().to_path_buf();
+      let resolve = |source: &str, target_env: TargetEnv| {
+        let resolver = Resolver::new();
+        let context = CompilationContext::new(
+          Config {
+            resolve: Box::new(ResolveConfig {
+              conditional_suffixes: vec![".{target}".to_string()],
+              ..Default::default()
+            }),
+            output: Box::new(OutputConfig {
+              target_env,
+              ..Default::default()
+            }),
+            ..Default::default()
+          },
+          vec![],
+        )
+        .unwrap();
+
+        resolver
+          .resolve(
+            source,
+            cwd.clone(),
+            &ResolveKind::Import,
+            &ResolveOptions::default(),
+            &Arc::new(context),
+          )
+          .unwrap()
+          .resolved_path
+      };
+      let path = |name: &str| cwd.join(name).to_string_lossy().to_string();
+
+      assert_eq!(
+        resolve("./impl", TargetEnv::Browser),
+        path("impl.browser.ts")
+      );
+      assert_eq!(resolve("./impl", TargetEnv::Node), path("impl.node.ts"));
+      assert_eq!(resolve("./impl.ts", TargetEnv::Node), path("impl.node.ts"));
+      // fallback to the file without suffix
+      assert_eq!(resolve("./shared", TargetEnv::Node), path("shared.ts"));
+    }
+  );
+}
+
+#[test]
+fn resolve_main_files() {
+  fixture!("tests/fixtures/resolve-main-files/index.ts", |file, _| {
+    let cwd = file.parent().unwrap().to_path_buf();
+    let resolve = |source: &str, main_files: Vec<&str>| {
+      let resolver = Resolver::new();
+      let context = CompilationContext::new(
+        Config {
+          resolve: Box::new(ResolveConfig {
+            main_files: main_files.into_iter().map(String::from).collect(),
+            ..Default::default()
+          }),
+          ..Default::default()
+        },
+        vec![],
+      )
+      .unwrap();
+
+      resolver
+        .resolve(
+          source,
+          cwd.clone(),
+          &ResolveKind::Import,
+          &ResolveOptions::default(),
+          &Arc::new(context),
+        )
+        .map(|result| result.resolved_path)
+    };
+    let path = |name: &str| Some(cwd.join(name).to_string_lossy().to_string());
+
+    assert_eq!(resolve("./components/button", vec!["index"]), None);
+    assert_eq!(
+      resolve("./components/button", vec!["main", "index"]),
+      path("components/button/main.ts")
+    );
+    assert_eq!(
+      resolve("./components/card", vec!["main", "index"]),
+      path("components/card/index.ts")
+    );
+    // directory imports are disallowed
+    assert_eq!(resolve("./components/card", vec![]), None);
+    assert_eq!(
+      resolve("./components/card/index", vec![]),
+      path("components/card/index.ts")
+    );
+  });
+}
+
+#[test]
+fn resolve_tsconfig_paths() {
+  fixture!(
+    "tests/fixtures/resolve-tsconfig-paths/index.ts",
+    |file, _| {
+      let cwd = file.parent().unwrap().to_path_buf();
+      let resolve = |source: &str, tsconfig_paths: bool| {
+        let resolver = Resolver::new();
+        let context = CompilationContext::new(
+          Config {
+            root: cwd.to_string_lossy().to_string(),
+            resolve: Box::new(ResolveConfig {
+              tsconfig_paths,
+              ..Default::default()
+            }),
+            ..Default::default()
+          },
+          vec![],
+        )
+        .unwrap();
+
+        resolver
+          .resolve(
+            source,
+            cwd.clone(),
+            &ResolveKind::Import,
+            &ResolveOptions::default(),
+            &Arc::new(context),
+          )
+          .map(|result| result.resolved_path)
+      };
+      let path = |name: &str| Some(cwd.join(name).to_string_lossy().to_string());
+
+      assert_eq!(resolve("@/utils/format", true), path("src/utils/format.ts"));
+      // the longest prefix wins
+      assert_eq!(
+        resolve("@components/button", true),
+        path("src/components/button.tsx")
+      );
+      assert_eq!(resolve("format", true), path("src/utils/format.ts"));
+      assert_eq!(resolve("@/utils/format", false), None);
+    }
+  );
+}
@@ -3,27 +3,35 @@ use std::sync::Arc;
 
 use farmfe_core::enhanced_magic_string::collapse_sourcemap::collapse_sourcemap_chain;
 use farmfe_core::enhanced_magic_string::magic_string::MagicString;
-use farmfe_core::enhanced_magic_string::types::SourceMapOptions;
+use farmfe_core::enhanced_magic_string::types::{MappingsOptionHires, SourceMapOptions};
 use farmfe_core::plugin::ResolveKind;
 use farmfe_core::resource::ResourceOrigin;
 use farmfe_core::{
   config::{ModuleFormat, TargetEnv, FARM_MODULE_SYSTEM},
   context::CompilationContext,
+  error::Result,
   module::{
     module_graph::ModuleGraph, module_group::ModuleGroupGraph, Module, ModuleId, ModuleSystem,
   },
-  resource::{Resource, ResourceType},
-  swc_ecma_ast::{self, Decl, ModuleDecl, ModuleItem, Pat},
+  resource::{resource_pot::ResourcePotType, Resource, ResourceType},
+  swc_ecma_ast::{self, Decl, Ident, ModuleDecl, ModuleExportName, ModuleItem},
 };
-use farmfe_toolkit::common::{append_source_map_comment, generate_source_map_resource};
-use farmfe_toolkit::fs::transform_output_entry_filename;
+use farmfe_toolkit::common::{
+  append_source_map_comment, collapse_rendered_map_chain, js_resource_intro, js_resource_outro,
+  source_map_resource,
+};
+use farmfe_toolkit::fs::{resource_ext, transform_output_entry_filename};
 use farmfe_toolkit::get_dynamic_resources_map::{
-  get_dynamic_resources_code, get_dynamic_resources_map,
+  get_dynamic_resources_code, get_dynamic_resources_map, get_resource_dependencies,
 };
 use farmfe_toolkit::html::get_farm_global_this;
 use farmfe_toolkit::sourcemap::SourceMap;
+use farmfe_toolkit::standalone_runtime::StandaloneRuntime;
+use farmfe_toolkit::swc_ecma_utils::find_pat_ids;
 use farmfe_utils::transform_string_to_static_str;
 
+use crate::runtime_features::{render_runtime_features, RuntimeFeatures};
+
 const FARM_NODE_MODULE: &str = "__farmNodeModule";
 
 pub enum ExportInfoOfEntryModule {
@@ -35,6 +43,15 @@ pub enum ExportInfoOfEntryModule {
   CJS,
 }
 
+impl ExportInfoOfEntryModule {
+  fn export_name(&self) -> &str {
+    match self {
+      ExportInfoOfEntryModule::Default | ExportInfoOfEntryModule::CJS => "default",
+      ExportInfoOfEntryModule::Named { name, import_as } => import_as.as_ref().unwrap_or(name),
+    }
+  }
+}
+
 pub fn get_export_info_of_entry_module(
   entry_module_id: &ModuleId,
   module_graph: &ModuleGraph,
@@ -63,6 +80,7 @@ pub fn get_export_info_of_entry_module(
 
   let ast = &entry_module.meta.as_script().ast;
   let mut export_info = vec![];
+  let mut star_export_info = vec![];
 
   for item in ast.body.iter() {
     match item {
@@ -81,16 +99,12 @@ pub fn get_export_info_of_entry_module(
             });
           }
           Decl::Var(var) => {
-            for decl in var.decls.iter() {
-              match &decl.name {
-                Pat::Ident(ident) => {
-                  export_info.push(ExportInfoOfEntryModule::Named {
-                    name: ident.sym.to_string(),
-                    import_as: None,
-                  });
-                }
-                _ => {}
-              }
+            // `export const { a, b: [c] } = obj` exports a and c
+            for ident in find_pat_ids::<_, Ident>(&var.decls) {
+              export_info.push(ExportInfoOfEntryModule::Named {
+                name: ident.sym.to_string(),
+                import_as: None,
+              });
             }
           }
           Decl::Using(_)
@@ -103,38 +117,22 @@ pub fn get_export_info_of_entry_module(
         },
         ModuleDecl::ExportNamed(named_export) => {
           for spec in named_export.specifiers.iter() {
-            match spec {
+            let name = match spec {
               swc_ecma_ast::ExportSpecifier::Named(named_spec) => {
-                export_info.push(ExportInfoOfEntryModule::Named {
-                  name: named_spec
-                    .exported
-                    .as_ref()
-                    .map(|exported| match exported {
-                      swc_ecma_ast::ModuleExportName::Ident(ident) => ident.sym.to_string(),
-                      swc_ecma_ast::ModuleExportName::Str(str) => str.value.to_string(),
-                    })
-                    .unwrap_or(match &named_spec.orig {
-                      swc_ecma_ast::ModuleExportName::Ident(ident) => ident.sym.to_string(),
-                      swc_ecma_ast::ModuleExportName::Str(str) => str.value.to_string(),
-                    }),
-                  import_as: None,
-                });
-              }
-              swc_ecma_ast::ExportSpecifier::Default(default) => {
-                export_info.push(ExportInfoOfEntryModule::Named {
-                  name: default.exported.sym.to_string(),
-                  import_as: None,
-                });
-              }
-              swc_ecma_ast::ExportSpecifier::Namespace(ns) => {
-                export_info.push(ExportInfoOfEntryModule::Named {
-                  name: match &ns.name {
-                    swc_ecma_ast::ModuleExportName::Ident(ident) => ident.sym.to_string(),
-                    swc_ecma_ast::ModuleExportName::Str(str) => str.value.to_string(),
-                  },
-                  import_as: None,
-                });
+                module_export_name(named_spec.exported.as_ref().unwrap_or(&named_spec.orig))
               }
+              swc_ecma_ast::ExportSpecifier::Default(default) => default.exported.sym.to_string(),
+              swc_ecma_ast::ExportSpecifier::Namespace(ns) => module_export_name(&ns.name),
+            };
+
+            // `export { a as default }` or `export { default } from './a'`
+            if name == "default" {
+              export_info.push(ExportInfoOfEntryModule::Default);
+            } else {
+              export_info.push(ExportInfoOfEntryModule::Named {
+                name,
+                import_as: None,
+              });
             }
           }
         }
@@ -142,13 +140,19 @@ pub fn get_export_info_of_entry_module(
           let source = export_all.src.value.to_string();
           let dep_module =
             module_graph.get_dep_by_source(entry_module_id, &source, Some(ResolveKind::ExportFrom));
+          // `export *` does not re-export default, and the exports of a commonjs module can not be known statically
           let mut dep_export_info =
             get_export_info_of_entry_module(&dep_module, module_graph, visited)
               .into_iter()
-              .filter(|e| !matches!(e, ExportInfoOfEntryModule::Default))
+              .filter(|e| {
+                !matches!(
+                  e,
+                  ExportInfoOfEntryModule::Default | ExportInfoOfEntryModule::CJS
+                )
+              })
               .collect();
 
-          export_info.append(&mut dep_export_info);
+          star_export_info.append(&mut dep_export_info);
         }
         ModuleDecl::ExportDefaultDecl(_) | ModuleDecl::ExportDefaultExpr(_) => {
           export_info.push(ExportInfoOfEntryModule::Default);
@@ -159,13 +163,30 @@ pub fn get_export_info_of_entry_module(
     }
   }
 
+  // local exports take precedence over the exports of `export *`, and a name is only exported once
+  let mut exported_names = HashSet::new();
   export_info
+    .into_iter()
+    .chain(star_export_info)
+    .filter(|export| exported_names.insert(export.export_name().to_string()))
+    .collect()
+}
+
+fn module_export_name(name: &ModuleExportName) -> String {
+  match name {
+    ModuleExportName::Ident(ident) => ident.sym.to_string(),
+    ModuleExportName::Str(str) => str.value.to_string(),
+  }
 }
 
+/// Generate the code that exports the bindings of the entry module.
+/// When `is_async_entry` is true, `entry` is a promise that resolves to the exports of the entry module,
+/// the default export is exported as a promise and the named exports are assigned once the promise is resolved.
 fn get_export_info_code(
   entry_module_id: &ModuleId,
   module_graph: &ModuleGraph,
-  context: &Arc<CompilationContext>,
+  is_async_entry: bool,
+  format: ModuleFormat,
 ) -> String {
   let mut visited = HashSet::new();
   let export_info = get_export_info_of_entry_module(entry_module_id, module_graph, &mut visited);
@@ -174,29 +195,48 @@ fn get_export_info_code(
     export_info
       .iter()
       .map(|export| match export {
-        ExportInfoOfEntryModule::Default => match context.config.output.format {
-          ModuleFormat::CommonJs => "module.exports = entry.default || entry;".to_string(),
-          ModuleFormat::EsModule => "export default entry.default || entry;".to_string(),
-        },
+        ExportInfoOfEntryModule::Default => {
+          let default_value = if is_async_entry {
+            "entry.then(function(entry){return entry.default || entry;})"
+          } else {
+            "entry.default || entry"
+          };
+
+          if format.is_esm() {
+            format!("export default {default_value};")
+          } else {
+            format!("module.exports = {default_value};")
+          }
+        }
         ExportInfoOfEntryModule::Named { name, import_as } => {
-          if let Some(import_as) = import_as {
-            match context.config.output.format {
-              ModuleFormat::CommonJs => format!("module.exports.{import_as} = entry.{name};"),
-              ModuleFormat::EsModule => format!(
-                "var {name}=entry.{name};export {{ {name} as {import_as} }};"
-              ),
+          let export_name = import_as.as_ref().unwrap_or(name);
+
+          match (format.is_esm(), is_async_entry) {
+            (false, false) => {
+              format!("module.exports.{export_name} = entry.{name};")
             }
-          } else {
-            match context.config.output.format {
-              ModuleFormat::CommonJs => format!("module.exports.{name} = entry.{name};"),
-              ModuleFormat::EsModule => format!("var {name}=entry.{name};export {{ {name} }};"),
+            (false, true) => format!(
+              "entry.then(function(entry){{module.exports.{export_name} = entry.{name};}});"
+            ),
+            (true, false) => {
+              format!(
+                "var {name}=entry.{name};{}",
+                esm_export_code(name, import_as)
+              )
             }
+            (true, true) => format!(
+              "var {name};entry.then(function(entry){{{name}=entry.{name};}});{}",
+              esm_export_code(name, import_as)
+            ),
+          }
+        }
+        ExportInfoOfEntryModule::CJS => {
+          if format.is_esm() {
+            "export default entry;".to_string()
+          } else {
+            "module.exports = entry;".to_string()
           }
         }
-        ExportInfoOfEntryModule::CJS => match context.config.output.format {
-          ModuleFormat::CommonJs => "module.exports = entry;".to_string(),
-          ModuleFormat::EsModule => "export default entry;".to_string(),
-        },
       })
       .collect::<Vec<String>>()
       .join("")
@@ -205,6 +245,14 @@ fn get_export_info_code(
   }
 }
 
+fn esm_export_code(name: &str, import_as: &Option<String>) -> String {
+  if let Some(import_as) = import_as {
+    format!("export {{ {name} as {import_as} }};")
+  } else {
+    format!("export {{ {name} }};")
+  }
+}
+
 #[derive(Debug, Default)]
 struct EntryResourceAndDepResources {
   pub entry_js_resource_name: String,
@@ -214,6 +262,7 @@ struct EntryResourceAndDepResources {
   pub dep_resources: Vec<String>,
   pub dynamic_resources: String,
   pub dynamic_module_resources_map: String,
+  pub has_dynamic_resources: bool,
 }
 
 fn get_entry_resource_and_dep_resources_name(
@@ -256,7 +305,11 @@ fn get_entry_resource_and_dep_resources_name(
       result.entry_js_resource_code = resource_pot.meta.rendered_content.clone();
 
       if !resource_pot.meta.rendered_map_chain.is_empty() {
-        result.entry_js_resource_source_map = Some(generate_source_map_resource(resource_pot));
+        // the `transform_sourcemap` hook is called after the entry code is handled, see [handle_entry_resources]
+        result.entry_js_resource_source_map = Some(source_map_resource(
+          resource_pot,
+          &collapse_rendered_map_chain(&resource_pot.meta.rendered_map_chain),
+        ));
       }
     } else {
       for resource_id in resource_pot.resources() {
@@ -278,11 +331,17 @@ fn get_entry_resource_and_dep_resources_name(
     resource_map,
     module_graph,
   );
-  let (dynamic_resources, dynamic_module_resources_map) =
-    get_dynamic_resources_code(&dynamic_resources_map, context.config.mode.clone());
+  let resource_dependencies =
+    get_resource_dependencies(&resource_pot_map, resource_map, module_graph);
+  let (dynamic_resources, dynamic_module_resources_map) = get_dynamic_resources_code(
+    &dynamic_resources_map,
+    &resource_dependencies,
+    context.config.mode.clone(),
+  );
 
   result.dynamic_resources = dynamic_resources;
   result.dynamic_module_resources_map = dynamic_module_resources_map;
+  result.has_dynamic_resources = !dynamic_resources_map.is_empty();
   result
 }
 
@@ -290,17 +349,49 @@ pub fn handle_entry_resources(
   resources_map: &mut HashMap<String, Resource>,
   context: &Arc<CompilationContext>,
   async_modules: &HashSet<ModuleId>,
-) {
+) -> Result<()> {
   let module_graph = context.module_graph.read();
   let module_group_graph = context.module_group_graph.read();
   let is_library = context.config.output.target_env.is_library();
 
-  // create a runtime resource
-  let mut runtime_code = None;
-  let mut runtime_resource = None;
+  // create a runtime resource for each output format
+  let mut runtime_codes = HashMap::new();
+  let mut runtime_resources = HashMap::new();
   let mut should_inject_runtime = false;
+  // the runtime is shared across builds as a standalone file
+  let standalone_config = context
+    .config
+    .runtime
+    .standalone
+    .as_ref()
+    .filter(|_| !is_library);
+  let mut standalone_runtime = None;
+  // formats whose runtime is shared by more than one entry, the runtime is emitted once for them when `output.sharedRuntimeChunk` is enabled
+  let shared_runtime_formats = if context.config.output.shared_runtime_chunk {
+    let mut entries_count_of_formats = HashMap::new();
+
+    for (entry, entry_name) in &module_graph.entries {
+      if module_graph
+        .module(entry)
+        .is_some_and(|module| module.module_type.is_script())
+      {
+        *entries_count_of_formats
+          .entry(context.config.output.format.get(Some(entry_name)))
+          .or_insert(0) += 1;
+      }
+    }
+
+    entries_count_of_formats
+      .into_iter()
+      .filter(|(_, count)| *count > 1)
+      .map(|(format, _)| format)
+      .collect::<HashSet<_>>()
+  } else {
+    HashSet::new()
+  };
 
-  for (entry, _) in &module_graph.entries {
+  for (entry, entry_name) in &module_graph.entries {
+    let format = context.config.output.format.get(Some(entry_name));
     let module = module_graph
       .module(entry)
       .expect("module is not found in module graph");
@@ -315,6 +406,7 @@ pub fn handle_entry_resources(
         mut dep_resources,
         dynamic_resources,
         dynamic_module_resources_map,
+        has_dynamic_resources,
       } = get_entry_resource_and_dep_resources_name(
         entry,
         module,
@@ -332,17 +424,18 @@ pub fn handle_entry_resources(
       // 1. import 'dep' or require('dep') to entry resource if target env is node
       let dep_resources_require_code = dep_resources
         .iter()
-        .map(|rn| match context.config.output.format {
-          ModuleFormat::EsModule => format!("import \"./{rn}\";"),
-          ModuleFormat::CommonJs => format!("require(\"./{rn}\");"),
+        .map(|rn| {
+          if format.is_esm() {
+            format!("import \"./{rn}\";")
+          } else {
+            format!("require(\"./{rn}\");")
+          }
         })
         .collect::<Vec<_>>()
         .join("");
 
-      let farm_global_this = get_farm_global_this(
-        &context.config.runtime.namespace,
-        &context.config.output.target_env,
-      );
+      let farm_global_this =
+        get_farm_global_this(&context.config.runtime.namespace, &context.config.output);
 
       // 4. setInitialLoadedResources and setDynamicModuleResourcesMap
       let set_initial_loaded_resources_code = format!(
@@ -361,12 +454,14 @@ pub fn handle_entry_resources(
         "".to_string()
       };
 
-      let top_level_await_entry =
-        if context.config.script.native_top_level_await && async_modules.contains(entry) {
-          "await "
-        } else {
-          ""
-        };
+      let is_async_entry = async_modules.contains(entry);
+      // the entry is awaited natively if top level await is enabled, otherwise `entry` is a promise
+      let top_level_await_entry = if context.config.script.native_top_level_await && is_async_entry
+      {
+        "await "
+      } else {
+        ""
+      };
 
       // 5. append call entry
       let call_entry_code = format!(
@@ -378,13 +473,36 @@ pub fn handle_entry_resources(
       );
 
       // 6. append export code
-      let export_info_code = get_export_info_code(entry, &module_graph, context);
+      let export_info_code = get_export_info_code(
+        entry,
+        &module_graph,
+        is_async_entry && !context.config.script.native_top_level_await,
+        format,
+      );
 
-      let runtime_code = if let Some(runtime_code) = runtime_code.as_ref() {
-        runtime_code
+      let all_features = RuntimeFeatures::all();
+      let runtime_code = get_or_create_runtime_code(
+        &mut runtime_codes,
+        resources_map,
+        format,
+        &all_features,
+        context,
+      )?;
+      let inject_runtime_resource =
+        (should_inject_runtime || shared_runtime_formats.contains(&format)) && !is_library;
+      // the inlined runtime only contains the features used by the entry
+      let inline_runtime_code = if standalone_config.is_none() && !inject_runtime_resource {
+        let entry_features = RuntimeFeatures::of_entry(has_dynamic_resources, context);
+
+        get_or_create_runtime_code(
+          &mut runtime_codes,
+          resources_map,
+          format,
+          &entry_features,
+          context,
+        )?
       } else {
-        runtime_code = Some(create_runtime_code(resources_map, context));
-        runtime_code.as_ref().unwrap()
+        String::new()
       };
 
       let entry_js_resource = resources_map
@@ -395,25 +513,38 @@ pub fn handle_entry_resources(
 
       for pre in vec![
         dep_resources_require_code,
-        if should_inject_runtime && !is_library {
-          let runtime_resource = if let Some(runtime_resource) = runtime_resource.as_ref() {
-            runtime_resource
+        if let Some(standalone_config) = standalone_config {
+          let standalone_runtime = standalone_runtime
+            .get_or_insert_with(|| StandaloneRuntime::new(&runtime_code, &farm_global_this));
+          let url = standalone_runtime.import_url(standalone_config, &entry_js_resource_name);
+          let version_check_code = standalone_runtime.version_check_code(&farm_global_this);
+
+          if format.is_esm() {
+            format!("import \"{url}\";{version_check_code}")
           } else {
-            runtime_resource = Some(create_farm_runtime_resource(runtime_code, context));
-            runtime_resource.as_ref().unwrap()
-          };
+            format!("require(\"{url}\");{version_check_code}")
+          }
+        } else if inject_runtime_resource {
+          let runtime_resource = runtime_resources
+            .entry(format)
+            .or_insert_with(|| create_farm_runtime_resource(&runtime_code, format, context));
 
-          match context.config.output.format {
-            ModuleFormat::EsModule => format!("import \"./{}\";", runtime_resource.name),
-            ModuleFormat::CommonJs => format!("require(\"./{}\");", runtime_resource.name),
+          if format.is_esm() {
+            format!("import \"./{}\";", runtime_resource.name)
+          } else {
+            format!("require(\"./{}\");", runtime_resource.name)
           }
         } else {
-          runtime_code.clone()
+          inline_runtime_code
         },
       ] {
         entry_bundle.prepend(&pre);
       }
 
+      if let Some(intro) = js_resource_intro(&context.config) {
+        entry_bundle.prepend(&intro);
+      }
+
       if !is_library {
         for post in vec![
           set_initial_loaded_resources_code,
@@ -425,6 +556,10 @@ pub fn handle_entry_resources(
         }
       }
 
+      if let Some(outro) = js_resource_outro(&context.config) {
+        entry_bundle.append(&outro);
+      }
+
       let entry_bundle_code = entry_bundle.to_string();
       // update entry resource
       entry_js_resource.bytes = entry_bundle_code.into_bytes();
@@ -433,14 +568,28 @@ pub fn handle_entry_resources(
         let entry_bundle_resource_map = entry_bundle
           .generate_map(SourceMapOptions {
             include_content: Some(true),
+            // minified code is rendered in a single line, a mapping per line would lose all the original tokens when collapsing
+            hires: if context.config.minify.enabled() {
+              Some(MappingsOptionHires::Boundary)
+            } else {
+              None
+            },
             ..Default::default()
           })
           .unwrap();
         let original_source_map = SourceMap::from_slice(&source_map.bytes).unwrap();
-        let collapsed_source_map = collapse_sourcemap_chain(
+        let mut collapsed_source_map = collapse_sourcemap_chain(
           vec![original_source_map, entry_bundle_resource_map],
           Default::default(),
         );
+
+        if let ResourceOrigin::ResourcePot(resource_pot_id) = &source_map.origin {
+          context.plugin_driver.transform_sourcemap(
+            &mut collapsed_source_map,
+            resource_pot_id,
+            context,
+          )?;
+        }
         let mut src_map = vec![];
         collapsed_source_map
           .to_writer(&mut src_map)
@@ -449,41 +598,71 @@ pub fn handle_entry_resources(
         source_map.bytes = src_map;
         source_map.name = entry_js_resource_source_map_name.clone();
         append_source_map_comment(entry_js_resource, &source_map, &context.config.sourcemap);
-        // update sourcemap resource
-        resources_map.insert(entry_js_resource_source_map_name, source_map);
+        // update sourcemap resource, the inline source map is embedded in the entry resource
+        if !context.config.sourcemap.is_inline() {
+          resources_map.insert(entry_js_resource_source_map_name, source_map);
+        }
       }
     }
   }
 
-  if should_inject_runtime {
-    if let Some(runtime_resource) = runtime_resource {
-      resources_map.insert(runtime_resource.name.clone(), runtime_resource);
-    }
+  // runtime resources are only created for the entries that import the runtime instead of inlining it
+  for (_, runtime_resource) in runtime_resources {
+    resources_map.insert(runtime_resource.name.clone(), runtime_resource);
+  }
+
+  // the standalone runtime is emitted only when it's not referenced from another build
+  if let Some(standalone_runtime) =
+    standalone_runtime.filter(|_| standalone_config.is_some_and(|config| config.url.is_none()))
+  {
+    resources_map.insert(
+      standalone_runtime.name.clone(),
+      create_standalone_runtime_resource(standalone_runtime),
+    );
   }
+
+  Ok(())
+}
+
+/// The runtime code is created once for each format and set of features
+fn get_or_create_runtime_code(
+  runtime_codes: &mut HashMap<(ModuleFormat, RuntimeFeatures), String>,
+  resources_map: &HashMap<String, Resource>,
+  format: ModuleFormat,
+  features: &RuntimeFeatures,
+  context: &Arc<CompilationContext>,
+) -> Result<String> {
+  if let Some(runtime_code) = runtime_codes.get(&(format, *features)) {
+    return Ok(runtime_code.clone());
+  }
+
+  let runtime_code = create_runtime_code(resources_map, format, features, context)?;
+  runtime_codes.insert((format, *features), runtime_code.clone());
+
+  Ok(runtime_code)
 }
 
 fn create_runtime_code(
   resources_map: &HashMap<String, Resource>,
+  format: ModuleFormat,
+  features: &RuntimeFeatures,
   context: &Arc<CompilationContext>,
-) -> String {
+) -> Result<String> {
   let node_specific_code = if context.config.output.target_env.is_node() {
-    match context.config.output.format {
-      ModuleFormat::EsModule => {
-        format!(
-          r#"import {FARM_NODE_MODULE} from 'node:module';global.nodeRequire = {FARM_NODE_MODULE}.createRequire(import.meta.url);"#
-        )
-      }
-      ModuleFormat::CommonJs => r#"global.nodeRequire = require;"#.to_string(), // _ => panic!("node only support cjs and esm format"),
+    if format.is_esm() {
+      format!(
+        r#"import {FARM_NODE_MODULE} from 'node:module';global.nodeRequire = {FARM_NODE_MODULE}.createRequire(import.meta.url);"#
+      )
+    } else {
+      r#"global.nodeRequire = require;"#.to_string()
     }
   } else {
     "".to_string()
   };
 
   // 2. __farm_global_this by namespace
-  let farm_global_this = get_farm_global_this(
-    &context.config.runtime.namespace,
-    &context.config.output.target_env,
-  );
+  let farm_global_this =
+    get_farm_global_this(&context.config.runtime.namespace, &context.config.output);
   let farm_global_this_code = format!(
     r#"{farm_global_this} = {{__FARM_TARGET_ENV__: '{}'}};"#,
     match &context.config.output.target_env {
@@ -495,32 +674,67 @@ fn create_runtime_code(
     }
   );
 
-  // 3. find runtime resource
-  let runtime_resource_code = String::from_utf8(
-    resources_map
-      .values()
-      .find(|r| matches!(r.resource_type, ResourceType::Runtime))
-      .expect("runtime resource not found")
-      .bytes
-      .clone(),
-  )
-  .unwrap();
+  // 3. find runtime resource, all features are enabled in the runtime resource
+  let runtime_resource_code = if *features == RuntimeFeatures::all() {
+    String::from_utf8(
+      resources_map
+        .values()
+        .find(|r| matches!(r.resource_type, ResourceType::Runtime))
+        .expect("runtime resource not found")
+        .bytes
+        .clone(),
+    )
+    .unwrap()
+  } else {
+    let resource_pot_map = context.resource_pot_map.read();
+    let runtime_resource_pot = resource_pot_map
+      .resource_pots()
+      .into_iter()
+      .find(|rp| matches!(rp.resource_pot_type, ResourcePotType::Runtime))
+      .expect("runtime resource pot not found");
+
+    render_runtime_features(
+      &runtime_resource_pot.meta.rendered_content,
+      features,
+      context,
+    )?
+  };
 
-  format!("{node_specific_code}{farm_global_this_code}{runtime_resource_code}")
+  Ok(format!(
+    "{node_specific_code}{farm_global_this_code}{runtime_resource_code}"
+  ))
 }
 
-fn create_farm_runtime_resource(runtime_code: &str, context: &Arc<CompilationContext>) -> Resource {
-  let bytes = runtime_code.to_string().into_bytes();
+fn create_farm_runtime_resource(
+  runtime_code: &str,
+  format: ModuleFormat,
+  context: &Arc<CompilationContext>,
+) -> Resource {
+  let bytes = format!(
+    "{}{runtime_code}{}",
+    js_resource_intro(&context.config).unwrap_or_default(),
+    js_resource_outro(&context.config).unwrap_or_default()
+  )
+  .into_bytes();
+  // entries that don't use the common format have their own runtime
+  let runtime_name = if format == context.config.output.format.common() {
+    "__farm_runtime".to_string()
+  } else {
+    match format {
+      ModuleFormat::EsModule => "__farm_runtime_esm".to_string(),
+      ModuleFormat::CommonJs => "__farm_runtime_cjs".to_string(),
+      ModuleFormat::Umd => "__farm_runtime_umd".to_string(),
+      ModuleFormat::Amd => "__farm_runtime_amd".to_string(),
+      ModuleFormat::Iife => "__farm_runtime_iife".to_string(),
+      ModuleFormat::System => "__farm_runtime_system".to_string(),
+    }
+  };
   let name = transform_output_entry_filename(
     context.config.output.entry_filename.clone(),
-    "__farm_runtime",
-    "__farm_runtime",
+    &runtime_name,
+    &runtime_name,
     &bytes,
-    "js", // todo: support configuring extension
-          // match context.config.output.format {
-          //   ModuleFormat::EsModule => "mjs",
-          //   ModuleFormat::CommonJs => "cjs",
-          // },
+    &resource_ext(&ResourceType::Js, format, &context.config.output.target_env),
   );
   Resource {
     name: name.clone(),
@@ -532,3 +746,14 @@ fn create_farm_runtime_resource(runtime_code: &str, context: &Arc<CompilationCon
     info: None,
   }
 }
+
+fn create_standalone_runtime_resource(standalone_runtime: StandaloneRuntime) -> Resource {
+  Resource {
+    name: standalone_runtime.name.clone(),
+    bytes: standalone_runtime.code.into_bytes(),
+    emitted: false,
+    resource_type: ResourceType::Js,
+    origin: ResourceOrigin::ResourcePot(standalone_runtime.name),
+    info: None,
+  }
+}
@@ -35,10 +35,8 @@ pub fn insert_runtime_plugins(content: String, context: &Arc<CompilationContext>
     .map(|(_, import)| import.as_str())
     .collect::<Vec<_>>();
 
-  let farm_global_this = get_farm_global_this(
-    &context.config.runtime.namespace,
-    &context.config.output.target_env,
-  );
+  let farm_global_this =
+    get_farm_global_this(&context.config.runtime.namespace, &context.config.output);
   // FARM_GLOBAL_THIS.FARM_MODULE_SYSTEM.setPlugins([PLUGIN_VAR_PREFIX0, PLUGIN_VAR_PREFIX1, ...])
   let plugins_call = format!(
     "{}.{}.setPlugins([{}]);",
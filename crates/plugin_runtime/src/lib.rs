@@ -3,6 +3,7 @@
 use std::{
   any::Any,
   collections::{HashMap, HashSet, VecDeque},
+  path::Path,
   sync::Arc,
 };
 
@@ -13,28 +14,32 @@ use farmfe_core::{
     FARM_MODULE_SYSTEM,
   },
   context::CompilationContext,
-  enhanced_magic_string::types::{MappingsOptionHires, SourceMapOptions},
+  enhanced_magic_string::bundle::Bundle,
   error::CompilationError,
   module::{ModuleId, ModuleType},
   plugin::{
-    Plugin, PluginFinalizeResourcesHookParams, PluginGenerateResourcesHookResult,
-    PluginHookContext, PluginLoadHookParam, PluginLoadHookResult, PluginResolveHookParam,
-    PluginResolveHookResult, PluginTransformHookResult,
+    Plugin, PluginAnalyzeDepsHookParam, PluginAnalyzeDepsHookResultEntry,
+    PluginFinalizeResourcesHookParams, PluginGenerateResourcesHookResult, PluginHookContext,
+    PluginLoadHookParam, PluginLoadHookResult, PluginResolveHookParam, PluginResolveHookResult,
+    PluginTransformHookResult, ResolveKind,
   },
+  regex,
   resource::{
     resource_pot::{ResourcePot, ResourcePotMetaData, ResourcePotType},
     Resource, ResourceOrigin, ResourceType,
   },
   serde_json,
+  swc_ecma_ast::{ModuleDecl, ModuleItem},
 };
 use farmfe_toolkit::{
-  fs::read_file_utf8,
-  html::get_farm_global_this,
+  fs::{read_file_utf8, resource_ext, transform_output_filename, CONTENT_HASH, CONTENT_HASH_NEW},
+  html::{get_farm_global_this, get_global_object},
   script::{module_type_from_id, set_module_system_for_module_meta},
 };
 
 use insert_runtime_plugins::insert_runtime_plugins;
 use render_resource_pot::*;
+use runtime_features::{render_runtime_features, RuntimeFeatures};
 
 pub use farmfe_toolkit::script::constant::RUNTIME_SUFFIX;
 pub const ASYNC_MODULES: &str = "async_modules";
@@ -43,13 +48,16 @@ mod find_async_modules;
 mod handle_entry_resources;
 mod insert_runtime_plugins;
 pub mod render_resource_pot;
+pub mod runtime_features;
 
 const PLUGIN_NAME: &str = "FarmPluginRuntime";
+/// file name of the virtual runtime entry under the root when `runtime.source` is set without `runtime.path`
+pub const INLINE_RUNTIME_FILENAME: &str = "__farm_inline_runtime__.ts";
 /// FarmPluginRuntime is charge of:
 /// * resolving, parsing and generating a executable runtime code and inject the code into the entries.
 /// * merge module's ast and render the script module using farm runtime's specification, for example, wrap the module to something like `function(module, exports, require) { xxx }`, see [Farm Runtime RFC](https://github.com/farm-fe/rfcs/pull/1)
 ///
-/// The runtime supports html entry and script(js/jsx/ts/tsx) entry, when entry is html, the runtime will be injected as a inline <script /> tag in the <head /> tag;
+/// The runtime supports html entry and script(js/jsx/ts/tsx) entry, when entry is html, the runtime will be injected as a inline <script /> tag in the <head /> tag (or a `<script src>` when `runtime.htmlInject` is `external`);
 /// when entry is script, the runtime will be injected into the entry module's head, makes sure the runtime execute before all other code.
 ///
 /// All runtime module (including the runtime core and its plugins) will be suffixed as `.farm-runtime` to distinguish with normal script modules.
@@ -64,6 +72,14 @@ impl Plugin for FarmPluginRuntime {
     if config.output.target_env.is_library() {
       return Ok(None);
     }
+    // the inline runtime source is loaded as a virtual runtime entry under the root
+    if config.runtime.source.is_some() && config.runtime.path.is_empty() {
+      config.runtime.path = Path::new(&config.root)
+        .join(INLINE_RUNTIME_FILENAME)
+        .to_string_lossy()
+        .to_string();
+    }
+
     // runtime package entry file
     if !config.runtime.path.is_empty() {
       config.input.insert(
@@ -83,7 +99,10 @@ impl Plugin for FarmPluginRuntime {
       0,
       PartialBundlingEnforceResourceConfig {
         name: "FARM_RUNTIME".to_string(),
-        test: vec![ConfigRegex::new(&format!(".+{RUNTIME_SUFFIX}"))],
+        test: vec![ConfigRegex::new(&format!(
+          ".+{}",
+          regex::escape(RUNTIME_SUFFIX)
+        ))],
       },
     );
 
@@ -91,9 +110,13 @@ impl Plugin for FarmPluginRuntime {
       "'<@__farm_global_this__@>'".to_string(),
       serde_json::Value::String(format!(
         "{}",
-        get_farm_global_this(&config.runtime.namespace, &config.output.target_env)
+        get_farm_global_this(&config.runtime.namespace, &config.output)
       )),
     );
+    config.define.insert(
+      "'<@__farm_chunk_load_timeout__@>'".to_string(),
+      serde_json::Value::from(config.runtime.chunk_load_timeout_ms),
+    );
 
     Ok(Some(()))
   }
@@ -107,6 +130,14 @@ impl Plugin for FarmPluginRuntime {
     // avoid cyclic resolve
     if hook_context.contain_caller(PLUGIN_NAME) {
       Ok(None)
+    } else if context.config.runtime.source.is_some()
+      && param.source == format!("{}{}", context.config.runtime.path, RUNTIME_SUFFIX)
+    {
+      // the inline runtime entry does not exist on disk
+      Ok(Some(PluginResolveHookResult {
+        resolved_path: param.source.clone(),
+        ..Default::default()
+      }))
     } else if param.source.ends_with(RUNTIME_SUFFIX) // if the source is a runtime module or its importer is a runtime module, then resolve it to the runtime module
       || (param.importer.is_some()
         && param
@@ -143,12 +174,15 @@ impl Plugin for FarmPluginRuntime {
   fn load(
     &self,
     param: &PluginLoadHookParam,
-    _context: &Arc<CompilationContext>,
+    context: &Arc<CompilationContext>,
     _hook_context: &PluginHookContext,
   ) -> farmfe_core::error::Result<Option<PluginLoadHookResult>> {
     if param.resolved_path.ends_with(RUNTIME_SUFFIX) {
       let real_file_path = param.resolved_path.replace(RUNTIME_SUFFIX, "");
-      let content = read_file_utf8(&real_file_path)?;
+      let content = match &context.config.runtime.source {
+        Some(source) if real_file_path == context.config.runtime.path => source.clone(),
+        _ => read_file_utf8(&real_file_path)?,
+      };
 
       if let Some(module_type) = module_type_from_id(&real_file_path) {
         Ok(Some(PluginLoadHookResult {
@@ -157,7 +191,9 @@ impl Plugin for FarmPluginRuntime {
           source_map: None,
         }))
       } else {
-        panic!("unknown module type for {real_file_path}");
+        Err(CompilationError::GenericError(format!(
+          "Can not determine the module type of runtime file `{real_file_path}`, make sure `runtime.path` points to a file with a known extension"
+        )))
       }
     } else {
       Ok(None)
@@ -183,6 +219,45 @@ impl Plugin for FarmPluginRuntime {
     Ok(None)
   }
 
+  fn analyze_deps(
+    &self,
+    param: &mut PluginAnalyzeDepsHookParam,
+    context: &Arc<CompilationContext>,
+  ) -> farmfe_core::error::Result<Option<()>> {
+    let farm_runtime_module_id = format!("{}{}", context.config.runtime.path, RUNTIME_SUFFIX);
+    // make runtime plugins dependencies of the runtime entry, so they are always part of the module graph
+    if param.module.id.resolved_path(&context.config.root) == farm_runtime_module_id {
+      // the plugins imported by `insert_runtime_plugins` are dependencies of the runtime entry already
+      let imported_sources = param
+        .module
+        .meta
+        .as_script()
+        .ast
+        .body
+        .iter()
+        .filter_map(|item| match item {
+          ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => Some(import.src.value.to_string()),
+          _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+      for plugin_path in &context.config.runtime.plugins {
+        if imported_sources.contains(plugin_path) {
+          continue;
+        }
+
+        param.deps.push(PluginAnalyzeDepsHookResultEntry {
+          source: format!("{plugin_path}{RUNTIME_SUFFIX}"),
+          kind: ResolveKind::Import,
+        });
+      }
+
+      return Ok(Some(()));
+    }
+
+    Ok(None)
+  }
+
   fn finalize_module(
     &self,
     param: &mut farmfe_core::plugin::PluginFinalizeModuleHookParam,
@@ -264,6 +339,59 @@ impl Plugin for FarmPluginRuntime {
     Ok(Some(()))
   }
 
+  fn process_resource_pots(
+    &self,
+    resource_pots: &mut Vec<&mut ResourcePot>,
+    context: &Arc<CompilationContext>,
+  ) -> farmfe_core::error::Result<Option<()>> {
+    // all runtime modules (including runtime plugins and the swc helpers they import) must be rendered in the FARM_RUNTIME resource pot,
+    // otherwise the runtime would reference modules that are not registered when bootstrapping.
+    // move the runtime modules that are split into other resource pots back to the first runtime resource pot
+    let Some(runtime_index) = resource_pots
+      .iter()
+      .position(|resource_pot| matches!(resource_pot.resource_pot_type, ResourcePotType::Runtime))
+    else {
+      return Ok(None);
+    };
+
+    let mut split_runtime_modules = vec![];
+
+    for (index, resource_pot) in resource_pots.iter_mut().enumerate() {
+      if index == runtime_index {
+        continue;
+      }
+
+      let runtime_modules = resource_pot
+        .modules()
+        .into_iter()
+        .filter(|module_id| {
+          matches!(resource_pot.resource_pot_type, ResourcePotType::Runtime)
+            || module_id.relative_path().ends_with(RUNTIME_SUFFIX)
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+      for module_id in runtime_modules {
+        resource_pot.remove_module(&module_id);
+        split_runtime_modules.push(module_id);
+      }
+    }
+
+    let runtime_resource_pot = &mut resource_pots[runtime_index];
+    let mut module_graph = context.module_graph.write();
+
+    for module_id in split_runtime_modules {
+      let module = module_graph.module_mut(&module_id).unwrap();
+      module.resource_pot = Some(runtime_resource_pot.id.clone());
+      runtime_resource_pot
+        .module_groups
+        .extend(module.module_groups.iter().cloned());
+      runtime_resource_pot.add_module(module_id);
+    }
+
+    Ok(None)
+  }
+
   fn render_resource_pot_modules(
     &self,
     resource_pot: &ResourcePot,
@@ -273,142 +401,115 @@ impl Plugin for FarmPluginRuntime {
     if !context.config.output.target_env.is_library()
       && matches!(resource_pot.resource_pot_type, ResourcePotType::Js)
     {
-      let async_modules = self.get_async_modules(context);
-      let async_modules = async_modules.downcast_ref::<HashSet<ModuleId>>().unwrap();
-      let module_graph = context.module_graph.read();
       let external_config = ExternalConfig::from(&*context.config);
-      let RenderedJsResourcePot {
-        mut bundle,
-        rendered_modules,
-        external_modules,
-      } = resource_pot_to_runtime_object(resource_pot, &module_graph, async_modules, context)?;
-
-      let mut external_modules_str = None;
-
-      let farm_global_this = get_farm_global_this(
-        &context.config.runtime.namespace,
-        &context.config.output.target_env,
-      );
-
-      // inject global externals
-      if !external_modules.is_empty() && context.config.output.target_env == TargetEnv::Node {
-        let mut import_strings = vec![];
-        let mut source_to_names = vec![];
-
-        for external_module in external_modules {
-          // replace all invalid characters with `_`
-          let mut name = external_module
-            .chars()
-            .map(|c| if c.is_alphanumeric() { c } else { '_' })
-            .collect::<String>();
-          name = format!("__farm_external_module_{name}");
-
-          let import_str = if context.config.output.format == ModuleFormat::EsModule {
-            format!("import * as {name} from {external_module:?};")
-          } else {
-            format!("var {name} = require({external_module:?});")
-          };
-          import_strings.push(import_str);
-          source_to_names.push((name, external_module));
+      let format = get_resource_pot_format(resource_pot, &context.module_graph.read(), context);
+      let farm_global_this =
+        get_farm_global_this(&context.config.runtime.namespace, &context.config.output);
+
+      let wrap = |bundle: &mut Bundle, external_modules: &[String]| {
+        let mut external_modules_str = None;
+
+        // inject global externals
+        if !external_modules.is_empty() && context.config.output.target_env == TargetEnv::Node {
+          let mut import_strings = vec![];
+          let mut source_to_names = vec![];
+
+          for external_module in external_modules {
+            // replace all invalid characters with `_`
+            let mut name = external_module
+              .chars()
+              .map(|c| if c.is_alphanumeric() { c } else { '_' })
+              .collect::<String>();
+            name = format!("__farm_external_module_{name}");
+
+            let import_str = if format == ModuleFormat::EsModule {
+              format!("import * as {name} from {external_module:?};")
+            } else {
+              format!("var {name} = require({external_module:?});")
+            };
+            import_strings.push(import_str);
+            source_to_names.push((name, external_module));
+          }
+
+          let mut prepend_str = import_strings.join("");
+          prepend_str.push_str(&format!(
+            "{farm_global_this}.{FARM_MODULE_SYSTEM}.setExternalModules({{{}}});",
+            source_to_names
+              .into_iter()
+              .map(
+                |(name, source)| if format == ModuleFormat::EsModule {
+                  format!("{source:?}: {name} && {name}.default && !{name}.__esModule ? {{...{name},__esModule:true}} : {{...{name}}}")
+                } else {
+                  format!("{source:?}: {name}")
+                }
+              )
+              .collect::<Vec<_>>()
+              .join(",")
+          ));
+
+          external_modules_str = Some(prepend_str);
+        } else if !external_modules.is_empty()
+          && context.config.output.target_env == TargetEnv::Browser
+        {
+          let global_object = get_global_object(&context.config.output);
+          let mut external_objs = Vec::new();
+
+          for source in external_modules {
+            let replace_source = external_config
+              .find_match(source)
+              .map(|v| v.source(source))
+              // it's maybe from plugin
+              .unwrap_or(source.clone());
+
+            let source_obj = format!("{global_object}['{replace_source}']||{{}}");
+            external_objs.push(if format == ModuleFormat::EsModule {
+              format!("{source:?}: ({source_obj}).default && !({source_obj}).__esModule ? {{...({source_obj}),__esModule:true}} : ({{...{source_obj}}})")
+            } else {
+              format!("{source:?}: {source_obj}")
+            });
+          }
+
+          let prepend_str = format!(
+            "{farm_global_this}.{FARM_MODULE_SYSTEM}.setExternalModules({{{}}});",
+            external_objs.join(",")
+          );
+          external_modules_str = Some(prepend_str);
         }
 
-        let mut prepend_str = import_strings.join("");
-        prepend_str.push_str(&format!(
-          "{farm_global_this}.{FARM_MODULE_SYSTEM}.setExternalModules({{{}}});",
-          source_to_names
-            .into_iter()
-            .map(
-              |(name, source)| if context.config.output.format == ModuleFormat::EsModule {
-                format!("{source:?}: {name} && {name}.default && !{name}.__esModule ? {{...{name},__esModule:true}} : {{...{name}}}")
-              } else {
-                format!("{source:?}: {name}")
-              }
-            )
-            .collect::<Vec<_>>()
-            .join(",")
-        ));
-
-        external_modules_str = Some(prepend_str);
-      } else if !external_modules.is_empty()
-        && context.config.output.target_env == TargetEnv::Browser
-      {
-        let mut external_objs = Vec::new();
-
-        for source in external_modules {
-          let replace_source = external_config
-            .find_match(&source)
-            .map(|v| v.source(&source))
-            // it's maybe from plugin
-            .unwrap_or(source.clone());
-
-          let source_obj = format!("window['{replace_source}']||{{}}");
-          external_objs.push(if context.config.output.format == ModuleFormat::EsModule {
-            format!("{source:?}: ({source_obj}).default && !({source_obj}).__esModule ? {{...({source_obj}),__esModule:true}} : ({{...{source_obj}}})")
-          } else {
-            format!("{source:?}: {source_obj}")
-          });
-        }
+        let is_target_node_and_cjs =
+          context.config.output.target_env == TargetEnv::Node && format == ModuleFormat::CommonJs;
 
-        let prepend_str = format!(
-          "{farm_global_this}.{FARM_MODULE_SYSTEM}.setExternalModules({{{}}});",
-          external_objs.join(",")
+        let str = format!(
+          r#"(function(_){{for(var r in _){{_[r].__farm_resource_pot__={};{farm_global_this}.{FARM_MODULE_SYSTEM}.register(r,_[r])}}}})("#,
+          if is_target_node_and_cjs {
+            "'file://'+__filename".to_string()
+          } else {
+            // TODO make it final output file name
+            format!("'{}'", resource_pot.name.to_string() + ".js")
+          },
         );
-        external_modules_str = Some(prepend_str);
-      }
 
-      let is_target_node_and_cjs = context.config.output.target_env == TargetEnv::Node
-        && context.config.output.format == ModuleFormat::CommonJs;
+        bundle.prepend(&str);
+        bundle.append(");", None);
 
-      let str = format!(
-        r#"(function(_){{for(var r in _){{_[r].__farm_resource_pot__={};{farm_global_this}.{FARM_MODULE_SYSTEM}.register(r,_[r])}}}})("#,
-        if is_target_node_and_cjs {
-          "'file://'+__filename".to_string()
-        } else {
-          // TODO make it final output file name
-          format!("'{}'", resource_pot.name.to_string() + ".js")
-        },
-      );
+        if let Some(external_modules_str) = external_modules_str {
+          bundle.prepend(&external_modules_str);
+        }
 
-      bundle.prepend(&str);
-      bundle.append(");", None);
+        Ok(())
+      };
 
-      if let Some(external_modules_str) = external_modules_str {
-        bundle.prepend(&external_modules_str);
-      }
+      let RenderedResourcePot {
+        code,
+        map,
+        rendered_modules,
+        ..
+      } = render_wrapped_resource_pot_to_code(resource_pot, context, wrap)?;
 
       return Ok(Some(ResourcePotMetaData {
         rendered_modules,
-        rendered_content: Arc::new(bundle.to_string()),
-        rendered_map_chain: if context.config.sourcemap.enabled(resource_pot.immutable) {
-          let root = context.config.root.clone();
-          let map = bundle
-            .generate_map(SourceMapOptions {
-              include_content: Some(true),
-              remap_source: Some(Box::new(move |src| {
-                format!("/{}", farmfe_utils::relative(&root, src))
-              })),
-              hires: if context.config.minify.enabled() {
-                Some(MappingsOptionHires::Boundary)
-              } else {
-                None
-              },
-              ..Default::default()
-            })
-            .map_err(|_| CompilationError::GenerateSourceMapError {
-              id: resource_pot.id.to_string(),
-            })?;
-          let mut buf = vec![];
-          map
-            .to_writer(&mut buf)
-            .map_err(|e| CompilationError::RenderScriptModuleError {
-              id: resource_pot.id.to_string(),
-              source: Some(Box::new(e)),
-            })?;
-
-          vec![Arc::new(String::from_utf8(buf).unwrap())]
-        } else {
-          vec![]
-        },
+        rendered_content: Arc::new(code),
+        rendered_map_chain: map.map(|map| vec![Arc::new(map)]).unwrap_or_default(),
         ..Default::default()
       }));
     }
@@ -419,7 +520,7 @@ impl Plugin for FarmPluginRuntime {
   fn generate_resources(
     &self,
     resource_pot: &mut ResourcePot,
-    _context: &Arc<CompilationContext>,
+    context: &Arc<CompilationContext>,
     hook_context: &PluginHookContext,
   ) -> farmfe_core::error::Result<Option<PluginGenerateResourcesHookResult>> {
     if matches!(&hook_context.caller, Some(c) if c == self.name()) {
@@ -428,10 +529,35 @@ impl Plugin for FarmPluginRuntime {
 
     // only handle runtime resource pot
     if matches!(resource_pot.resource_pot_type, ResourcePotType::Runtime) {
+      // the runtime resource is shared by entries, the features of each entry are handled when injecting the runtime
+      let bytes = render_runtime_features(
+        &resource_pot.meta.rendered_content,
+        &RuntimeFeatures::all(),
+        context,
+      )?
+      .into_bytes();
+      let filename_config = &context.config.output.filename;
+      // fold the content hash into the name so the runtime can be cached immutably when its content is unchanged
+      let name =
+        if filename_config.contains(CONTENT_HASH) || filename_config.contains(CONTENT_HASH_NEW) {
+          transform_output_filename(
+            filename_config.clone(),
+            &resource_pot.id.to_string(),
+            &bytes,
+            &resource_ext(
+              &ResourceType::Runtime,
+              context.config.output.format.common(),
+              &context.config.output.target_env,
+            ),
+          )
+        } else {
+          resource_pot.id.to_string()
+        };
+
       Ok(Some(PluginGenerateResourcesHookResult {
         resource: Resource {
-          name: resource_pot.id.to_string(),
-          bytes: resource_pot.meta.rendered_content.as_bytes().to_vec(),
+          name,
+          bytes,
           emitted: true, // do not emit runtime resource by default. The runtime will be injected into the html or script entry.
           resource_type: ResourceType::Runtime,
           origin: ResourceOrigin::ResourcePot(resource_pot.id.clone()),
@@ -455,7 +581,7 @@ impl Plugin for FarmPluginRuntime {
 
     let async_modules = self.get_async_modules(context);
     let async_modules = async_modules.downcast_ref::<HashSet<ModuleId>>().unwrap();
-    handle_entry_resources::handle_entry_resources(param.resources_map, context, async_modules);
+    handle_entry_resources::handle_entry_resources(param.resources_map, context, async_modules)?;
 
     Ok(Some(()))
   }
@@ -6,12 +6,13 @@ use std::{
 use farmfe_core::{
   cache::cache_store::CacheStoreKey,
   cache_item,
-  config::minify::MinifyMode,
+  config::{minify::MinifyMode, Mode, ModuleFormat},
   context::CompilationContext,
   deserialize,
   enhanced_magic_string::{
     bundle::{Bundle, BundleOptions},
     magic_string::{MagicString, MagicStringOptions},
+    types::{MappingsOptionHires, SourceMapOptions},
   },
   error::{CompilationError, Result},
   module::{module_graph::ModuleGraph, ModuleId},
@@ -26,13 +27,34 @@ use farmfe_utils::hash::sha256;
 use render_module::RenderModuleOptions;
 
 use self::render_module::{render_module, RenderModuleResult};
+use crate::{find_async_modules::find_async_modules, ASYNC_MODULES};
 
 mod render_module;
 // mod farm_module_system;
+mod rename_module_params;
 mod source_replacer;
 mod transform_async_module;
 mod transform_module_decls;
 
+/// Get the output format of the resource pot. Entry resource pots use the format of their entry,
+/// other resource pots use the common format of all entries.
+pub fn get_resource_pot_format(
+  resource_pot: &ResourcePot,
+  module_graph: &ModuleGraph,
+  context: &Arc<CompilationContext>,
+) -> ModuleFormat {
+  let entry_name = resource_pot
+    .entry_module
+    .as_ref()
+    .and_then(|entry| module_graph.entries.get(entry));
+
+  context
+    .config
+    .output
+    .format
+    .get(entry_name.map(|name| name.as_str()))
+}
+
 /// Merge all modules' ast in a [ResourcePot] to Farm's runtime [ObjectLit]. The [ObjectLit] looks like:
 /// ```js
 /// {
@@ -60,6 +82,7 @@ pub fn resource_pot_to_runtime_object(
   context: &Arc<CompilationContext>,
 ) -> Result<RenderedJsResourcePot> {
   let modules = Mutex::new(vec![]);
+  let format = get_resource_pot_format(resource_pot, module_graph, context);
 
   let minify_builder =
     MinifyBuilder::create_builder(&context.config.minify, Some(MinifyMode::Module));
@@ -85,10 +108,11 @@ pub fn resource_pot_to_runtime_object(
           name: m_id.to_string() + "-resource_pot_to_runtime_object",
           key: sha256(
             format!(
-              "resource_pot_to_runtime_object_{}_{}_{}",
+              "resource_pot_to_runtime_object_{}_{}_{}_{:?}",
               content_hash,
               m_id.to_string(),
-              module.used_exports.join(",")
+              module.used_exports.join(","),
+              format
             )
             .as_bytes(),
             32,
@@ -126,6 +150,7 @@ pub fn resource_pot_to_runtime_object(
         is_enabled_minify,
         minify_builder: &minify_builder,
         is_async_module,
+        format,
         context,
       })?;
       let code = rendered_module.rendered_content.clone();
@@ -156,7 +181,7 @@ pub fn resource_pot_to_runtime_object(
         }),
       );
 
-      module.prepend(&format!("{:?}:", m_id.id(context.config.mode.clone())));
+      prepend_module_key(&mut module, m_id, context);
       module.append(",");
 
       modules.lock().push(RenderedScriptModule {
@@ -199,8 +224,13 @@ pub fn resource_pot_to_runtime_object(
   let mut external_modules = external_modules_set.into_iter().collect::<Vec<_>>();
   external_modules.sort();
 
-  bundle.prepend("{");
-  bundle.append("}", None);
+  if module_indent(context).is_some() {
+    bundle.prepend("{\n");
+    bundle.append("\n}", None);
+  } else {
+    bundle.prepend("{");
+    bundle.append("}", None);
+  }
 
   Ok(RenderedJsResourcePot {
     bundle,
@@ -209,6 +239,195 @@ pub fn resource_pot_to_runtime_object(
   })
 }
 
+/// The code of a [ResourcePot] rendered by [render_resource_pot_to_code].
+pub struct RenderedResourcePot {
+  /// the runtime object of the modules, e.g. `{"a.js": function(module, exports, farmRequire, farmDynamicRequire) {...},}`
+  pub code: String,
+  /// the source map of `code`, only generated when `sourcemap` is enabled for the resource pot
+  pub map: Option<String>,
+  /// the external modules that the modules of the resource pot import, sorted by name
+  pub external_modules: Vec<String>,
+  /// the rendered modules of the resource pot
+  pub rendered_modules: HashMap<ModuleId, RenderedModule>,
+}
+
+/// Render a [ResourcePot] to Farm's runtime object outside of the runtime plugin, e.g. to wrap it in a custom output.
+/// The async modules are detected from the module graph when the compilation has not detected them yet.
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use farmfe_core::{
+///   config::{persistent_cache::PersistentCacheConfig, Config},
+///   context::CompilationContext,
+///   module::{
+///     module_graph::{ModuleGraph, ModuleGraphEdgeDataItem},
+///     Module, ModuleMetaData, ModuleType, ScriptModuleMetaData,
+///   },
+///   parking_lot::RwLock,
+///   plugin::ResolveKind,
+///   resource::resource_pot::{ResourcePot, ResourcePotType},
+///   swc_ecma_ast::EsVersion,
+///   swc_ecma_parser::Syntax,
+/// };
+/// use farmfe_plugin_runtime::render_resource_pot::render_resource_pot_to_code;
+/// use farmfe_toolkit::script::parse_module;
+///
+/// let mut module_graph = ModuleGraph::new();
+///
+/// for (id, code) in [
+///   ("index.js", "import { b } from './b.js';\nconsole.log(b);"),
+///   ("b.js", "export const b = 1;"),
+/// ] {
+///   let ast = parse_module(id, code, Syntax::Es(Default::default()), EsVersion::EsNext)
+///     .unwrap()
+///     .ast;
+///   let mut module = Module::new(id.into());
+///   module.module_type = ModuleType::Js;
+///   module.content = Arc::new(code.to_string());
+///   module.meta = Box::new(ModuleMetaData::Script(ScriptModuleMetaData {
+///     ast,
+///     ..Default::default()
+///   }));
+///   module_graph.add_module(module);
+/// }
+///
+/// module_graph
+///   .add_edge_item(
+///     &"index.js".into(),
+///     &"b.js".into(),
+///     ModuleGraphEdgeDataItem {
+///       source: "./b.js".to_string(),
+///       kind: ResolveKind::Import,
+///       order: 0,
+///     },
+///   )
+///   .unwrap();
+///
+/// let config = Config {
+///   persistent_cache: Box::new(PersistentCacheConfig::Bool(false)),
+///   ..Default::default()
+/// };
+/// let mut context = CompilationContext::new(config, vec![]).unwrap();
+/// context.module_graph = Box::new(RwLock::new(module_graph));
+///
+/// let mut resource_pot = ResourcePot::new("index".to_string(), ResourcePotType::Js);
+/// resource_pot.add_module("index.js".into());
+/// resource_pot.add_module("b.js".into());
+///
+/// let rendered = render_resource_pot_to_code(&resource_pot, &Arc::new(context)).unwrap();
+///
+/// // every module is rendered as a property of the runtime object
+/// assert!(rendered.code.starts_with("{\"b.js\":function"));
+/// assert!(rendered.code.contains("\"index.js\":function"));
+/// // the import is replaced with the require of the module id
+/// assert!(rendered.code.contains("(\"b.js\").b"));
+/// assert!(rendered.external_modules.is_empty());
+/// ```
+pub fn render_resource_pot_to_code(
+  resource_pot: &ResourcePot,
+  context: &Arc<CompilationContext>,
+) -> Result<RenderedResourcePot> {
+  render_wrapped_resource_pot_to_code(resource_pot, context, |_, _| Ok(()))
+}
+
+/// Same as [render_resource_pot_to_code], but `wrap` can prepend or append code to the runtime object before the
+/// code and the source map are generated, e.g. the `register` call of the runtime. The external modules are passed to `wrap`.
+pub fn render_wrapped_resource_pot_to_code(
+  resource_pot: &ResourcePot,
+  context: &Arc<CompilationContext>,
+  wrap: impl FnOnce(&mut Bundle, &[String]) -> Result<()>,
+) -> Result<RenderedResourcePot> {
+  let detected_async_modules;
+  let async_modules_ref = context.custom.get(ASYNC_MODULES);
+  let async_modules = match &async_modules_ref {
+    Some(async_modules) => async_modules.downcast_ref::<HashSet<ModuleId>>().unwrap(),
+    None => {
+      detected_async_modules = find_async_modules(context);
+      &detected_async_modules
+    }
+  };
+
+  let RenderedJsResourcePot {
+    mut bundle,
+    rendered_modules,
+    external_modules,
+  } = resource_pot_to_runtime_object(
+    resource_pot,
+    &context.module_graph.read(),
+    async_modules,
+    context,
+  )?;
+
+  wrap(&mut bundle, &external_modules)?;
+
+  let map = if context.config.sourcemap.enabled(resource_pot.immutable) {
+    Some(generate_resource_pot_map(&bundle, resource_pot, context)?)
+  } else {
+    None
+  };
+
+  Ok(RenderedResourcePot {
+    code: bundle.to_string(),
+    map,
+    external_modules,
+    rendered_modules,
+  })
+}
+
+fn generate_resource_pot_map(
+  bundle: &Bundle,
+  resource_pot: &ResourcePot,
+  context: &Arc<CompilationContext>,
+) -> Result<String> {
+  let root = context.config.root.clone();
+  let map = bundle
+    .generate_map(SourceMapOptions {
+      include_content: Some(true),
+      remap_source: Some(Box::new(move |src| {
+        format!("/{}", farmfe_utils::relative(&root, src))
+      })),
+      // minified code is rendered in a single line, a mapping per line would lose all the original tokens when collapsing
+      hires: if context.config.minify.enabled() {
+        Some(MappingsOptionHires::Boundary)
+      } else {
+        None
+      },
+      ..Default::default()
+    })
+    .map_err(|_| CompilationError::GenerateSourceMapError {
+      id: resource_pot.id.to_string(),
+    })?;
+  let mut buf = vec![];
+  map
+    .to_writer(&mut buf)
+    .map_err(|e| CompilationError::RenderScriptModuleError {
+      id: resource_pot.id.to_string(),
+      source: Some(Box::new(e)),
+    })?;
+
+  Ok(String::from_utf8(buf).unwrap())
+}
+
+/// Indent of the rendered modules, only works in development mode to keep the output compact in production.
+pub(crate) fn module_indent(context: &Arc<CompilationContext>) -> Option<usize> {
+  if matches!(context.config.mode, Mode::Development) && !context.config.minify.enabled() {
+    context.config.output.indent.filter(|indent| *indent > 0)
+  } else {
+    None
+  }
+}
+
+fn prepend_module_key(module: &mut MagicString, id: &ModuleId, context: &Arc<CompilationContext>) {
+  let key = id.id(context.config.mode.clone());
+
+  if let Some(indent) = module_indent(context) {
+    module.prepend(&format!("{}{key:?}: ", " ".repeat(indent)));
+  } else {
+    module.prepend(&format!("{key:?}:"));
+  }
+}
+
 pub struct RenderedScriptModule {
   pub id: ModuleId,
   pub module: MagicString,
@@ -254,7 +473,7 @@ impl CacheRenderedScriptModule {
       ..Default::default()
     };
     let mut module = MagicString::new(&self.code, Some(magic_string_option));
-    module.prepend(&format!("{:?}:", self.id.id(context.config.mode.clone())));
+    prepend_module_key(&mut module, &self.id, context);
     module.append(",");
     module
   }
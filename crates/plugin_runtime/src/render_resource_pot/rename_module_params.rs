@@ -0,0 +1,101 @@
+use farmfe_core::{
+  config::{
+    RuntimeModuleParamsConfig, FARM_DYNAMIC_REQUIRE, FARM_MODULE, FARM_MODULE_EXPORT, FARM_REQUIRE,
+  },
+  swc_common::{Mark, SyntaxContext},
+  swc_ecma_ast::{
+    Expr, Ident, KeyValueProp, Module as SwcModule, Prop, PropName, SimpleAssignTarget,
+  },
+};
+use farmfe_toolkit::swc_ecma_visit::{VisitMut, VisitMutWith};
+
+/// Rename the references of the module function parameters to the names configured by `runtime.moduleParams`.
+/// For example, with `{ module: "m", exports: "e", require: "r" }`:
+/// ```js
+/// const b = farmRequire('./b');
+/// module.exports = { b };
+/// // =>
+/// const b = r('./b');
+/// m.exports = { b };
+/// ```
+pub fn rename_module_params(
+  module: &mut SwcModule,
+  unresolved_mark: Mark,
+  module_params: &RuntimeModuleParamsConfig,
+) {
+  module.visit_mut_with(&mut ModuleParamsRenamer {
+    unresolved_mark,
+    module_params,
+  });
+}
+
+struct ModuleParamsRenamer<'a> {
+  unresolved_mark: Mark,
+  module_params: &'a RuntimeModuleParamsConfig,
+}
+
+impl<'a> ModuleParamsRenamer<'a> {
+  /// the configured name if the ident references a module function parameter
+  fn renamed(&self, ident: &Ident) -> Option<&'a str> {
+    let ctxt = ident.span.ctxt;
+    // `module` and `exports` are unresolved references, `farmRequire` and `farmDynamicRequire` are injected without mark
+    let is_unresolved = ctxt.outer() == self.unresolved_mark;
+    let is_injected = ctxt == SyntaxContext::empty() || is_unresolved;
+
+    match &*ident.sym {
+      FARM_MODULE if is_unresolved => Some(&self.module_params.module),
+      FARM_MODULE_EXPORT if is_unresolved => Some(&self.module_params.exports),
+      FARM_REQUIRE if is_injected => Some(&self.module_params.require),
+      FARM_DYNAMIC_REQUIRE if is_injected => Some(&self.module_params.dynamic_require),
+      _ => None,
+    }
+  }
+
+  /// rename the ident and mark it as unresolved, so hygiene renames the local variables that conflict with it
+  fn rename(&self, ident: &mut Ident, name: &str) {
+    ident.sym = name.into();
+    ident.span.ctxt = SyntaxContext::empty().apply_mark(self.unresolved_mark);
+  }
+}
+
+impl<'a> VisitMut for ModuleParamsRenamer<'a> {
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    if let Expr::Ident(ident) = expr {
+      if let Some(name) = self.renamed(ident) {
+        self.rename(ident, name);
+      }
+      return;
+    }
+
+    expr.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_simple_assign_target(&mut self, target: &mut SimpleAssignTarget) {
+    if let SimpleAssignTarget::Ident(binding_ident) = target {
+      if let Some(name) = self.renamed(&binding_ident.id) {
+        self.rename(&mut binding_ident.id, name);
+      }
+      return;
+    }
+
+    target.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_prop(&mut self, prop: &mut Prop) {
+    // `{ module }` => `{ module: m }`
+    if let Prop::Shorthand(ident) = prop {
+      if let Some(name) = self.renamed(ident) {
+        let mut value = ident.clone();
+        self.rename(&mut value, name);
+
+        *prop = Prop::KeyValue(KeyValueProp {
+          key: PropName::Ident(Ident::new(ident.sym.clone(), ident.span)),
+          value: Box::new(Expr::Ident(value)),
+        });
+      }
+      return;
+    }
+
+    prop.visit_mut_children_with(self);
+  }
+}
@@ -3,16 +3,20 @@ use std::{path::PathBuf, sync::Arc};
 use farmfe_core::{
   context::CompilationContext,
   error::CompilationError,
+  farm_profile_scope,
   module::{module_graph::ModuleGraph, Module, ModuleId, ModuleSystem},
   resource::resource_pot::RenderedModule,
   swc_common::{comments::SingleThreadedComments, util::take::Take, Mark},
-  swc_ecma_ast::{ArrowExpr, BlockStmtOrExpr, Expr, ExprStmt},
+  swc_ecma_ast::{
+    ArrowExpr, BlockStmtOrExpr, CallExpr, Callee, Decl, Expr, ExprOrSpread, ExprStmt, Ident, Lit,
+    NewExpr, Pat, ReturnStmt, Str, VarDecl, VarDeclKind, VarDeclarator,
+  },
 };
 use farmfe_toolkit::{
   common::{build_source_map, create_swc_source_map, MinifyBuilder, Source},
   minify::minify_js_module,
   script::{
-    codegen_module,
+    codegen_module, codegen_module_with_indent, directive_prologue_len,
     swc_try_with::{resolve_module_mark, try_with},
     CodeGenCommentsConfig,
   },
@@ -25,7 +29,7 @@ use farmfe_toolkit::{
 };
 
 use farmfe_core::{
-  config::{FARM_DYNAMIC_REQUIRE, FARM_MODULE, FARM_MODULE_EXPORT, FARM_REQUIRE},
+  config::{ModuleFormat, FARM_MODULE_ID},
 
   swc_common::DUMMY_SP,
   swc_ecma_ast::{
@@ -34,6 +38,8 @@ use farmfe_core::{
 };
 
 use super::{
+  module_indent,
+  rename_module_params::rename_module_params,
   source_replacer::{ExistingCommonJsRequireVisitor, SourceReplacer, SourceReplacerOptions},
   transform_async_module,
   transform_module_decls::{transform_module_decls, TransformModuleDeclsOptions},
@@ -51,6 +57,7 @@ pub struct RenderModuleOptions<'a, F: Fn(&ModuleId) -> bool> {
   pub is_enabled_minify: F,
   pub minify_builder: &'a MinifyBuilder,
   pub is_async_module: bool,
+  pub format: ModuleFormat,
   pub context: &'a Arc<CompilationContext>,
 }
 
@@ -63,6 +70,7 @@ pub fn render_module<'a, F: Fn(&ModuleId) -> bool>(
     is_enabled_minify,
     minify_builder,
     is_async_module,
+    format,
     context,
   } = options;
   let mut cloned_module = module.meta.as_script().ast.clone();
@@ -73,6 +81,15 @@ pub fn render_module<'a, F: Fn(&ModuleId) -> bool>(
   let mut external_modules = vec![];
   let comments: SingleThreadedComments = module.meta.as_script().comments.clone().into();
   let minify_enabled = is_enabled_minify(&module.id);
+  let lazy_parse_enabled = !is_async_module
+    && context
+      .config
+      .runtime
+      .lazy_parse
+      .iter()
+      .any(|regex| regex.is_match(&module.id.to_string()));
+
+  let mut render_error = None;
 
   try_with(cm.clone(), &context.meta.script.globals, || {
     let (unresolved_mark, top_level_mark) = if module.meta.as_script().unresolved_mark == 0
@@ -124,8 +141,24 @@ pub fn render_module<'a, F: Fn(&ModuleId) -> bool>(
       module_id: module.id.clone(),
       mode: context.config.mode.clone(),
       target_env: context.config.output.target_env.clone(),
+      format,
     });
     cloned_module.visit_mut_with(&mut source_replacer);
+
+    let module_params = &context.config.runtime.module_params;
+    // rename before hygiene, so that the local variables conflicting with the params are renamed
+    if !module_params.is_default() {
+      rename_module_params(&mut cloned_module, unresolved_mark, module_params);
+    }
+
+    if context.config.runtime.inject_module_id {
+      inject_module_id(
+        &mut cloned_module,
+        unresolved_mark,
+        module.id.id(context.config.mode.clone()),
+      );
+    }
+
     cloned_module.visit_mut_with(&mut hygiene_with_config(HygieneConfig {
       top_level_mark,
       ..Default::default()
@@ -137,11 +170,21 @@ pub fn render_module<'a, F: Fn(&ModuleId) -> bool>(
     ) && is_async_module
     {
       // transform async module to meet the requirements of farm runtime
-      transform_async_module::transform_async_module(&mut cloned_module);
+      transform_async_module::transform_async_module(&mut cloned_module, &module_params.require);
     }
     // swc code gen would emit a trailing `;` when is_target_legacy is false.
     // we can not deal with this situation for now, so we set is_target_legacy to true here, it will be fixed in the future.
-    wrap_function(&mut cloned_module, unresolved_mark, is_async_module, true);
+    if let Err(e) = wrap_function(
+      &mut cloned_module,
+      &module.id,
+      unresolved_mark,
+      module_params.names(),
+      is_async_module,
+      true,
+    ) {
+      render_error = Some(e);
+      return;
+    }
 
     if minify_enabled {
       minify_js_module(
@@ -159,33 +202,52 @@ pub fn render_module<'a, F: Fn(&ModuleId) -> bool>(
     external_modules = source_replacer.external_modules;
   })?;
 
+  if let Some(e) = render_error {
+    return Err(e);
+  }
+
   // remove shebang
   cloned_module.shebang = None;
 
+  if lazy_parse_enabled {
+    wrap_lazy_parse(
+      &mut cloned_module,
+      &module.id,
+      cm.clone(),
+      &comments,
+      context,
+    )?;
+  }
+
   let sourcemap_enabled = context.config.sourcemap.enabled(module.immutable);
   // wrap module function
   // let wrapped_module = wrap_module_ast(cloned_module);
   let mut mappings = vec![];
-  let code_bytes = codegen_module(
-    &cloned_module,
-    context.config.script.target.clone(),
-    cm.clone(),
-    if sourcemap_enabled {
-      Some(&mut mappings)
-    } else {
-      None
-    },
-    context.config.minify.enabled(),
-    Some(CodeGenCommentsConfig {
-      comments: &comments,
-      // preserve all comments when generate module code.
-      config: &context.config.comments,
-    }),
-  )
-  .map_err(|e| CompilationError::RenderScriptModuleError {
-    id: module.id.to_string(),
-    source: Some(Box::new(e)),
-  })?;
+  let code_bytes = {
+    farm_profile_scope!(format!("Codegen module {:?}", module.id));
+    codegen_module_with_indent(
+      &cloned_module,
+      context.config.script.target,
+      cm.clone(),
+      if sourcemap_enabled {
+        Some(&mut mappings)
+      } else {
+        None
+      },
+      context.config.minify.enabled(),
+      Some(CodeGenCommentsConfig {
+        comments: &comments,
+        // comments are filtered by `comments`, only license comments are preserved by default
+        config: &context.config.comments,
+      }),
+      module_indent(context),
+      context.config.output.semicolons,
+    )
+    .map_err(|e| CompilationError::RenderScriptModuleError {
+      id: module.id.to_string(),
+      source: Some(Box::new(e)),
+    })?
+  };
 
   let code = Arc::new(String::from_utf8(code_bytes).unwrap());
 
@@ -221,6 +283,28 @@ pub fn render_module<'a, F: Fn(&ModuleId) -> bool>(
   })
 }
 
+/// Declare `var __farm_module_id__ = "<module id>";` at the top of the module, after the directive prologue like `"use strict"`.
+/// The ident is marked as unresolved, so that the references of `__farm_module_id__` in the module refer to it.
+fn inject_module_id(module: &mut SwcModule, unresolved_mark: Mark, module_id: String) {
+  module.body.insert(
+    directive_prologue_len(module),
+    ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+      span: DUMMY_SP,
+      kind: VarDeclKind::Var,
+      declare: false,
+      decls: vec![VarDeclarator {
+        span: DUMMY_SP,
+        name: Pat::Ident(BindingIdent {
+          id: Ident::new(FARM_MODULE_ID.into(), DUMMY_SP.apply_mark(unresolved_mark)),
+          type_ann: None,
+        }),
+        init: Some(Box::new(Expr::Lit(Lit::Str(module_id.into())))),
+        definite: false,
+      }],
+    })))),
+  );
+}
+
 /// Wrap the module ast to follow Farm's commonjs-style module system.
 /// Note: this function won't render the esm to commonjs, if you want to render esm to commonjs, see [common_js].
 ///
@@ -240,54 +324,36 @@ pub fn render_module<'a, F: Fn(&ModuleId) -> bool>(
 /// ```
 fn wrap_function(
   module: &mut SwcModule,
+  module_id: &ModuleId,
   unresolved_mark: Mark,
+  param_names: [&str; 4],
   is_async_module: bool,
   is_target_legacy: bool,
-) {
+) -> farmfe_core::error::Result<()> {
   let body = module.body.take();
 
-  let params = vec![
-    Param {
-      span: DUMMY_SP.apply_mark(unresolved_mark),
-      decorators: vec![],
-      pat: farmfe_core::swc_ecma_ast::Pat::Ident(BindingIdent {
-        id: FARM_MODULE.into(),
-        type_ann: None,
-      }),
-    },
-    Param {
-      span: DUMMY_SP.apply_mark(unresolved_mark),
-      decorators: vec![],
-      pat: farmfe_core::swc_ecma_ast::Pat::Ident(BindingIdent {
-        id: FARM_MODULE_EXPORT.into(),
-        type_ann: None,
-      }),
-    },
-    Param {
-      span: DUMMY_SP.apply_mark(unresolved_mark),
-      decorators: vec![],
-      pat: farmfe_core::swc_ecma_ast::Pat::Ident(BindingIdent {
-        id: FARM_REQUIRE.into(),
-        type_ann: None,
-      }),
-    },
-    Param {
+  let params = param_names
+    .into_iter()
+    .map(|name| Param {
       span: DUMMY_SP.apply_mark(unresolved_mark),
       decorators: vec![],
-      pat: farmfe_core::swc_ecma_ast::Pat::Ident(BindingIdent {
-        id: FARM_DYNAMIC_REQUIRE.into(),
+      pat: Pat::Ident(BindingIdent {
+        id: name.into(),
         type_ann: None,
       }),
-    },
-  ];
+    })
+    .collect::<Vec<_>>();
 
   let stmts = body
     .into_iter()
     .map(|body| match body {
-      ModuleItem::ModuleDecl(decl) => unreachable!("{:?}", decl),
-      ModuleItem::Stmt(stmt) => stmt,
+      ModuleItem::ModuleDecl(decl) => Err(CompilationError::GenericError(format!(
+        "Module `{}` still contains a module declaration after it is transformed to commonjs: {decl:?}",
+        module_id.to_string()
+      ))),
+      ModuleItem::Stmt(stmt) => Ok(stmt),
     })
-    .collect();
+    .collect::<farmfe_core::error::Result<Vec<_>>>()?;
 
   let item = if !is_target_legacy {
     ModuleItem::Stmt(Stmt::Expr(ExprStmt {
@@ -326,4 +392,110 @@ fn wrap_function(
   };
 
   module.body.push(item);
+
+  Ok(())
+}
+
+/// Replace the body of the wrapped module function with a `new Function` call, so that the module body is parsed on first require.
+/// The parameters are taken from the wrapped function, as they may be mangled by the minifier.
+/// For example:
+/// ```js
+/// function(module, exports, farmRequire, farmDynamicRequire) {
+///   return new Function("module", "exports", "farmRequire", "farmDynamicRequire", "\"use strict\";\nconst b = farmRequire('./b');\nconsole.log(b);")(module, exports, farmRequire, farmDynamicRequire);
+/// }
+/// ```
+fn wrap_lazy_parse(
+  module: &mut SwcModule,
+  module_id: &ModuleId,
+  cm: Arc<farmfe_core::swc_common::SourceMap>,
+  comments: &SingleThreadedComments,
+  context: &Arc<CompilationContext>,
+) -> farmfe_core::error::Result<()> {
+  let wrapped_function = match module.body.first_mut() {
+    Some(ModuleItem::Stmt(Stmt::Decl(Decl::Fn(fn_decl)))) => Some(&mut fn_decl.function),
+    _ => None,
+  };
+  let Some(Function {
+    params,
+    body: Some(body),
+    ..
+  }) = wrapped_function.map(|function| function.as_mut())
+  else {
+    return Err(CompilationError::GenericError(format!(
+      "Module `{}` should be wrapped as a function before wrapping lazy parse",
+      module_id.to_string()
+    )));
+  };
+  let params = params
+    .iter()
+    .filter_map(|param| match &param.pat {
+      Pat::Ident(ident) => Some(ident.id.clone()),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+
+  // the code of `new Function` is sloppy by default
+  let mut stmts = vec![ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+    span: DUMMY_SP,
+    expr: Box::new(Expr::Lit(Lit::Str("use strict".into()))),
+  }))];
+  stmts.extend(body.stmts.take().into_iter().map(ModuleItem::Stmt));
+
+  let body_module = SwcModule {
+    span: DUMMY_SP,
+    body: stmts,
+    shebang: None,
+  };
+  let code_bytes = codegen_module(
+    &body_module,
+    context.config.script.target,
+    cm,
+    None,
+    context.config.minify.enabled(),
+    Some(CodeGenCommentsConfig {
+      comments,
+      config: &context.config.comments,
+    }),
+  )
+  .map_err(|e| CompilationError::RenderScriptModuleError {
+    id: module_id.to_string(),
+    source: Some(Box::new(e)),
+  })?;
+
+  let str_arg = |value: String| ExprOrSpread {
+    spread: None,
+    expr: Box::new(Expr::Lit(Lit::Str(Str {
+      span: DUMMY_SP,
+      value: value.into(),
+      raw: None,
+    }))),
+  };
+  let mut function_args: Vec<ExprOrSpread> = params
+    .iter()
+    .map(|param| str_arg(param.sym.to_string()))
+    .collect();
+  function_args.push(str_arg(String::from_utf8_lossy(&code_bytes).to_string()));
+
+  body.stmts.push(Stmt::Return(ReturnStmt {
+    span: DUMMY_SP,
+    arg: Some(Box::new(Expr::Call(CallExpr {
+      span: DUMMY_SP,
+      callee: Callee::Expr(Box::new(Expr::New(NewExpr {
+        span: DUMMY_SP,
+        callee: Box::new(Expr::Ident("Function".into())),
+        args: Some(function_args),
+        type_args: None,
+      }))),
+      args: params
+        .into_iter()
+        .map(|param| ExprOrSpread {
+          spread: None,
+          expr: Box::new(Expr::Ident(param)),
+        })
+        .collect(),
+      type_args: None,
+    }))),
+  }));
+
+  Ok(())
 }
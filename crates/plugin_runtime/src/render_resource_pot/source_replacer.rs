@@ -8,7 +8,7 @@
 //! ```
 
 use farmfe_core::{
-  config::{Mode, TargetEnv, FARM_DYNAMIC_REQUIRE, FARM_REQUIRE},
+  config::{Mode, ModuleFormat, TargetEnv, FARM_DYNAMIC_REQUIRE, FARM_REQUIRE},
   module::{module_graph::ModuleGraph, ModuleId},
   plugin::ResolveKind,
   swc_common::{Mark, DUMMY_SP},
@@ -36,6 +36,7 @@ pub struct SourceReplacer<'a> {
   mode: Mode,
   pub external_modules: Vec<String>,
   target_env: TargetEnv,
+  format: ModuleFormat,
 }
 
 pub struct SourceReplacerOptions<'a> {
@@ -45,6 +46,7 @@ pub struct SourceReplacerOptions<'a> {
   pub module_id: ModuleId,
   pub mode: Mode,
   pub target_env: TargetEnv,
+  pub format: ModuleFormat,
 }
 
 impl<'a> SourceReplacer<'a> {
@@ -56,6 +58,7 @@ impl<'a> SourceReplacer<'a> {
       module_id,
       mode,
       target_env,
+      format,
     } = options;
 
     Self {
@@ -66,6 +69,7 @@ impl<'a> SourceReplacer<'a> {
       mode,
       external_modules: vec![],
       target_env,
+      format,
     }
   }
 }
@@ -155,6 +159,11 @@ impl SourceReplacer<'_> {
               obj: Box::new(Expr::Ident("global".into())),
               prop: MemberProp::Ident("nodeRequire".into()),
             })));
+            // the external id may differ from the source, e.g. native addons are copied to the output dir
+            if id.to_string() != source {
+              str.value = id.to_string().into();
+              str.raw = None;
+            }
             return SourceReplaceResult::NotReplaced;
           }
 
@@ -191,7 +200,11 @@ impl SourceReplacer<'_> {
         let dep_module = self.module_graph.module(&id).unwrap();
 
         if dep_module.external {
-          self.external_modules.push(id.to_string());
+          // for esm output, `import('external')` is preserved as a native dynamic import and resolved by the host,
+          // so the external should not be imported eagerly and registered to the module system
+          if !matches!(self.format, ModuleFormat::EsModule) {
+            self.external_modules.push(id.to_string());
+          }
 
           return SourceReplaceResult::NotReplaced;
         }
@@ -1,5 +1,4 @@
 use farmfe_core::{
-  config::FARM_REQUIRE,
   swc_common::DUMMY_SP,
   swc_ecma_ast::{
     ArrayLit, ArrayPat, AwaitExpr, BindingIdent, CallExpr, Callee, Decl, Expr, ExprOrSpread,
@@ -29,7 +28,8 @@ use farmfe_toolkit::swc_ecma_visit::{VisitMut, VisitMutWith};
 /// const _dep2 = _interop_require_default._(_dep2__f);
 /// console.log(_dep2.default)
 /// ```
-pub fn transform_async_module(ast: &mut Module) {
+/// `require_name` is the name of the require parameter of the module function, `farmRequire` by default.
+pub fn transform_async_module(ast: &mut Module, require_name: &str) {
   let mut await_all = vec![];
   let mut stmt_to_remove = vec![];
   let mut first_require_index = usize::MAX;
@@ -49,7 +49,7 @@ pub fn transform_async_module(ast: &mut Module) {
               let name = sym.to_string();
 
               if let Some(box expr) = &mut decl.init {
-                let mut visitor = FarmRequireVisitor::new(name.clone());
+                let mut visitor = FarmRequireVisitor::new(name.clone(), require_name);
                 expr.visit_mut_with(&mut visitor);
                 if visitor.requires.len() == 1 {
                   await_all.push((Some(name), visitor.requires.remove(0)));
@@ -61,7 +61,7 @@ pub fn transform_async_module(ast: &mut Module) {
         }
         // farmRequire("ea236e3d")
         Stmt::Expr(ExprStmt { box expr, .. }) => {
-          if let Some(id) = try_get_farm_require_id(expr) {
+          if let Some(id) = try_get_farm_require_id(expr, require_name) {
             await_all.push((None, id));
             stmt_to_remove.push(i);
             first_require_index = first_require_index.min(i);
@@ -111,7 +111,7 @@ pub fn transform_async_module(ast: &mut Module) {
           optional: false,
           type_ann: None,
         }),
-        init: Some(Box::new(create_promise_all(&await_all))),
+        init: Some(Box::new(create_promise_all(&await_all, require_name))),
         definite: false,
       }],
     })));
@@ -121,7 +121,7 @@ pub fn transform_async_module(ast: &mut Module) {
   }
 }
 
-fn create_promise_all(await_all: &Vec<(Option<String>, String)>) -> Expr {
+fn create_promise_all(await_all: &[(Option<String>, String)], require_name: &str) -> Expr {
   Expr::Await(AwaitExpr {
     span: DUMMY_SP,
     arg: Box::new(Expr::Call(CallExpr {
@@ -152,7 +152,7 @@ fn create_promise_all(await_all: &Vec<(Option<String>, String)>) -> Expr {
                 expr: Box::new(Expr::Call(CallExpr {
                   span: DUMMY_SP,
                   callee: Callee::Expr(Box::new(Expr::Ident(Ident::new(
-                    FARM_REQUIRE.into(),
+                    require_name.into(),
                     DUMMY_SP,
                   )))),
                   args: vec![ExprOrSpread {
@@ -176,24 +176,26 @@ fn create_promise_all(await_all: &Vec<(Option<String>, String)>) -> Expr {
 }
 
 /// collect id and transform farmRequire("475776c7") to name__f
-struct FarmRequireVisitor {
+struct FarmRequireVisitor<'a> {
   pub name: String,
+  pub require_name: &'a str,
   /// id list, example: vec!["475776c7"]
   pub requires: Vec<String>,
 }
 
-impl FarmRequireVisitor {
-  pub fn new(name: String) -> Self {
+impl<'a> FarmRequireVisitor<'a> {
+  pub fn new(name: String, require_name: &'a str) -> Self {
     Self {
       name,
+      require_name,
       requires: vec![],
     }
   }
 }
 
-impl VisitMut for FarmRequireVisitor {
+impl<'a> VisitMut for FarmRequireVisitor<'a> {
   fn visit_mut_expr(&mut self, expr: &mut farmfe_core::swc_ecma_ast::Expr) {
-    if let Some(id) = try_get_farm_require_id(expr) {
+    if let Some(id) = try_get_farm_require_id(expr, self.require_name) {
       self.requires.push(id);
       *expr = Expr::Ident(Ident::new(rename_ident(&self.name).into(), DUMMY_SP));
     } else {
@@ -206,10 +208,10 @@ fn rename_ident(name: &str) -> String {
   format!("{name}__f")
 }
 
-fn try_get_farm_require_id(expr: &Expr) -> Option<String> {
+fn try_get_farm_require_id(expr: &Expr, require_name: &str) -> Option<String> {
   if let Expr::Call(call_expr) = expr {
     if let Callee::Expr(box Expr::Ident(Ident { sym, .. })) = &call_expr.callee {
-      if sym.to_string() == FARM_REQUIRE.to_string() && call_expr.args.len() == 1 {
+      if sym == require_name && call_expr.args.len() == 1 {
         if let ExprOrSpread {
           expr: box Expr::Lit(Lit::Str(id)),
           ..
@@ -228,6 +230,7 @@ mod tests {
   use std::sync::Arc;
 
   use farmfe_core::{
+    config::FARM_REQUIRE,
     swc_common::{FilePathMapping, SourceMap},
     swc_ecma_ast::{EsVersion, Module},
     swc_ecma_parser::Syntax,
@@ -270,7 +273,7 @@ console.log(_dep2.default);
     "#;
 
     let mut ast = parse(input);
-    super::transform_async_module(&mut ast);
+    super::transform_async_module(&mut ast, FARM_REQUIRE);
     let code = codegen(&ast);
 
     let output = r#"
@@ -297,7 +300,7 @@ console.log(b);
     "#;
 
     let mut ast = parse(input);
-    super::transform_async_module(&mut ast);
+    super::transform_async_module(&mut ast, FARM_REQUIRE);
     let code = codegen(&ast);
 
     let output = r#"
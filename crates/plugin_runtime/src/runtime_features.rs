@@ -0,0 +1,300 @@
+//! Runtime features that can be omitted from the runtime of an entry when the entry does not use them.
+//!
+//! The runtime guards the code of a feature with a free flag identifier, for example, `if (__FARM_DYNAMIC_IMPORT__) { ... }`.
+//! The flags are replaced by boolean literals when the runtime is injected into an entry, then the dead branches and
+//! the declarations that are no longer referenced are removed.
+use std::{
+  collections::{HashMap, HashSet},
+  path::PathBuf,
+  sync::Arc,
+};
+
+use farmfe_core::{
+  config::Mode,
+  context::CompilationContext,
+  error::{CompilationError, Result},
+  swc_common::DUMMY_SP,
+  swc_ecma_ast::{
+    BinExpr, BinaryOp, ClassDecl, Decl, EmptyStmt, Expr, FnDecl, Ident, Lit, Module as SwcModule,
+    ModuleItem, Pat, Stmt, UnaryExpr, UnaryOp, VarDeclarator,
+  },
+  swc_ecma_parser::Syntax,
+};
+use farmfe_toolkit::{
+  common::{create_swc_source_map, Source},
+  script::{codegen_module, parse_module, CodeGenCommentsConfig, ParseScriptModuleResult},
+  swc_ecma_visit::{Visit, VisitMut, VisitMutWith, VisitWith},
+};
+
+/// Guards the code that loads resources of dynamic imported modules
+pub const FARM_DYNAMIC_IMPORT_FEATURE: &str = "__FARM_DYNAMIC_IMPORT__";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuntimeFeatures {
+  pub dynamic_import: bool,
+}
+
+impl RuntimeFeatures {
+  /// All features are enabled for the runtime that is shared by entries
+  pub fn all() -> Self {
+    Self {
+      dynamic_import: true,
+    }
+  }
+
+  /// Features used by the entry. The runtime loads the updated resources in development, so all features are kept.
+  pub fn of_entry(has_dynamic_resources: bool, context: &Arc<CompilationContext>) -> Self {
+    if matches!(context.config.mode, Mode::Development) || context.config.lazy_compilation {
+      return Self::all();
+    }
+
+    Self {
+      dynamic_import: has_dynamic_resources,
+    }
+  }
+
+  fn flags(&self) -> HashMap<&'static str, bool> {
+    HashMap::from([(FARM_DYNAMIC_IMPORT_FEATURE, self.dynamic_import)])
+  }
+}
+
+/// Replace the feature flags in the runtime code and remove the code of the disabled features.
+/// The code is returned as is if it does not contain any feature flag, and the flags are simply replaced by `true`
+/// when all features are enabled, so the shared runtime is not parsed and printed again.
+pub fn render_runtime_features(
+  runtime_code: &str,
+  features: &RuntimeFeatures,
+  context: &Arc<CompilationContext>,
+) -> Result<String> {
+  let flags = features.flags();
+
+  if !flags.keys().any(|flag| runtime_code.contains(flag)) {
+    return Ok(runtime_code.to_string());
+  }
+
+  if *features == RuntimeFeatures::all() {
+    return Ok(flags.keys().fold(runtime_code.to_string(), |code, flag| {
+      code.replace(flag, "true")
+    }));
+  }
+
+  let path = "__farm_runtime__.js";
+  let (cm, _) = create_swc_source_map(Source {
+    path: PathBuf::from(path),
+    content: Arc::new(runtime_code.to_string()),
+  });
+  let ParseScriptModuleResult { mut ast, comments } = parse_module(
+    path,
+    runtime_code,
+    Syntax::Es(Default::default()),
+    context.config.script.target,
+  )
+  .map_err(|e| {
+    CompilationError::GenericError(format!(
+      "failed to parse the rendered runtime when removing the unused runtime features: {e:?}"
+    ))
+  })?;
+
+  ast.visit_mut_with(&mut FeatureFlagsReplacer { flags: &flags });
+  remove_unused_decls(&mut ast);
+
+  let code_bytes = codegen_module(
+    &ast,
+    context.config.script.target,
+    cm,
+    None,
+    context.config.minify.enabled(),
+    Some(CodeGenCommentsConfig {
+      comments: &comments,
+      config: &context.config.comments,
+    }),
+  )
+  .map_err(|e| {
+    CompilationError::GenericError(format!(
+      "failed to generate the runtime without the unused runtime features: {e:?}"
+    ))
+  })?;
+
+  String::from_utf8(code_bytes).map_err(|e| CompilationError::GenericError(e.to_string()))
+}
+
+struct FeatureFlagsReplacer<'a> {
+  flags: &'a HashMap<&'static str, bool>,
+}
+
+impl<'a> VisitMut for FeatureFlagsReplacer<'a> {
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    expr.visit_mut_children_with(self);
+
+    match expr {
+      Expr::Ident(Ident { sym, .. }) => {
+        if let Some(value) = self.flags.get(sym.as_str()) {
+          *expr = bool_expr(*value);
+        }
+      }
+      Expr::Paren(paren) => {
+        if let Some(value) = as_bool(&paren.expr) {
+          *expr = bool_expr(value);
+        }
+      }
+      Expr::Unary(UnaryExpr {
+        op: UnaryOp::Bang,
+        arg,
+        ..
+      }) => {
+        if let Some(value) = as_bool(arg) {
+          *expr = bool_expr(!value);
+        }
+      }
+      Expr::Cond(cond) => {
+        if let Some(value) = as_bool(&cond.test) {
+          *expr = if value {
+            *cond.cons.clone()
+          } else {
+            *cond.alt.clone()
+          };
+        }
+      }
+      Expr::Bin(BinExpr {
+        op: op @ (BinaryOp::LogicalAnd | BinaryOp::LogicalOr),
+        left,
+        right,
+        ..
+      }) => {
+        if let Some(value) = as_bool(left) {
+          *expr = match (op, value) {
+            (BinaryOp::LogicalAnd, true) | (BinaryOp::LogicalOr, false) => *right.clone(),
+            _ => bool_expr(value),
+          };
+        }
+      }
+      _ => {}
+    }
+  }
+
+  fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
+    stmt.visit_mut_children_with(self);
+
+    if let Stmt::If(if_stmt) = stmt {
+      if let Some(value) = as_bool(&if_stmt.test) {
+        *stmt = if value {
+          *if_stmt.cons.clone()
+        } else if let Some(alt) = &if_stmt.alt {
+          *alt.clone()
+        } else {
+          Stmt::Empty(EmptyStmt { span: DUMMY_SP })
+        };
+      }
+    }
+  }
+}
+
+fn bool_expr(value: bool) -> Expr {
+  Expr::Lit(Lit::Bool(value.into()))
+}
+
+fn as_bool(expr: &Expr) -> Option<bool> {
+  match expr {
+    Expr::Lit(Lit::Bool(b)) => Some(b.value),
+    _ => None,
+  }
+}
+
+/// Remove the classes, functions and variables without side effects that are not referenced in the runtime.
+/// Repeat until nothing is removed as a removed declaration may be the last reference of another one.
+fn remove_unused_decls(ast: &mut SwcModule) {
+  loop {
+    let mut collector = ReferencedIdentsCollector::default();
+    ast.visit_with(&mut collector);
+
+    let mut remover = UnusedDeclsRemover {
+      referenced_idents: collector.idents,
+      removed: false,
+    };
+    ast.visit_mut_with(&mut remover);
+
+    if !remover.removed {
+      break;
+    }
+  }
+}
+
+struct UnusedDeclsRemover {
+  referenced_idents: HashSet<String>,
+  removed: bool,
+}
+
+impl UnusedDeclsRemover {
+  fn is_used(&self, stmt: &Stmt) -> bool {
+    match stmt {
+      Stmt::Decl(Decl::Class(ClassDecl { ident, .. })) | Stmt::Decl(Decl::Fn(FnDecl { ident, .. })) => {
+        self.referenced_idents.contains(ident.sym.as_str())
+      }
+      Stmt::Decl(Decl::Var(var_decl)) => var_decl.decls.iter().any(|decl| {
+        !is_pure_declarator(decl)
+          || matches!(&decl.name, Pat::Ident(ident) if self.referenced_idents.contains(ident.id.sym.as_str()))
+      }),
+      Stmt::Empty(_) => false,
+      _ => true,
+    }
+  }
+}
+
+impl VisitMut for UnusedDeclsRemover {
+  fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+    items.visit_mut_children_with(self);
+
+    let len = items.len();
+    items.retain(|item| match item {
+      ModuleItem::Stmt(stmt) => self.is_used(stmt),
+      ModuleItem::ModuleDecl(_) => true,
+    });
+    self.removed |= items.len() != len;
+  }
+
+  fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+    stmts.visit_mut_children_with(self);
+
+    let len = stmts.len();
+    stmts.retain(|stmt| self.is_used(stmt));
+    self.removed |= stmts.len() != len;
+  }
+}
+
+fn is_pure_declarator(decl: &VarDeclarator) -> bool {
+  matches!(decl.name, Pat::Ident(_))
+    && decl.init.as_ref().map_or(true, |init| {
+      matches!(
+        &**init,
+        Expr::Lit(_) | Expr::Fn(_) | Expr::Arrow(_) | Expr::Class(_)
+      )
+    })
+}
+
+/// Collect the referenced identifiers, the identifiers of the declarations themselves are not references
+#[derive(Default)]
+struct ReferencedIdentsCollector {
+  idents: HashSet<String>,
+}
+
+impl Visit for ReferencedIdentsCollector {
+  fn visit_ident(&mut self, ident: &Ident) {
+    self.idents.insert(ident.sym.to_string());
+  }
+
+  fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+    class_decl.class.visit_with(self);
+  }
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+    fn_decl.function.visit_with(self);
+  }
+
+  fn visit_var_declarator(&mut self, decl: &VarDeclarator) {
+    if !matches!(decl.name, Pat::Ident(_)) {
+      decl.name.visit_with(self);
+    }
+
+    decl.init.visit_with(self);
+  }
+}
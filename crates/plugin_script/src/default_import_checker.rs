@@ -0,0 +1,97 @@
+use farmfe_core::{
+  module::{module_graph::ModuleGraph, Module, ModuleSystem},
+  swc_ecma_ast::{
+    ExportSpecifier, ImportSpecifier, Module as SwcModule, ModuleDecl, ModuleExportName, ModuleItem,
+  },
+};
+
+/// Find the default imports that target an esm module without a default export.
+/// Per ESM spec, the imported binding would be `undefined`, so a warning is returned for each of them, for example:
+/// ```js
+/// // named-only.ts
+/// export const a = 1;
+/// // index.ts
+/// import a from './named-only'; // a is undefined
+/// ```
+/// Modules that are external, not script or need CommonJS interop (commonjs or hybrid) are skipped.
+pub fn check_missing_default_imports(module_graph: &ModuleGraph) -> Vec<String> {
+  let mut warnings = vec![];
+
+  for module in module_graph.modules() {
+    if !module.module_type.is_script() || module.external {
+      continue;
+    }
+
+    for item in &module.meta.as_script().ast.body {
+      let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item else {
+        continue;
+      };
+
+      if import_decl.type_only || !import_decl.specifiers.iter().any(is_default_import) {
+        continue;
+      }
+
+      let source = import_decl.src.value.to_string();
+      let Some(dep_id) = module_graph.get_dep_by_source_optional(&module.id, &source, None) else {
+        continue;
+      };
+      let dep = module_graph.module(&dep_id).unwrap();
+
+      if is_esm_without_default_export(dep) {
+        warnings.push(format!(
+          "Default import of `{source}` in {} targets {} which has no default export, the imported binding will be undefined",
+          module.id.to_string(),
+          dep.id.to_string()
+        ));
+      }
+    }
+  }
+
+  warnings
+}
+
+fn is_default_import(specifier: &ImportSpecifier) -> bool {
+  match specifier {
+    ImportSpecifier::Default(_) => true,
+    ImportSpecifier::Named(named) => {
+      matches!(&named.imported, Some(imported) if is_default_name(imported))
+    }
+    ImportSpecifier::Namespace(_) => false,
+  }
+}
+
+fn is_default_name(name: &ModuleExportName) -> bool {
+  match name {
+    ModuleExportName::Ident(ident) => ident.sym == *"default",
+    ModuleExportName::Str(str) => str.value == *"default",
+  }
+}
+
+fn is_esm_without_default_export(module: &Module) -> bool {
+  if !module.module_type.is_script()
+    || module.external
+    || module.meta.as_script().module_system != ModuleSystem::EsModule
+  {
+    return false;
+  }
+
+  !has_default_export(&module.meta.as_script().ast)
+}
+
+fn has_default_export(ast: &SwcModule) -> bool {
+  ast.body.iter().any(|item| match item {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(_))
+    | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(_)) => true,
+    ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export_named)) => export_named
+      .specifiers
+      .iter()
+      .any(|specifier| match specifier {
+        ExportSpecifier::Named(named) => {
+          is_default_name(named.exported.as_ref().unwrap_or(&named.orig))
+        }
+        ExportSpecifier::Namespace(namespace) => is_default_name(&namespace.name),
+        ExportSpecifier::Default(_) => true,
+      }),
+    _ => false,
+  })
+}
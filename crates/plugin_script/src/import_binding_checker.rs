@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+
+use farmfe_core::swc_ecma_ast::{
+  AssignExpr, AssignTarget, BindingIdent, ForHead, ForInStmt, ForOfStmt, Id, ImportSpecifier,
+  Module as SwcModule, ModuleDecl, ModuleItem, SimpleAssignTarget, UpdateExpr,
+};
+use farmfe_toolkit::swc_ecma_visit::{Visit, VisitWith};
+
+/// Find the imported bindings that are reassigned in the module.
+/// Per ESM spec, imported bindings are read-only, for example:
+/// ```js
+/// import { x } from './a';
+/// x = 1; // TypeError: Assignment to constant variable
+/// ```
+/// The module should be processed by swc `resolver` and typescript should be stripped before calling this function,
+/// so that shadowed bindings and type-only imports are not reported.
+pub fn find_reassigned_imports(ast: &SwcModule) -> Vec<String> {
+  let imported = collect_imported_bindings(ast);
+
+  if imported.is_empty() {
+    return vec![];
+  }
+
+  let mut checker = ReassignedImportsChecker {
+    imported,
+    reassigned: vec![],
+  };
+  ast.visit_with(&mut checker);
+
+  checker.reassigned
+}
+
+fn collect_imported_bindings(ast: &SwcModule) -> HashSet<Id> {
+  let mut imported = HashSet::new();
+
+  for item in &ast.body {
+    let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item else {
+      continue;
+    };
+
+    if import_decl.type_only {
+      continue;
+    }
+
+    for specifier in &import_decl.specifiers {
+      let local = match specifier {
+        ImportSpecifier::Named(named) => &named.local,
+        ImportSpecifier::Default(default) => &default.local,
+        ImportSpecifier::Namespace(namespace) => &namespace.local,
+      };
+      imported.insert(local.to_id());
+    }
+  }
+
+  imported
+}
+
+struct ReassignedImportsChecker {
+  imported: HashSet<Id>,
+  reassigned: Vec<String>,
+}
+
+impl ReassignedImportsChecker {
+  fn check_binding(&mut self, ident: &BindingIdent) {
+    let name = ident.id.sym.to_string();
+
+    if self.imported.contains(&ident.id.to_id()) && !self.reassigned.contains(&name) {
+      self.reassigned.push(name);
+    }
+  }
+
+  /// check all the bindings of a destructuring pattern, e.g. `[x, { y }] = value`
+  fn check_pattern<N: VisitWith<BindingIdentsCollector>>(&mut self, pat: &N) {
+    let mut collector = BindingIdentsCollector { idents: vec![] };
+    pat.visit_with(&mut collector);
+
+    for ident in collector.idents {
+      self.check_binding(&ident);
+    }
+  }
+}
+
+impl Visit for ReassignedImportsChecker {
+  fn visit_assign_expr(&mut self, n: &AssignExpr) {
+    match &n.left {
+      AssignTarget::Simple(SimpleAssignTarget::Ident(ident)) => self.check_binding(ident),
+      AssignTarget::Pat(pat) => self.check_pattern(pat),
+      _ => {}
+    }
+
+    n.visit_children_with(self);
+  }
+
+  fn visit_update_expr(&mut self, n: &UpdateExpr) {
+    if let Some(ident) = n.arg.as_ident() {
+      self.check_binding(&ident.clone().into());
+    }
+
+    n.visit_children_with(self);
+  }
+
+  fn visit_for_in_stmt(&mut self, n: &ForInStmt) {
+    if let ForHead::Pat(pat) = &n.left {
+      self.check_pattern(pat);
+    }
+
+    n.visit_children_with(self);
+  }
+
+  fn visit_for_of_stmt(&mut self, n: &ForOfStmt) {
+    if let ForHead::Pat(pat) = &n.left {
+      self.check_pattern(pat);
+    }
+
+    n.visit_children_with(self);
+  }
+}
+
+struct BindingIdentsCollector {
+  idents: Vec<BindingIdent>,
+}
+
+impl Visit for BindingIdentsCollector {
+  fn visit_binding_ident(&mut self, n: &BindingIdent) {
+    self.idents.push(n.clone());
+  }
+}
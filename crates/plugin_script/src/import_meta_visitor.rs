@@ -7,8 +7,8 @@ use farmfe_core::{
   plugin::{PluginResolveHookParam, ResolveKind},
   swc_common::DUMMY_SP,
   swc_ecma_ast::{
-    CallExpr, Callee, Expr, ExprOrSpread, Ident, Lit, MemberExpr, MemberProp, MetaPropKind,
-    NewExpr, Str,
+    CallExpr, Callee, EmptyStmt, Expr, ExprOrSpread, Ident, Lit, MemberExpr, MemberProp,
+    MetaPropKind, ModuleItem, NewExpr, Stmt, Str,
   },
 };
 use farmfe_toolkit::swc_ecma_visit::{VisitMut, VisitMutWith};
@@ -215,3 +215,76 @@ pub fn replace_import_meta_url(ast: &mut farmfe_core::swc_ecma_ast::Module) {
   let mut visitor = ImportMetaURLVisitor {};
   ast.visit_mut_with(&mut visitor);
 }
+
+/// replace `import.meta.hot` and `module.meta.hot` with `undefined` and remove the statements guarded by `if (import.meta.hot)`,
+/// the `else` branch is kept. The removed statements are dropped from their statement list, an empty statement
+/// is only left where a statement is required, e.g. `if (a) if (import.meta.hot) { ... }`
+struct ImportMetaHotStripper;
+
+impl ImportMetaHotStripper {
+  fn is_import_meta_hot(expr: &Expr) -> bool {
+    let Expr::Member(MemberExpr {
+      obj,
+      prop: MemberProp::Ident(Ident { sym, .. }),
+      ..
+    }) = expr
+    else {
+      return false;
+    };
+
+    sym == "hot"
+      && match &**obj {
+        Expr::MetaProp(meta_prop) => matches!(meta_prop.kind, MetaPropKind::ImportMeta),
+        // `import.meta` transformed by [ImportMetaVisitor]
+        Expr::Member(MemberExpr {
+          obj: box Expr::Ident(Ident { sym: module, .. }),
+          prop: MemberProp::Ident(Ident { sym: meta, .. }),
+          ..
+        }) => module == FARM_MODULE && meta == "meta",
+        _ => false,
+      }
+  }
+
+  /// the empty statement left by a removed `if (import.meta.hot)`, empty statements of the source have a span
+  fn is_stripped(stmt: &Stmt) -> bool {
+    matches!(stmt, Stmt::Empty(EmptyStmt { span }) if span.is_dummy())
+  }
+}
+
+impl VisitMut for ImportMetaHotStripper {
+  fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
+    if let Stmt::If(if_stmt) = stmt {
+      if Self::is_import_meta_hot(&if_stmt.test) {
+        *stmt = match if_stmt.alt.take() {
+          Some(box alt) => alt,
+          None => Stmt::Empty(EmptyStmt { span: DUMMY_SP }),
+        };
+      }
+    }
+
+    stmt.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+    items.visit_mut_children_with(self);
+    items.retain(|item| !matches!(item, ModuleItem::Stmt(stmt) if Self::is_stripped(stmt)));
+  }
+
+  fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+    stmts.visit_mut_children_with(self);
+    stmts.retain(|stmt| !Self::is_stripped(stmt));
+  }
+
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    if Self::is_import_meta_hot(expr) {
+      *expr = Expr::Ident(Ident::new("undefined".into(), DUMMY_SP));
+    } else {
+      expr.visit_mut_children_with(self);
+    }
+  }
+}
+
+pub fn strip_import_meta_hot(ast: &mut farmfe_core::swc_ecma_ast::Module) {
+  let mut visitor = ImportMetaHotStripper;
+  ast.visit_mut_with(&mut visitor);
+}
@@ -6,11 +6,12 @@ use std::{
   sync::Arc,
 };
 
+use default_import_checker::check_missing_default_imports;
 use deps_analyzer::DepsAnalyzer;
 use farmfe_core::{
-  config::{Config, ModuleFormat, TargetEnv},
+  config::{Config, Mode, TargetEnv},
   context::CompilationContext,
-  error::Result,
+  error::{CompilationError, Result},
   module::{
     CommentsMetaData, ModuleMetaData, ModuleSystem, ModuleType, ScriptModuleMetaData,
     VIRTUAL_MODULE_PREFIX,
@@ -30,7 +31,8 @@ use farmfe_core::{
 use farmfe_swc_transformer_import_glob::transform_import_meta_glob;
 use farmfe_toolkit::{
   common::{
-    create_swc_source_map, generate_source_map_resource, load_source_original_source_map, Source,
+    create_swc_source_map, generate_source_map_resource_with_chain,
+    load_source_original_source_map, wrap_js_resource_code, Source,
   },
   fs::read_file_utf8,
   script::{
@@ -40,12 +42,15 @@ use farmfe_toolkit::{
   swc_ecma_transforms::resolver,
   swc_ecma_visit::VisitMutWith,
 };
+use import_binding_checker::find_reassigned_imports;
 
-use import_meta_visitor::{replace_import_meta_url, ImportMetaVisitor};
+use import_meta_visitor::{replace_import_meta_url, strip_import_meta_hot, ImportMetaVisitor};
 #[cfg(feature = "swc_plugin")]
 use swc_plugins::{init_plugin_module_cache_once, transform_by_swc_plugins};
 
+mod default_import_checker;
 mod deps_analyzer;
+mod import_binding_checker;
 mod import_meta_visitor;
 #[cfg(feature = "swc_plugin")]
 mod swc_plugins;
@@ -170,6 +175,20 @@ impl Plugin for FarmPluginScript {
       swc_script_transforms::strip_typescript(param, &cm, context)?;
     }
 
+    // imported bindings are read-only, reassigning them would throw at runtime
+    if let Some(name) = find_reassigned_imports(&param.meta.as_script().ast).first() {
+      return Err(CompilationError::GenericError(format!(
+        "Cannot reassign imported binding `{name}` in {}. Imported bindings are read-only, declare a local variable instead",
+        param.module_id.to_string()
+      )));
+    }
+
+    // hmr is never enabled in production, `if (import.meta.hot) { ... }` blocks are dead code.
+    // strip them before analyzing deps, so that the modules only imported by the blocks are not built
+    if matches!(context.config.mode, Mode::Production) {
+      strip_import_meta_hot(&mut param.meta.as_script_mut().ast);
+    }
+
     // execute swc plugins
     #[cfg(feature = "swc_plugin")]
     if param.module_type.is_script() && !context.config.script.plugins.is_empty() {
@@ -252,7 +271,7 @@ impl Plugin for FarmPluginScript {
     set_module_system_for_module_meta(param, context);
 
     let is_replace_import_meta_url = context.config.output.target_env.is_library()
-      && matches!(context.config.output.format, ModuleFormat::CommonJs);
+      && !context.config.output.format.common().is_esm();
 
     if is_replace_import_meta_url {
       let ast = &mut param.module.meta.as_script_mut().ast;
@@ -263,7 +282,7 @@ impl Plugin for FarmPluginScript {
     // skip transform import.meta when targetEnv is node
     if !context.config.output.target_env.is_library()
       && (matches!(context.config.output.target_env, TargetEnv::Browser)
-        || matches!(context.config.output.format, ModuleFormat::CommonJs))
+        || !context.config.output.format.common().is_esm())
     {
       // transform `import.meta.xxx` to `module.meta.xxx`
       let ast = &mut param.module.meta.as_script_mut().ast;
@@ -287,6 +306,21 @@ impl Plugin for FarmPluginScript {
     Ok(None)
   }
 
+  /// warn about default imports that target an esm module without a default export after the module graph is linked
+  fn build_end(&self, context: &Arc<CompilationContext>) -> Result<Option<()>> {
+    let warnings = check_missing_default_imports(&context.module_graph.read());
+
+    if !warnings.is_empty() {
+      let mut log_store = context.log_store.lock();
+
+      for warning in warnings {
+        log_store.add_warning(warning);
+      }
+    }
+
+    Ok(None)
+  }
+
   fn generate_resources(
     &self,
     resource_pot: &mut ResourcePot,
@@ -294,10 +328,21 @@ impl Plugin for FarmPluginScript {
     _hook_context: &PluginHookContext,
   ) -> Result<Option<PluginGenerateResourcesHookResult>> {
     if matches!(resource_pot.resource_pot_type, ResourcePotType::Js) {
-      let buf = resource_pot.meta.rendered_content.as_bytes().to_vec();
+      let mut rendered_content = resource_pot.meta.rendered_content.clone();
+      let mut rendered_map_chain = resource_pot.meta.rendered_map_chain.clone();
+
+      // the banner and footer of the entries are added by the runtime plugin, around the injected runtime.
+      // there is no runtime in library mode, so they are added here
+      if resource_pot.entry_module.is_none() || context.config.output.target_env.is_library() {
+        if let Some(code) =
+          wrap_js_resource_code(&rendered_content, &mut rendered_map_chain, &context.config)
+        {
+          rendered_content = Arc::new(code);
+        }
+      }
 
       let resource = Resource {
-        bytes: buf,
+        bytes: rendered_content.as_bytes().to_vec(),
         name: resource_pot.name.to_string(),
         emitted: false,
         resource_type: ResourceType::Js,
@@ -306,11 +351,11 @@ impl Plugin for FarmPluginScript {
       };
       let mut source_map = None;
 
-      if context.config.sourcemap.enabled(resource_pot.immutable)
-        && !resource_pot.meta.rendered_map_chain.is_empty()
+      if context.config.sourcemap.enabled(resource_pot.immutable) && !rendered_map_chain.is_empty()
       {
         // collapse source map chain
-        let map = generate_source_map_resource(resource_pot);
+        let map =
+          generate_source_map_resource_with_chain(resource_pot, &rendered_map_chain, context)?;
         source_map = Some(map);
       }
 
@@ -0,0 +1,3 @@
+console.log('side effect');
+
+export {};
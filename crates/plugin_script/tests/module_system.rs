@@ -9,7 +9,7 @@ pub fn module_system() {
   fixture("tests/fixtures/module_system/**/*.js", |path, base| {
     let module = build_module(path.clone(), base);
 
-    if path.ends_with("esm.js") {
+    if path.ends_with("esm.js") || path.ends_with("empty_export.js") {
       assert_eq!(
         module.meta.as_script().module_system,
         ModuleSystem::EsModule
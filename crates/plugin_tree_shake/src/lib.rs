@@ -1,20 +1,15 @@
 #![feature(box_patterns)]
 #![feature(exact_size_is_empty)]
 
-use farmfe_core::{
-  config::{Config, Mode},
-  plugin::Plugin,
-};
-
-use crate::remove_hot_update::remove_useless_hot_update_stmts;
+use farmfe_core::{config::Config, plugin::Plugin};
 
 pub mod fill_module_mark;
 pub mod init_tree_shake_module_map;
 pub mod mark_initial_side_effects;
 pub mod module;
-pub mod remove_hot_update;
 pub mod statement_graph;
 pub mod tree_shake_modules;
+pub mod tree_shaking_report;
 
 pub struct FarmPluginTreeShake;
 
@@ -72,16 +67,19 @@ impl Plugin for FarmPluginTreeShake {
       }
     }
 
-    // 6. remove the unused modules
+    // 6. record the declared and retained exports of the tree shaken modules
+    context.custom.insert(
+      tree_shaking_report::TREE_SHAKING_REPORT.to_string(),
+      Box::new(tree_shaking_report::generate_tree_shaking_report(
+        &tree_shake_modules_map,
+      )),
+    );
+
+    // 7. remove the unused modules
     for module_id in modules_to_remove {
       module_graph.remove_module(&module_id);
     }
 
-    // 7. remove useless hot update statements if production
-    if matches!(context.config.mode, Mode::Production) {
-      remove_useless_hot_update_stmts(module_graph);
-    }
-
     Ok(Some(()))
   }
 }
@@ -30,10 +30,25 @@ pub fn analyze_statement_side_effects(
 
           analyzer.side_effects
         }
+        farmfe_core::swc_ecma_ast::Decl::Class(class_decl) => {
+          let mut analyzer = SideEffectsAnalyzer::new(unresolved_mark, top_level_mark, comments);
+          analyzer.set_in_top_level(true);
+          class_decl.class.visit_with(&mut analyzer);
+
+          analyzer.side_effects
+        }
         _ => StatementSideEffects::NoSideEffects,
       },
       farmfe_core::swc_ecma_ast::ModuleDecl::ExportNamed(_) => StatementSideEffects::NoSideEffects,
-      farmfe_core::swc_ecma_ast::ModuleDecl::ExportDefaultDecl(_) => {
+      farmfe_core::swc_ecma_ast::ModuleDecl::ExportDefaultDecl(default_decl) => {
+        if let farmfe_core::swc_ecma_ast::DefaultDecl::Class(class_expr) = &default_decl.decl {
+          let mut analyzer = SideEffectsAnalyzer::new(unresolved_mark, top_level_mark, comments);
+          analyzer.set_in_top_level(true);
+          class_expr.class.visit_with(&mut analyzer);
+
+          return analyzer.side_effects;
+        }
+
         StatementSideEffects::NoSideEffects
       }
       farmfe_core::swc_ecma_ast::ModuleDecl::ExportDefaultExpr(default_expr) => {
@@ -64,6 +79,8 @@ struct SideEffectsAnalyzer<'a> {
   in_assign_left: bool,
   in_top_level: bool,
   in_call: bool,
+  /// `this` in a class static block refers to the class itself
+  in_static_block: bool,
 }
 
 impl<'a> SideEffectsAnalyzer<'a> {
@@ -80,6 +97,7 @@ impl<'a> SideEffectsAnalyzer<'a> {
       in_assign_left: false,
       in_top_level: false,
       in_call: false,
+      in_static_block: false,
     }
   }
 
@@ -111,6 +129,24 @@ impl<'a> Visit for SideEffectsAnalyzer<'a> {
     self.set_in_top_level(pre);
   }
 
+  fn visit_class(&mut self, n: &farmfe_core::swc_ecma_ast::Class) {
+    if !self.is_in_top_level() {
+      return;
+    }
+
+    // only static blocks are executed when the class is defined, methods and properties are not analyzed for now
+    for member in &n.body {
+      if let farmfe_core::swc_ecma_ast::ClassMember::StaticBlock(static_block) = member {
+        let pre = self.in_static_block;
+        self.in_static_block = true;
+
+        static_block.body.visit_children_with(self);
+
+        self.in_static_block = pre;
+      }
+    }
+  }
+
   fn visit_var_decl(&mut self, n: &farmfe_core::swc_ecma_ast::VarDecl) {
     if !self.is_in_top_level() {
       return;
@@ -161,11 +197,11 @@ impl<'a> Visit for SideEffectsAnalyzer<'a> {
       | farmfe_core::swc_ecma_ast::Stmt::For(_)
       | farmfe_core::swc_ecma_ast::Stmt::ForIn(_)
       | farmfe_core::swc_ecma_ast::Stmt::ForOf(_) => n.visit_children_with(self),
-      farmfe_core::swc_ecma_ast::Stmt::Decl(decl) => {
-        if let farmfe_core::swc_ecma_ast::Decl::Var(var_decl) = decl {
-          var_decl.visit_with(self);
-        }
-      }
+      farmfe_core::swc_ecma_ast::Stmt::Decl(decl) => match decl {
+        farmfe_core::swc_ecma_ast::Decl::Var(var_decl) => var_decl.visit_with(self),
+        farmfe_core::swc_ecma_ast::Decl::Class(class_decl) => class_decl.class.visit_with(self),
+        _ => {}
+      },
       farmfe_core::swc_ecma_ast::Stmt::Expr(expr) => {
         expr.visit_children_with(self);
       }
@@ -217,7 +253,17 @@ impl<'a> Visit for SideEffectsAnalyzer<'a> {
     }
 
     match expr {
-      Expr::Fn(_) | Expr::Class(_) | Expr::Lit(_) | Expr::Arrow(_) => self
+      Expr::Fn(_) | Expr::Lit(_) | Expr::Arrow(_) => self
+        .side_effects
+        .merge_side_effects(StatementSideEffects::NoSideEffects),
+      Expr::Class(class_expr) => {
+        self
+          .side_effects
+          .merge_side_effects(StatementSideEffects::NoSideEffects);
+        class_expr.class.visit_with(self);
+      }
+      // the class is only defined by the statement itself, so reading or writing it in static blocks has no side effects
+      Expr::This(_) if self.in_static_block => self
         .side_effects
         .merge_side_effects(StatementSideEffects::NoSideEffects),
       Expr::Call(_) | Expr::New(_) | Expr::This(_) | Expr::SuperProp(_) => {
@@ -377,3 +377,53 @@ fn object_lit_assign() {
     ));
   })
 }
+
+#[test]
+fn class_static_block() {
+  GLOBALS.set(&Globals::new(), || {
+    let code = r#"
+    class A {
+      static count = 0;
+      static {
+        const initial = 1;
+        this.count = initial;
+      }
+    }
+    export class B {
+      static {
+        console.log('B is defined');
+      }
+    }
+    const C = class {
+      static {
+        this.created = new Date();
+      }
+    }
+"#;
+    let (module, comments, unresolved_mark, top_level_mark) = parse_module_comments(code);
+
+    let side_effects = super::analyze_statement_side_effects(
+      &module.body[0],
+      unresolved_mark,
+      top_level_mark,
+      &comments,
+    );
+    assert!(!side_effects.is_preserved());
+
+    let side_effects = super::analyze_statement_side_effects(
+      &module.body[1],
+      unresolved_mark,
+      top_level_mark,
+      &comments,
+    );
+    assert!(side_effects.is_preserved());
+
+    let side_effects = super::analyze_statement_side_effects(
+      &module.body[2],
+      unresolved_mark,
+      top_level_mark,
+      &comments,
+    );
+    assert!(side_effects.is_preserved());
+  })
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use farmfe_core::module::ModuleId;
+
+use crate::{
+  module::{TreeShakeModule, UsedExports, UsedExportsIdent},
+  statement_graph::ExportSpecifierInfo,
+};
+
+/// Key of the tree shaking report in `context.custom`
+pub const TREE_SHAKING_REPORT: &str = "tree_shaking_report";
+
+/// Declared and retained exports of a tree shaken module
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TreeShakeInfo {
+  /// exports declared in the source of the module, `export * from` is not included
+  pub declared_exports: Vec<String>,
+  /// exports that are used and preserved after tree shaking
+  pub retained_exports: Vec<String>,
+}
+
+impl TreeShakeInfo {
+  /// exports that are removed by tree shaking
+  pub fn removed_exports(&self) -> Vec<String> {
+    self
+      .declared_exports
+      .iter()
+      .filter(|export| !self.retained_exports.contains(export))
+      .cloned()
+      .collect()
+  }
+}
+
+pub fn generate_tree_shaking_report(
+  tree_shake_modules_map: &HashMap<ModuleId, TreeShakeModule>,
+) -> HashMap<ModuleId, TreeShakeInfo> {
+  tree_shake_modules_map
+    .iter()
+    .map(|(module_id, tree_shake_module)| {
+      let mut declared_exports = tree_shake_module
+        .exports()
+        .into_iter()
+        .flat_map(|export_info| export_info.specifiers.iter())
+        .filter_map(|specifier| match specifier {
+          ExportSpecifierInfo::Default => Some("default".to_string()),
+          ExportSpecifierInfo::Named { local, exported } => {
+            Some(exported.as_ref().unwrap_or(local).0.to_string())
+          }
+          ExportSpecifierInfo::Namespace(ns) => Some(ns.0.to_string()),
+          ExportSpecifierInfo::All => None,
+        })
+        .collect::<Vec<_>>();
+      declared_exports.sort();
+      declared_exports.dedup();
+
+      let retained_exports = match &tree_shake_module.handled_used_exports {
+        UsedExports::All => declared_exports.clone(),
+        UsedExports::Partial(idents) if idents.contains(&UsedExportsIdent::ImportAll) => {
+          declared_exports.clone()
+        }
+        UsedExports::Partial(idents) => declared_exports
+          .iter()
+          .filter(|export| {
+            // `export * from` uses all the exports except the default export
+            (idents.contains(&UsedExportsIdent::ExportAll) && export.as_str() != "default")
+              || idents.iter().any(|ident| ident.is_ident_matched(export))
+          })
+          .cloned()
+          .collect(),
+      };
+
+      (
+        module_id.clone(),
+        TreeShakeInfo {
+          declared_exports,
+          retained_exports,
+        },
+      )
+    })
+    .collect()
+}
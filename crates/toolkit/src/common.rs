@@ -9,9 +9,16 @@ use farmfe_core::{
     comments::CommentsConfig,
     config_regex::ConfigRegex,
     minify::{MinifyMode, MinifyOptions},
-    SourcemapConfig,
+    Config, SourcemapConfig,
   },
-  enhanced_magic_string::collapse_sourcemap::collapse_sourcemap_chain,
+  context::CompilationContext,
+  enhanced_magic_string::{
+    bundle::{Bundle, BundleOptions},
+    collapse_sourcemap::collapse_sourcemap_chain,
+    magic_string::MagicString,
+    types::{MappingsOptionHires, SourceMapOptions},
+  },
+  error::Result,
   relative_path::RelativePath,
   resource::{resource_pot::ResourcePot, Resource, ResourceOrigin, ResourceType},
   serde_json::Value,
@@ -20,6 +27,7 @@ use farmfe_core::{
     source_map::SourceMapGenConfig,
     BytePos, FileName, LineCol, SourceFile, SourceMap,
   },
+  VERSION,
 };
 use farmfe_utils::hash::base64_decode;
 
@@ -43,25 +51,30 @@ pub fn append_source_map_comment(
   map: &Resource,
   config: &SourcemapConfig,
 ) {
+  // the hidden source map is emitted without being referenced by the resource
+  if config.is_hidden() {
+    return;
+  }
+
   let source_map_str = match &resource.resource_type {
     ResourceType::Js => "\n//# sourceMappingURL=",
     ResourceType::Css => "\n/*# sourceMappingURL=",
     _ => unreachable!("only js and css need source map"),
   };
 
-  // get last path segment
-  let mut source_map_url = PathBuf::from(&map.name)
-    .components()
-    .last()
-    .unwrap()
-    .as_os_str()
-    .to_str()
-    .unwrap()
-    .to_string();
-
-  if config.is_inline() {
-    source_map_url = format!("data:application/json;base64,{}", base64_encode(&map.bytes));
-  }
+  let source_map_url = if config.is_inline() {
+    format!("data:application/json;base64,{}", base64_encode(&map.bytes))
+  } else {
+    // get last path segment
+    PathBuf::from(&map.name)
+      .components()
+      .last()
+      .unwrap()
+      .as_os_str()
+      .to_str()
+      .unwrap()
+      .to_string()
+  };
 
   let source_map_comment = format!(
     "{}{}{}",
@@ -77,17 +90,123 @@ pub fn append_source_map_comment(
   resource.bytes.append(&mut source_map_comment.into_bytes());
 }
 
-pub fn generate_source_map_resource(resource_pot: &ResourcePot) -> Resource {
-  // collapse source map chain
-  let source_map_chain = resource_pot
-    .meta
-    .rendered_map_chain
+/// The comment prepended to the emitted js resources when `output.versionComment` is enabled
+pub fn farm_version_comment(config: &Config) -> String {
+  format!("/* Farm v{VERSION} ({}) */\n", config.mode.to_string())
+}
+
+/// The code prepended to every emitted js resource, `output.banner` followed by the comment of `output.versionComment`
+pub fn js_resource_intro(config: &Config) -> Option<String> {
+  let mut intro = String::new();
+
+  if let Some(banner) = &config.output.banner {
+    intro.push_str(banner);
+    intro.push('\n');
+  }
+
+  if config.output.version_comment {
+    intro.push_str(&farm_version_comment(config));
+  }
+
+  (!intro.is_empty()).then_some(intro)
+}
+
+/// The code appended to every emitted js resource, that is `output.footer`
+pub fn js_resource_outro(config: &Config) -> Option<String> {
+  config
+    .output
+    .footer
+    .as_ref()
+    .map(|footer| format!("\n{footer}"))
+}
+
+/// Wrap the rendered code of a js resource pot with [js_resource_intro] and [js_resource_outro],
+/// the source map of the wrapped code is pushed to the map chain so the original positions are kept.
+/// Return [None] if there is nothing to wrap.
+pub fn wrap_js_resource_code(
+  code: &str,
+  source_map_chain: &mut Vec<Arc<String>>,
+  config: &Config,
+) -> Option<String> {
+  let intro = js_resource_intro(config);
+  let outro = js_resource_outro(config);
+
+  if intro.is_none() && outro.is_none() {
+    return None;
+  }
+
+  let mut bundle = Bundle::new(BundleOptions {
+    intro: intro.as_deref().map(Into::into),
+    ..Default::default()
+  });
+  bundle
+    .add_source(MagicString::new(code, None), None)
+    .expect("failed to add the rendered code to the bundle");
+
+  if let Some(outro) = &outro {
+    bundle.append(outro, None);
+  }
+
+  if !source_map_chain.is_empty() {
+    let map = bundle
+      .generate_map(SourceMapOptions {
+        include_content: Some(true),
+        // minified code is rendered in a single line, a mapping per line would lose all the original tokens when collapsing
+        hires: if config.minify.enabled() {
+          Some(MappingsOptionHires::Boundary)
+        } else {
+          None
+        },
+        ..Default::default()
+      })
+      .expect("failed to generate the source map of the wrapped code");
+    let mut buf = vec![];
+    map.to_writer(&mut buf).expect("failed to write sourcemap");
+    source_map_chain.push(Arc::new(String::from_utf8(buf).unwrap()));
+  }
+
+  Some(bundle.to_string())
+}
+
+/// Collapse the rendered source map chain of the resource pot, then the `transform_sourcemap` hook of the plugins
+/// is called with the collapsed map before it is serialized.
+pub fn generate_source_map_resource(
+  resource_pot: &ResourcePot,
+  context: &Arc<CompilationContext>,
+) -> Result<Resource> {
+  generate_source_map_resource_with_chain(
+    resource_pot,
+    &resource_pot.meta.rendered_map_chain,
+    context,
+  )
+}
+
+/// Same as [generate_source_map_resource] but collapses the given source map chain of the resource pot
+pub fn generate_source_map_resource_with_chain(
+  resource_pot: &ResourcePot,
+  rendered_map_chain: &[Arc<String>],
+  context: &Arc<CompilationContext>,
+) -> Result<Resource> {
+  let mut collapsed_sourcemap = collapse_rendered_map_chain(rendered_map_chain);
+  context
+    .plugin_driver
+    .transform_sourcemap(&mut collapsed_sourcemap, &resource_pot.id, context)?;
+
+  Ok(source_map_resource(resource_pot, &collapsed_sourcemap))
+}
+
+pub fn collapse_rendered_map_chain(rendered_map_chain: &[Arc<String>]) -> sourcemap::SourceMap {
+  let source_map_chain = rendered_map_chain
     .iter()
     .map(|s| sourcemap::SourceMap::from_slice(s.as_bytes()).unwrap())
     .collect::<Vec<_>>();
-  let collapsed_sourcemap = collapse_sourcemap_chain(source_map_chain, Default::default());
+  collapse_sourcemap_chain(source_map_chain, Default::default())
+}
+
+/// The source map [Resource] of the resource pot
+pub fn source_map_resource(resource_pot: &ResourcePot, map: &sourcemap::SourceMap) -> Resource {
   let mut src_map = vec![];
-  collapsed_sourcemap
+  map
     .to_writer(&mut src_map)
     .expect("failed to write sourcemap");
   Resource {
@@ -146,7 +265,7 @@ pub fn minify_comments(comments: &SingleThreadedComments, config: &CommentsConfi
   match config {
     // preserve all comments
     CommentsConfig::Bool(true) => {}
-    CommentsConfig::Bool(false) => {
+    CommentsConfig::Bool(false) | CommentsConfig::None => {
       let (mut l, mut t) = comments.borrow_all_mut();
       l.clear();
       t.clear();
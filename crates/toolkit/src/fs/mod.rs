@@ -1,4 +1,8 @@
-use farmfe_core::error::{CompilationError, Result};
+use farmfe_core::{
+  config::{ModuleFormat, TargetEnv},
+  error::{CompilationError, Result},
+  resource::ResourceType,
+};
 
 use crate::hash::sha256;
 
@@ -20,6 +24,24 @@ pub fn read_file_raw(path: &str) -> Result<Vec<u8>> {
   std::fs::read(path).map_err(|e| CompilationError::GenericError(format!("{e:?}")))
 }
 
+/// The `[ext]` of the emitted resource. Js resources of es module libraries targeting node are emitted as `.mjs`,
+/// node only loads `.js` files as es modules when the nearest `package.json` has `"type": "module"`, and the chunks
+/// import each other by the full file name.
+pub fn resource_ext(
+  resource_type: &ResourceType,
+  format: ModuleFormat,
+  target_env: &TargetEnv,
+) -> String {
+  match resource_type {
+    ResourceType::Js | ResourceType::Runtime
+      if format == ModuleFormat::EsModule && target_env.is_library() && target_env.is_node() =>
+    {
+      "mjs".to_string()
+    }
+    _ => resource_type.to_ext(),
+  }
+}
+
 pub fn transform_output_filename(
   filename_config: String,
   name: &str,
@@ -64,3 +86,24 @@ pub fn transform_output_entry_filename(
 
   transform_output_filename(res, name, bytes, ext)
 }
+
+/// relative path from the resource `from` to the resource `to`, both are relative to the output dir,
+/// e.g. `assets/index.js` -> `chunks/shared.js` = `../chunks/shared.js`
+pub fn relative_resource_path(from: &str, to: &str) -> String {
+  let from_dir = from.split('/').collect::<Vec<_>>();
+  let from_dir = &from_dir[..from_dir.len() - 1];
+  let to = to.split('/').collect::<Vec<_>>();
+
+  let common = from_dir
+    .iter()
+    .zip(to.iter())
+    .take_while(|(a, b)| a == b)
+    .count();
+  let parents = vec![".."; from_dir.len() - common];
+
+  if parents.is_empty() {
+    format!("./{}", to[common..].join("/"))
+  } else {
+    format!("{}/{}", parents.join("/"), to[common..].join("/"))
+  }
+}
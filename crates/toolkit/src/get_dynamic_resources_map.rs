@@ -7,7 +7,7 @@ use farmfe_core::{
     module_group::{ModuleGroupGraph, ModuleGroupId},
     ModuleId,
   },
-  resource::{resource_pot_map::ResourcePotMap, Resource, ResourceType},
+  resource::{resource_pot::ResourcePot, resource_pot_map::ResourcePotMap, Resource, ResourceType},
 };
 
 pub fn get_dynamic_resources_map(
@@ -75,8 +75,71 @@ pub fn get_dynamic_resources_map(
   dynamic_resources_map
 }
 
+/// Get the js resources that each js resource statically depends on, e.g. a lazy chunk depends on the vendor chunk split from it,
+/// so the runtime can preload the dependencies of a chunk before executing its modules.
+pub fn get_resource_dependencies(
+  resource_pot_map: &ResourcePotMap,
+  resources_map: &HashMap<String, Resource>,
+  module_graph: &ModuleGraph,
+) -> HashMap<String, Vec<String>> {
+  let js_resources = |rp: &ResourcePot| {
+    rp.resources()
+      .into_iter()
+      .filter(|r| {
+        resources_map
+          .get(*r)
+          .is_some_and(|resource| matches!(resource.resource_type, ResourceType::Js))
+      })
+      .cloned()
+      .collect::<Vec<_>>()
+  };
+  let mut resource_dependencies = HashMap::new();
+
+  for rp in resource_pot_map.resource_pots() {
+    let mut deps = vec![];
+
+    for module_id in rp.modules() {
+      for (dep_id, edge) in module_graph.dependencies(module_id) {
+        if edge.is_dynamic() {
+          continue;
+        }
+
+        let Some(dep_rp) = module_graph
+          .module(&dep_id)
+          .and_then(|m| m.resource_pot.as_ref())
+          .filter(|dep_rp_id| *dep_rp_id != &rp.id)
+          .and_then(|dep_rp_id| resource_pot_map.resource_pot(dep_rp_id))
+        else {
+          continue;
+        };
+
+        for resource in js_resources(dep_rp) {
+          if !deps.contains(&resource) {
+            deps.push(resource);
+          }
+        }
+      }
+    }
+
+    if deps.is_empty() {
+      continue;
+    }
+
+    deps.sort();
+
+    for resource in js_resources(rp) {
+      resource_dependencies.insert(resource, deps.clone());
+    }
+  }
+
+  resource_dependencies
+}
+
+/// Generate the code of the dynamic resources and the resources map of the dynamic modules. A js resource carries `deps`, the indexes of
+/// the dynamic resources it depends on, the dependencies that are not dynamic resources are loaded with the entry already.
 pub fn get_dynamic_resources_code(
   dynamic_resources_map: &HashMap<ModuleId, Vec<(String, ResourceType)>>,
+  resource_dependencies: &HashMap<String, Vec<String>>,
   mode: Mode,
 ) -> (String, String) {
   let mut dynamic_resources_code_vec = vec![];
@@ -97,20 +160,11 @@ pub fn get_dynamic_resources_code(
         continue;
       }
 
-      match resource_type {
-        ResourceType::Js => {
-          dynamic_resources.push(format!(r#"{{ path: '{resource_name}', type: 0 }}"#));
-        }
-        ResourceType::Css => {
-          dynamic_resources.push(format!(r#"{{ path: '{resource_name}', type: 1 }}"#));
-        }
-        _ => {
-          panic!(
-            "unsupported type ({resource_type:?}) when injecting dynamic resources"
-          )
-        }
+      if !matches!(resource_type, ResourceType::Js | ResourceType::Css) {
+        panic!("unsupported type ({resource_type:?}) when injecting dynamic resources")
       }
 
+      dynamic_resources.push((resource_name, resource_type));
       dynamic_resources_index.push(format!("{}", dynamic_resources.len() - 1));
       visited_resources.insert(key, dynamic_resources.len() - 1);
     }
@@ -119,6 +173,31 @@ pub fn get_dynamic_resources_code(
     dynamic_resources_code_vec.push((id, dynamic_resources_index.join(",")));
   }
 
+  let dynamic_resources = dynamic_resources
+    .into_iter()
+    .map(|(resource_name, resource_type)| match resource_type {
+      ResourceType::Js => {
+        let deps = resource_dependencies
+          .get(resource_name)
+          .into_iter()
+          .flatten()
+          .filter_map(|dep| visited_resources.get(&format!("{dep}{:?}", ResourceType::Js)))
+          .map(|index| index.to_string())
+          .collect::<Vec<_>>();
+
+        if deps.is_empty() {
+          format!(r#"{{ path: '{resource_name}', type: 0 }}"#)
+        } else {
+          format!(
+            r#"{{ path: '{resource_name}', type: 0, deps: [{}] }}"#,
+            deps.join(",")
+          )
+        }
+      }
+      _ => format!(r#"{{ path: '{resource_name}', type: 1 }}"#),
+    })
+    .collect::<Vec<_>>();
+
   let mut dynamic_resources_code = dynamic_resources_code_vec
     .into_iter()
     .map(|(id, resources_code)| format!(r#"'{id}': [{resources_code}]"#))
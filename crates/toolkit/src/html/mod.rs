@@ -1,7 +1,7 @@
 use std::{path::PathBuf, sync::Arc};
 
 use farmfe_core::{
-  config::TargetEnv,
+  config::OutputConfig,
   error::CompilationError,
   swc_common::{input::SourceFileInput, DUMMY_SP},
   swc_html_ast::{Attribute, Child, Document, Element, Namespace, Text},
@@ -120,10 +120,16 @@ pub fn create_attribute(name: &str, value: Option<&str>) -> Attribute {
   }
 }
 
-pub fn get_farm_global_this(namespace: &str, target_env: &TargetEnv) -> String {
-  if target_env.is_node() {
-    format!("global['{namespace}']")
+pub fn get_global_object(output: &OutputConfig) -> &str {
+  if let Some(global_object) = &output.global_object {
+    global_object
+  } else if output.target_env.is_node() {
+    "global"
   } else {
-    format!("window['{namespace}']")
+    "window"
   }
 }
+
+pub fn get_farm_global_this(namespace: &str, output: &OutputConfig) -> String {
+  format!("{}['{namespace}']", get_global_object(output))
+}
@@ -40,6 +40,7 @@ pub use sourcemap;
 
 pub mod get_dynamic_resources_map;
 pub mod minify;
+pub mod standalone_runtime;
 
 // pluginutils
 pub mod pluginutils;
@@ -35,6 +35,14 @@ impl NormalizedMinifyOptions {
       if compress.toplevel.is_none() {
         compress.toplevel = Some(TerserTopLevelOptions::Bool(true));
       }
+
+      if minify.should_keep_fnames() {
+        compress.keep_fnames = true;
+      }
+
+      if minify.should_keep_classnames() {
+        compress.keep_classnames = true;
+      }
     }
 
     // mangle
@@ -54,6 +62,14 @@ impl NormalizedMinifyOptions {
           mangle.top_level = Some(true);
         }
 
+        if minify.should_keep_fnames() {
+          mangle.keep_fn_names = true;
+        }
+
+        if minify.should_keep_classnames() {
+          mangle.keep_class_names = true;
+        }
+
         mangle
       });
 
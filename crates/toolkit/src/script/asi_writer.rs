@@ -0,0 +1,118 @@
+use farmfe_core::swc_common::{BytePos, Span};
+use swc_ecma_codegen::text_writer::WriteJs;
+
+type Result = std::io::Result<()>;
+
+/// The lines starting with these characters would continue the statement of the previous line without a semicolon
+const ASI_HAZARDS: [char; 7] = ['(', '[', '`', '+', '-', '/', '*'];
+
+/// Writes the code in the ASI (automatic semicolon insertion) style: the semicolons at the end of lines are omitted,
+/// and a `;` is inserted before the next line when it starts with one of [ASI_HAZARDS], for example:
+/// ```js
+/// const a = b
+/// ;(function () {})()
+/// ```
+/// The semicolons that are followed by other code on the same line are kept, e.g. `for (let i = 0; i < n; i++)`.
+/// The first line is treated as following an omitted semicolon, as the modules are concatenated after other modules
+/// whose last semicolon is omitted.
+pub struct AsiWriter<W: WriteJs> {
+  inner: W,
+  pending_semi: Option<Option<Span>>,
+  /// the semicolon at the end of the previous line is omitted
+  omitted_semi: bool,
+}
+
+impl<W: WriteJs> AsiWriter<W> {
+  pub fn new(inner: W) -> Self {
+    Self {
+      inner,
+      pending_semi: None,
+      omitted_semi: true,
+    }
+  }
+
+  fn before_token(&mut self, token: &str) -> Result {
+    self.commit_pending_semi()?;
+
+    if self.omitted_semi {
+      self.omitted_semi = false;
+
+      if token.starts_with(ASI_HAZARDS) {
+        self.inner.write_punct(None, ";")?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+macro_rules! before_token {
+  ($fn_name:ident ( $( $arg_name:ident : $arg_ty:ty ),* ), $token:ident) => {
+    fn $fn_name(&mut self, $( $arg_name: $arg_ty ),*) -> Result {
+      self.before_token($token)?;
+      self.inner.$fn_name($( $arg_name ),*)
+    }
+  };
+}
+
+impl<W: WriteJs> WriteJs for AsiWriter<W> {
+  fn increase_indent(&mut self) -> Result {
+    self.inner.increase_indent()
+  }
+
+  fn decrease_indent(&mut self) -> Result {
+    self.inner.decrease_indent()
+  }
+
+  fn write_semi(&mut self, span: Option<Span>) -> Result {
+    self.commit_pending_semi()?;
+    self.pending_semi = Some(span);
+
+    Ok(())
+  }
+
+  fn write_space(&mut self) -> Result {
+    self.commit_pending_semi()?;
+    self.inner.write_space()
+  }
+
+  before_token!(write_keyword(span: Option<Span>, s: &'static str), s);
+  before_token!(write_operator(span: Option<Span>, s: &str), s);
+  before_token!(write_param(s: &str), s);
+  before_token!(write_property(s: &str), s);
+  before_token!(write_lit(span: Span, s: &str), s);
+  before_token!(write_str_lit(span: Span, s: &str), s);
+  before_token!(write_str(s: &str), s);
+  before_token!(write_symbol(span: Span, s: &str), s);
+  before_token!(write_punct(span: Option<Span>, s: &'static str), s);
+
+  fn write_line(&mut self) -> Result {
+    if self.pending_semi.take().is_some() {
+      self.omitted_semi = true;
+    }
+
+    self.inner.write_line()
+  }
+
+  /// comments do not end the omitted semicolon, the line after the comment is checked instead
+  fn write_comment(&mut self, s: &str) -> Result {
+    self.commit_pending_semi()?;
+    self.inner.write_comment(s)
+  }
+
+  fn care_about_srcmap(&self) -> bool {
+    self.inner.care_about_srcmap()
+  }
+
+  fn add_srcmap(&mut self, pos: BytePos) -> Result {
+    self.inner.add_srcmap(pos)
+  }
+
+  fn commit_pending_semi(&mut self) -> Result {
+    if let Some(span) = self.pending_semi.take() {
+      self.inner.write_semi(span)?;
+    }
+
+    Ok(())
+  }
+}
@@ -17,8 +17,8 @@ use farmfe_core::{
     BytePos, FileName, LineCol, Mark, SourceMap,
   },
   swc_ecma_ast::{
-    CallExpr, Callee, EsVersion, Expr, Ident, Import, MemberProp, Module as SwcModule, ModuleItem,
-    Stmt,
+    CallExpr, Callee, EsVersion, Expr, ExprStmt, Ident, Import, Lit, MemberProp,
+    Module as SwcModule, ModuleItem, Stmt,
   },
 };
 use swc_ecma_visit::{Visit, VisitWith};
@@ -28,8 +28,9 @@ use crate::common::{create_swc_source_map, minify_comments, Source};
 
 pub use farmfe_toolkit_plugin_types::swc_ast::ParseScriptModuleResult;
 
-use self::swc_try_with::try_with;
+use self::{asi_writer::AsiWriter, swc_try_with::try_with};
 
+mod asi_writer;
 pub mod defined_idents_collector;
 pub mod swc_try_with;
 pub mod constant;
@@ -120,11 +121,47 @@ pub fn codegen_module(
   src_map: Option<&mut Vec<(BytePos, LineCol)>>,
   minify: bool,
   comments_cfg: Option<CodeGenCommentsConfig>,
+) -> std::result::Result<Vec<u8>, std::io::Error> {
+  codegen_module_with_indent(ast, target, cm, src_map, minify, comments_cfg, None, true)
+}
+
+/// the widest indent supported by [codegen_module_with_indent], `output.indent` is validated against it on the node side
+const MAX_INDENT: &str = "                ";
+
+/// Same as [codegen_module], but the generated code is nested in `indent` spaces, for example, the module is a property of a object literal.
+/// The indent is clamped to 16 spaces, see [MAX_INDENT].
+/// The first line is not indented and the trailing new line is removed, so that it can be wrapped like `"key": <code>,`.
+/// When `semicolons` is false, the code is generated in the ASI style, see [AsiWriter]. It's ignored when `minify` is true.
+#[allow(clippy::too_many_arguments)]
+pub fn codegen_module_with_indent(
+  ast: &SwcModule,
+  target: EsVersion,
+  cm: Arc<SourceMap>,
+  mut src_map: Option<&mut Vec<(BytePos, LineCol)>>,
+  minify: bool,
+  comments_cfg: Option<CodeGenCommentsConfig>,
+  indent: Option<usize>,
+  semicolons: bool,
 ) -> std::result::Result<Vec<u8>, std::io::Error> {
   let mut buf = vec![];
+  let indent_str = indent
+    .filter(|_| !minify)
+    .map(|indent| &MAX_INDENT[..indent.min(MAX_INDENT.len())])
+    .filter(|indent_str| !indent_str.is_empty());
 
   {
-    let wr = Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, src_map)) as Box<dyn WriteJs>;
+    let mut writer = JsWriter::new(cm.clone(), "\n", &mut buf, src_map.as_deref_mut());
+
+    if let Some(indent_str) = indent_str {
+      writer.set_indent_str(indent_str);
+      writer.increase_indent()?;
+    }
+
+    let wr = if semicolons || minify {
+      Box::new(writer) as Box<dyn WriteJs>
+    } else {
+      Box::new(AsiWriter::new(writer))
+    };
     let cfg = swc_ecma_codegen::Config::default()
       .with_minify(minify)
       .with_target(target)
@@ -147,6 +184,23 @@ pub fn codegen_module(
     ast.emit_with(&mut emitter)?;
   }
 
+  // strip the indent of the first line and the trailing new line
+  if let Some(indent_str) = indent_str {
+    while buf.last() == Some(&b'\n') {
+      buf.pop();
+    }
+
+    if buf.starts_with(indent_str.as_bytes()) {
+      buf.drain(..indent_str.len());
+
+      if let Some(src_map) = src_map {
+        for (_, line_col) in src_map.iter_mut().filter(|(_, lc)| lc.line == 0) {
+          line_col.col = line_col.col.saturating_sub(indent_str.len() as u32);
+        }
+      }
+    }
+  }
+
   Ok(buf)
 }
 
@@ -209,6 +263,26 @@ pub fn is_dynamic_import(call_expr: &CallExpr) -> bool {
   matches!(&call_expr.callee, Callee::Import(Import { .. }))
 }
 
+/// The value of the directive, e.g. `use strict` of `"use strict";`
+pub fn directive(item: &ModuleItem) -> Option<String> {
+  match item {
+    ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+      expr: box Expr::Lit(Lit::Str(str)),
+      ..
+    })) => Some(str.value.to_string()),
+    _ => None,
+  }
+}
+
+/// the directive prologue ends at the first statement that is not a string literal
+pub fn directive_prologue_len(ast: &SwcModule) -> usize {
+  ast
+    .body
+    .iter()
+    .take_while(|item| directive(item).is_some())
+    .count()
+}
+
 pub fn module_system_from_deps(deps: Vec<ResolveKind>) -> ModuleSystem {
   let mut module_system = ModuleSystem::Custom(String::from("unknown"));
 
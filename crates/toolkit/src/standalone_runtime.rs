@@ -0,0 +1,56 @@
+//! Helpers for `runtime.standalone`. The runtime is emitted as a standalone versioned file,
+//! so that multiple builds can share it by referencing the same url.
+use farmfe_core::{config::RuntimeStandaloneConfig, VERSION};
+use farmfe_utils::hash::sha256;
+
+use crate::fs::relative_resource_path;
+
+pub const FARM_RUNTIME_VERSION: &str = "__FARM_RUNTIME_VERSION__";
+const STANDALONE_RUNTIME_NAME: &str = "farm_runtime";
+
+pub struct StandaloneRuntime {
+  /// `{core version}-{runtime code hash}`, builds can only share the runtime with the same version
+  pub version: String,
+  /// name of the emitted runtime resource
+  pub name: String,
+  /// content of the emitted runtime resource
+  pub code: String,
+}
+
+impl StandaloneRuntime {
+  pub fn new(runtime_code: &str, farm_global_this: &str) -> Self {
+    let version = format!("{VERSION}-{}", sha256(runtime_code.as_bytes(), 8));
+    let name = format!("{STANDALONE_RUNTIME_NAME}.{version}.js");
+    let code = format!("{runtime_code}{farm_global_this}.{FARM_RUNTIME_VERSION} = '{version}';");
+
+    Self {
+      version,
+      name,
+      code,
+    }
+  }
+
+  /// The url used to reference the standalone runtime, the emitted runtime is used if `runtime.standalone.url` is not set
+  pub fn url(&self, config: &RuntimeStandaloneConfig, public_path: &str) -> String {
+    config
+      .url
+      .clone()
+      .unwrap_or_else(|| format!("{public_path}{}", self.name))
+  }
+
+  /// The url used to import the standalone runtime from the resource `importer`, e.g. `../farm_runtime.xxx.js` for `js/index.js`
+  pub fn import_url(&self, config: &RuntimeStandaloneConfig, importer: &str) -> String {
+    config
+      .url
+      .clone()
+      .unwrap_or_else(|| relative_resource_path(importer, &self.name))
+  }
+
+  /// Throw if the loaded runtime is not compatible with current build
+  pub fn version_check_code(&self, farm_global_this: &str) -> String {
+    format!(
+      r#"if (!{farm_global_this} || {farm_global_this}.{FARM_RUNTIME_VERSION} !== '{version}') {{ throw new Error("Incompatible Farm runtime, expected version {version} but got " + ({farm_global_this} && {farm_global_this}.{FARM_RUNTIME_VERSION})); }}"#,
+      version = self.version
+    )
+  }
+}
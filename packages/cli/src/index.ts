@@ -1,4 +1,5 @@
 import { readFileSync } from 'node:fs';
+import { resolve } from 'node:path';
 
 import { cac } from 'cac';
 import { getOptionFromBuildOption } from './config.js';
@@ -86,6 +87,10 @@ cli
   .option('--sourcemap', 'output source maps for build')
   .option('--treeShaking', 'Eliminate useless code without side effects')
   .option('--minify', 'code compression at build time')
+  .option(
+    '--profile-json <file>',
+    'write the profile scopes of the build as a JSON trace, requires a binding built with the profile feature'
+  )
   .action(
     async (
       rootPath: string,
@@ -99,6 +104,12 @@ cli
         ...getOptionFromBuildOption(options)
       };
 
+      if (options.profileJson) {
+        // the profile scopes are only collected by the sync compilation
+        process.env.FARM_PROFILE = 'true';
+        process.env.FARM_PROFILE_JSON = resolve(options.profileJson);
+      }
+
       const { build } = await resolveCore();
       handleAsyncOperationErrors(build(defaultOptions), 'error during build');
     }
@@ -34,6 +34,7 @@ export interface FarmCLIBuildOptions {
   sourcemap?: boolean;
   minify?: boolean;
   treeShaking?: boolean;
+  profileJson?: string;
   format?: 'cjs' | 'esm';
   target?:
     | 'browser'
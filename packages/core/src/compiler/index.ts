@@ -18,6 +18,15 @@ export interface UpdateQueueItem {
   resolve: (res: JsUpdateResult) => void;
 }
 
+/**
+ * A resource written to `output.path` by `writeResourcesToDisk`, `size` is in bytes.
+ */
+export interface WrittenResource {
+  name: string;
+  filePath: string;
+  size: number;
+}
+
 export interface TracedModuleGraph {
   root: string;
   modules: Array<{
@@ -152,12 +161,18 @@ export class Compiler {
     return this._bindingCompiler.resourcesMap() as Record<string, Resource>;
   }
 
-  writeResourcesToDisk(): void {
+  /**
+   * Write the resources to `output.path` and return the written resources.
+   * When `output.dryRun` is enabled, nothing is written and the resources that would be written are returned.
+   */
+  writeResourcesToDisk(): WrittenResource[] {
     const resources = this.resources();
     const configOutputPath = this.config.config.output.path;
     const outputPath = path.isAbsolute(configOutputPath)
       ? configOutputPath
       : path.join(this.config.config.root, configOutputPath);
+    const dryRun = this.config.config.output.dryRun;
+    const writtenResources: WrittenResource[] = [];
 
     for (const [name, resource] of Object.entries(resources)) {
       // remove query params and hash of name
@@ -165,6 +180,16 @@ export class Compiler {
       const nameWithoutHash = nameWithoutQuery.split('#')[0];
 
       let filePath = path.join(outputPath, nameWithoutHash);
+      writtenResources.push({
+        name: nameWithoutHash,
+        filePath,
+        size: resource.length
+      });
+
+      if (dryRun) {
+        continue;
+      }
+
       if (!existsSync(path.dirname(filePath))) {
         mkdirSync(path.dirname(filePath), { recursive: true });
       }
@@ -172,7 +197,11 @@ export class Compiler {
       writeFileSync(filePath, resource);
     }
 
-    this.callWriteResourcesHook();
+    if (!dryRun) {
+      this.callWriteResourcesHook();
+    }
+
+    return writtenResources;
   }
 
   callWriteResourcesHook() {
@@ -11,6 +11,7 @@ export const FARM_DEFAULT_NAMESPACE = 'FARM_DEFAULT_NAMESPACE';
 
 export const CUSTOM_KEYS = {
   external_record: 'external.record',
+  external_prefer: 'external.prefer',
   runtime_isolate: 'runtime.isolate',
   resolve_dedupe: 'resolve.dedupe',
   css_locals_conversion: 'css.modules.locals_conversion',
@@ -664,7 +664,7 @@ export function normalizeDevServerConfig(
   }) as NormalizedServerConfig;
 }
 
-type Format = Exclude<OutputConfig['format'], undefined>;
+type Format = Extract<OutputConfig['format'], string>;
 const formatFromExt: Record<string, Format> = {
   cjs: 'cjs',
   mjs: 'esm',
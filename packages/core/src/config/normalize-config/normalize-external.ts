@@ -89,6 +89,12 @@ export function normalizeExternal(
   resolvedCompilation.custom[CUSTOM_KEYS.external_record] =
     JSON.stringify(recordExternal);
 
+  if (config.compilation?.externalPrefer) {
+    resolvedCompilation.custom[CUSTOM_KEYS.external_prefer] = JSON.stringify(
+      config.compilation.externalPrefer
+    );
+  }
+
   resolvedCompilation.external = [
     ...stringExternal,
     '^node:',
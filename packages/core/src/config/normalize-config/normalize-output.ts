@@ -116,8 +116,8 @@ function normalizeTargetEnv(config: Config['config']) {
   if (targetsMap[targetEnv]) {
     const { scriptTargets, cssTargets, scriptGenTarget } =
       targetsMap[targetEnv];
-    // set defaults for targets
-    if (config.presetEnv !== false) {
+    // set defaults for targets, a browserslist query already decides its own targets and is kept as is
+    if (config.presetEnv !== false && typeof config.presetEnv !== 'string') {
       // null means disable presetEnv
       if (scriptTargets == null) {
         config.presetEnv = false;
@@ -206,8 +206,8 @@ function tryGetDefaultPublicPath(
     return publicPath;
   }
 
-  if (['node', 'browser'].includes(targetEnv)) {
-    return targetEnv === 'node' ? './' : '/';
+  if (['node', 'node-next', 'browser'].includes(targetEnv)) {
+    return targetEnv === 'browser' ? '/' : './';
   }
 }
 
@@ -31,6 +31,7 @@ const compilationConfigSchema = z
       .object({
         entryFilename: z.string().optional(),
         filename: z.string().optional(),
+        chunkFilename: z.string().optional(),
         path: z.string().optional(),
         publicPath: z.string().optional(),
         assetsFilename: z.string().optional(),
@@ -50,8 +51,25 @@ const compilationConfigSchema = z
             'library-node'
           ])
           .optional(),
-        format: z.enum(['cjs', 'esm']).optional(),
-        clean: z.boolean().optional()
+        format: z
+          .union([
+            z.enum(['cjs', 'esm', 'umd', 'amd', 'iife', 'system']),
+            z.record(z.enum(['cjs', 'esm', 'umd', 'amd', 'iife', 'system']))
+          ])
+          .optional(),
+        clean: z.boolean().optional(),
+        indent: z.number().int().nonnegative().max(16).optional(),
+        semicolons: z.boolean().optional(),
+        name: z.string().optional(),
+        freezeExports: z.boolean().optional(),
+        checksums: z.boolean().optional(),
+        writeManifest: z.boolean().optional(),
+        sharedRuntimeChunk: z.boolean().optional(),
+        versionComment: z.boolean().optional(),
+        banner: z.string().optional(),
+        footer: z.string().optional(),
+        globalObject: z.string().optional(),
+        dryRun: z.boolean().optional()
       })
       .strict()
       .optional(),
@@ -60,10 +78,13 @@ const compilationConfigSchema = z
         extensions: z.array(z.string()).optional(),
         alias: z.record(z.string()).optional(),
         mainFields: z.array(z.string()).optional(),
+        mainFiles: z.array(z.string()).optional(),
         conditions: z.array(z.string()).optional(),
         symlinks: z.boolean().optional(),
         strictExports: z.boolean().optional(),
         autoExternalFailedResolve: z.boolean().optional(),
+        conditionalSuffixes: z.array(z.string()).optional(),
+        tsconfigPaths: z.boolean().optional(),
         dedupe: z.array(z.string()).optional()
       })
       .strict()
@@ -75,6 +96,7 @@ const compilationConfigSchema = z
     externalNodeBuiltins: z
       .union([z.boolean(), z.array(z.string())])
       .optional(),
+    externalPrefer: z.enum(['external', 'local']).optional(),
     mode: z.string().optional(),
     watch: z
       .union([
@@ -94,9 +116,30 @@ const compilationConfigSchema = z
     runtime: z
       .object({
         path: z.string().optional(),
+        source: z.string().optional(),
         plugins: z.array(z.string()).optional(),
         swcHelpersPath: z.string().optional(),
-        isolate: z.boolean().optional()
+        isolate: z.boolean().optional(),
+        standalone: z
+          .object({
+            url: z.string().optional()
+          })
+          .strict()
+          .optional(),
+        lazyParse: z.array(z.string()).optional(),
+        moduleParams: z
+          .object({
+            module: z.string().optional(),
+            exports: z.string().optional(),
+            require: z.string().optional(),
+            dynamicRequire: z.string().optional()
+          })
+          .strict()
+          .optional(),
+        htmlInject: z.enum(['inline', 'external']).optional(),
+        nonce: z.string().optional(),
+        injectModuleId: z.boolean().optional(),
+        chunkLoadTimeoutMs: z.number().int().positive().optional()
       })
       .strict()
       .optional(),
@@ -104,7 +147,8 @@ const compilationConfigSchema = z
       .object({
         include: z.array(z.string()).optional(),
         publicDir: z.string().optional(),
-        mode: z.enum(['browser', 'node']).optional()
+        mode: z.enum(['browser', 'node']).optional(),
+        nativeAddon: z.enum(['copy', 'external']).optional()
       })
       .strict()
       .optional(),
@@ -173,7 +217,8 @@ const compilationConfigSchema = z
         z.boolean(),
         z.literal('all'),
         z.literal('inline'),
-        z.literal('all-inline')
+        z.literal('all-inline'),
+        z.literal('hidden')
       ])
       .optional(),
     partialBundling: z
@@ -204,11 +249,13 @@ const compilationConfigSchema = z
         enforceTargetConcurrentRequests: z.boolean().optional(),
         enforceTargetMinSize: z.boolean().optional(),
         immutableModules: z.array(z.string()).optional(),
-        immutableModulesWeight: z.number().optional()
+        immutableModulesWeight: z.number().optional(),
+        vendorChunk: z.boolean().optional()
       })
       .strict()
       .optional(),
     lazyCompilation: z.boolean().optional(),
+    failOnCircularDependency: z.boolean().optional(),
     treeShaking: z.boolean().optional(),
     minify: z
       .union([
@@ -224,7 +271,10 @@ const compilationConfigSchema = z
               z.literal('minify-resource-pot')
             ])
             .optional(),
-          moduleDecls: z.boolean().optional()
+          moduleDecls: z.boolean().optional(),
+          keepNames: z.boolean().optional(),
+          keepFnames: z.boolean().optional(),
+          keepClassnames: z.boolean().optional()
         })
       ])
       .optional(),
@@ -233,6 +283,7 @@ const compilationConfigSchema = z
     presetEnv: z
       .union([
         z.boolean(),
+        z.string(),
         z.object({
           include: z.array(z.string()).optional(),
           exclude: z.array(z.string()).optional(),
@@ -265,7 +316,8 @@ const compilationConfigSchema = z
                 .optional()
             })
           ])
-          .optional()
+          .optional(),
+        exportStyleElement: z.boolean().optional()
       })
       .optional(),
     html: z.object({ base: z.string().optional() }).optional(),
@@ -295,7 +347,9 @@ const compilationConfigSchema = z
         })
         .optional()
     ]),
-    comments: z.union([z.boolean(), z.literal('license')]).optional(),
+    comments: z
+      .union([z.boolean(), z.literal('license'), z.literal('none')])
+      .optional(),
     custom: z.record(z.string(), z.string()).optional()
   })
   .strict();
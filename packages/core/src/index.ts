@@ -297,10 +297,11 @@ export async function createBundleHandler(
   watchMode = false
 ) {
   const compiler = await createCompiler(resolvedUserConfig, logger);
+  const dryRun = resolvedUserConfig.compilation?.output?.dryRun;
 
   await compilerHandler(
     async () => {
-      if (resolvedUserConfig.compilation?.output?.clean) {
+      if (resolvedUserConfig.compilation?.output?.clean && !dryRun) {
         compiler.removeOutputPathDir();
       }
       try {
@@ -308,7 +309,13 @@ export async function createBundleHandler(
       } catch (err) {
         throw new Error(logError(err) as unknown as string);
       }
-      compiler.writeResourcesToDisk();
+      const writtenResources = compiler.writeResourcesToDisk();
+
+      if (dryRun) {
+        for (const { name, size } of writtenResources) {
+          logger.info(`${name} ${(size / 1024).toFixed(2)} KB`);
+        }
+      }
     },
     resolvedUserConfig,
     logger
@@ -77,7 +77,9 @@ export function farmUserConfigToViteConfig(config: UserConfig): ViteUserConfig {
         output: {
           assetFileNames: config.compilation?.output?.assetsFilename,
           entryFileNames: config.compilation?.output?.entryFilename,
-          chunkFileNames: config.compilation?.output?.filename
+          chunkFileNames:
+            config.compilation?.output?.chunkFilename ??
+            config.compilation?.output?.filename
         }
       }
       // other options are not supported in farm
@@ -350,14 +350,34 @@ const notSupport: (method: string) => (...args: any[]) => any =
 // eslint-disable-next-line @typescript-eslint/no-explicit-any
 const noop: (...args: any) => any = () => void 0;
 
+// per entry formats can not be represented in rollup, use the format shared by all entries
+function getCommonFormat(
+  format: Config['config']['output']['format']
+): 'cjs' | 'esm' | 'umd' | 'amd' | 'iife' | 'system' | undefined {
+  if (typeof format !== 'object') return format;
+
+  const formats = new Set(Object.values(format));
+  return formats.size === 1 ? formats.values().next().value : 'esm';
+}
+
 function transformFarmFormatToRollupFormat(
   config: Config['config']['output']
 ): InternalModuleFormat {
-  if (config.format === 'esm') {
+  const format = getCommonFormat(config.format);
+
+  if (format === 'esm') {
     return 'es';
-  } else if (config.format === 'cjs') {
+  } else if (format === 'cjs') {
     if (config.targetEnv === 'node') return 'cjs';
     return 'amd';
+  } else if (format === 'umd') {
+    return 'umd';
+  } else if (format === 'amd') {
+    return 'amd';
+  } else if (format === 'iife') {
+    return 'iife';
+  } else if (format === 'system') {
+    return 'system';
   }
 }
 
@@ -367,7 +387,7 @@ export function transformFarmConfigToRollupNormalizedOutputOptions(
   return {
     amd: { autoId: false, define: 'define', forceJsExtensionForImports: false },
     assetFileNames: config.output.assetsFilename,
-    chunkFileNames: config.output.filename,
+    chunkFileNames: config.output.chunkFilename ?? config.output.filename,
     compact: Boolean(config.minify),
     dir: config.output.path,
     dynamicImportInCjs: true,
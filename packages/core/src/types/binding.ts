@@ -124,6 +124,11 @@ export interface OutputConfig {
    * Configure the name of all the output files
    */
   filename?: string;
+  /**
+   * Configure the name of the js output files which don't contain the entry modules, e.g. `[name].[hash].[ext]`. Prior to filename.
+   * The `[hash]` is computed from the modules and the content of the chunk, so the chunk keeps its name when other chunks change.
+   */
+  chunkFilename?: string;
   /**
    * Output dir that production files are emitted to.
    */
@@ -142,6 +147,7 @@ export interface OutputConfig {
   /**
    * Target execution environment of production files, browser or node. browser is equal to `browser-es2017`, node is equal to `node16`.
    * You can also set target env version like `node16`, `node-legacy`, 'browser-legacy`, 'browser-es2015', 'browser-2017', 'browser-esnext'. Farm will automatically downgrade syntax and inject polyfill according to the specified target env.
+   * `node-next` emits native es modules for node without the Farm runtime, `import.meta` is kept and `require` of commonjs externals is declared by `createRequire`.
    * @default 'browser'
    */
   targetEnv?:
@@ -158,13 +164,80 @@ export interface OutputConfig {
     | 'library-browser'
     | 'library-node';
   /**
-   * output module format
+   * output module format, a map from entry name to format can be used to specify the format of each entry,
+   * e.g. `{ main: 'esm', legacy: 'cjs' }`. Entries that are not in the map and non-entry resources use the format shared by all entries, or `esm` if they differ.
    */
-  format?: 'cjs' | 'esm';
+  format?:
+    | 'cjs'
+    | 'esm'
+    | 'umd'
+    | 'amd'
+    | 'iife'
+    | 'system'
+    | Record<string, 'cjs' | 'esm' | 'umd' | 'amd' | 'iife' | 'system'>;
   /**
    * clean output.path automatically or not
    */
   clean?: boolean;
+  /**
+   * Number of spaces used to indent the rendered modules in development mode, making the output easier to read.
+   * At most 16 spaces are supported. Compact output is always kept in production.
+   */
+  indent?: number;
+  /**
+   * Whether the statements of the rendered modules end with semicolons. When `false`, the modules are rendered in the ASI style:
+   * the semicolons at the end of lines are omitted, and a line that would continue the previous statement is prefixed by `;`.
+   * Minified output is not affected.
+   * @default true
+   */
+  semicolons?: boolean;
+  /**
+   * Global variable name the library exports are assigned to when the `umd` bundle is loaded by a plain `<script>`.
+   * It's required by the `iife` format when the entry has exports.
+   */
+  name?: string;
+  /**
+   * `Object.freeze` the exports of library entries so consumers can not mutate them, only works for `cjs`, `umd` and `amd` formats.
+   */
+  freezeExports?: boolean;
+  /**
+   * Emit a `checksums.txt` listing the sha256 of every emitted resource, which can be verified by `sha256sum -c checksums.txt`.
+   * @default false
+   */
+  checksums?: boolean;
+  /**
+   * Emit a `manifest.json` mapping each entry module id to its entry file, the js chunks and the css files it loads.
+   * @default false
+   */
+  writeManifest?: boolean;
+  /**
+   * Emit the runtime once and import it from each entry, instead of inlining the runtime into every entry. An entry that is the only consumer of the runtime still inlines it.
+   * @default false
+   */
+  sharedRuntimeChunk?: boolean;
+  /**
+   * Prepend `/* Farm vX.Y.Z (production) *\/` to every emitted js resource, which identifies the Farm version and the build mode of a deployed bundle.
+   * @default false
+   */
+  versionComment?: boolean;
+  /**
+   * Code prepended to every emitted js resource, for example, a copyright banner. The source map is shifted accordingly.
+   */
+  banner?: string;
+  /**
+   * Code appended to every emitted js resource, before the source map comment.
+   */
+  footer?: string;
+  /**
+   * Expression of the global object the Farm runtime is attached to, e.g. `self` for web workers or `globalThis` for Deno.
+   * @default 'global' when targeting node, otherwise 'window'
+   */
+  globalObject?: string;
+  /**
+   * Compile and report the resources that would be emitted, without writing them to `output.path`. Useful for checking the output names and sizes in CI.
+   * @default false
+   */
+  dryRun?: boolean;
 }
 
 export interface ResolveConfig {
@@ -182,6 +255,11 @@ export interface ResolveConfig {
    * @default ["exports", "browser", "module", "main"]
    */
   mainFields?: string[];
+  /**
+   * File names tried in order when a directory is imported, the configured extensions are appended to each name. Directory imports are disallowed when it's empty.
+   * @default ["index"]
+   */
+  mainFiles?: string[];
   /**
    * Conditions of node package module spec
    */
@@ -201,6 +279,19 @@ export interface ResolveConfig {
    * @default false
    */
   autoExternalFailedResolve?: boolean;
+  /**
+   * Suffixes tried before the resolved file name, `{target}` is replaced with the target env (`browser` or `node`).
+   * For example, `['.{target}']` resolves `./impl` to `./impl.browser.ts` when targeting browser and `./impl.node.ts` when targeting node.
+   * Falls back to `./impl.ts` when no variant exists. Files under `node_modules` are not affected.
+   * @default []
+   */
+  conditionalSuffixes?: string[];
+  /**
+   * Resolve the `compilerOptions.paths` aliases of the `tsconfig.json` under the root, relative to `compilerOptions.baseUrl`.
+   * They are tried after `alias`, `extends` of tsconfig.json is not followed.
+   * @default false
+   */
+  tsconfigPaths?: boolean;
   /**
    *
    * @default []
@@ -214,6 +305,11 @@ export interface RuntimeConfig {
    * Note: t is not recommended to configure this option under normal circumstances, because once this option is configured, the pointed runtime needs to be compatible with Farm's runtime
    */
   path?: string;
+  /**
+   * Provide the source code of the runtime entry inline instead of reading it from `path`.
+   * When `path` is not set, the inline runtime is resolved as a virtual module under the project root.
+   */
+  source?: string;
   /**
    * Configure the Runtime plug-in, through the Runtime plug-in, you can intervene in Runtime behavior, such as module loading, resource loading, etc.
    */
@@ -233,6 +329,49 @@ export interface RuntimeConfig {
    * If set to true, the farm entry script will be emitted as a separate file.
    */
   isolate?: boolean;
+  /**
+   * Emit the runtime as a standalone versioned file `farm_runtime.[version].js` so that it can be shared across builds.
+   * Set `url` to reference a standalone runtime emitted by another build instead of emitting one, the entries will check the runtime version before executing.
+   * Note: the builds sharing a runtime should use the same `namespace` and runtime `plugins`.
+   */
+  standalone?: {
+    url?: string;
+  };
+  /**
+   * Experimental. Modules matching these regexes are emitted with their body as a string that is compiled by `new Function` on first require,
+   * deferring the parse cost of rarely-used modules. Async modules are always emitted as normal, and the module body is not covered by sourcemaps.
+   */
+  lazyParse?: string[];
+  /**
+   * Parameter names of the rendered module function `function(module, exports, farmRequire, farmDynamicRequire) {}`.
+   * Only the emitted module code is affected, the runtime passes the arguments by position.
+   */
+  moduleParams?: {
+    module?: string;
+    exports?: string;
+    require?: string;
+    dynamicRequire?: string;
+  };
+  /**
+   * How the runtime is injected into html entries. `inline` injects the runtime code as an inline `<script>`,
+   * `external` emits the runtime as a separate file referenced by `<script src>`, which is required by sites with a strict CSP.
+   * @default 'inline'
+   */
+  htmlInject?: 'inline' | 'external';
+  /**
+   * Nonce attribute of the `<script>` tags injected into html entries.
+   */
+  nonce?: string;
+  /**
+   * Declare a `__farm_module_id__` constant holding the id of the module in each emitted module, which is useful for debugging and error reporting.
+   * @default false
+   */
+  injectModuleId?: boolean;
+  /**
+   * Reject the loading of a dynamic resource when it does not finish within the timeout in milliseconds.
+   * By default there is no timeout.
+   */
+  chunkLoadTimeoutMs?: number;
 }
 
 export interface ScriptConfig {
@@ -308,6 +447,12 @@ export interface CssConfig {
   prefixer?: {
     targets?: string[] | string | BrowserTargetsRecord;
   } | null;
+  /**
+   * Export the injected `<style>` element as the default export of css modules, e.g. `import style from './a.css'`.
+   * Only applies when styles are injected at runtime, that is development mode targeting browser.
+   * @default false
+   */
+  exportStyleElement?: boolean;
   /**
    * You SHOULD NOT use this option. It's preserved vite css options for compatibility of vite plugins
    */
@@ -391,6 +536,11 @@ export interface PartialBundlingConfig {
    * @default ["node_modules"]
    */
   immutableModules?: string[];
+  /**
+   * Place the node_modules modules shared by 2 or more entries into a separate `vendor` resource.
+   * @default false
+   */
+  vendorChunk?: boolean;
 }
 
 export interface PresetEnvConfig {
@@ -427,6 +577,11 @@ export interface Config {
      */
     external?: (string | Record<string, string>)[];
     externalNodeBuiltins?: boolean | string[];
+    /**
+     * Which one wins when an import matches `external` but can also be resolved locally, default to `external`.
+     * With `local`, the local module is bundled and the import is only kept external when it can not be resolved.
+     */
+    externalPrefer?: 'external' | 'local';
     mode?: 'development' | 'production';
     root?: string;
     runtime?: RuntimeConfig;
@@ -435,6 +590,11 @@ export interface Config {
       include?: string[];
       publicDir?: string;
       mode?: 'node' | 'browser';
+      /**
+       * How `.node` native addons are handled when targeting node. `copy` keeps the import external and copies the addon to the output dir, `external` only keeps the import external.
+       * @default 'copy'
+       */
+      nativeAddon?: 'copy' | 'external';
     };
     script?: ScriptConfig;
     css?: CssConfig;
@@ -448,8 +608,9 @@ export interface Config {
       - inline: Only generate sourcemap for files not under node_modules, and inline sourcemap into the product, do not generate a separate file
       - all: generate sourcemap for all files, and generate a separate sourcemap file
       - all-inline: Generate sourcemaps for all files, and inline sourcemaps into the product, do not generate separate files
+      - hidden: Only generate sourcemap for files not under node_modules, and generate a separate sourcemap file without the `sourceMappingURL` comment in the product
      */
-    sourcemap?: boolean | 'inline' | 'all' | 'all-inline';
+    sourcemap?: boolean | 'inline' | 'all' | 'all-inline' | 'hidden';
     /**
      * Configure the behavior of Farm's partial bundling. For details, please refer to https://farmfe.org/docs/features/partial-bundling
      */
@@ -458,6 +619,11 @@ export interface Config {
      * Whether to enable lazy compilation, configure to false to disabled. See https://farmfe.org/docs/features/lazy-compilation
      */
     lazyCompilation?: boolean;
+    /**
+     * Fail the build with the cycle path if the module graph contains any circular dependency.
+     * @default false
+     */
+    failOnCircularDependency?: boolean;
     /**
      * Whether to enable tree shake, set to false to disable. See https://farmfe.org/docs/features/tree-shake
      */
@@ -465,9 +631,16 @@ export interface Config {
     minify?: boolean | JsMinifyOptions;
     record?: boolean;
     progress?: boolean;
-    presetEnv?: boolean | PresetEnvConfig;
+    /**
+     * true: enable with the default targets, string: a browserslist query of the targets, e.g. '> 0.5%, last 2 versions'
+     */
+    presetEnv?: boolean | string | PresetEnvConfig;
     persistentCache?: boolean | PersistentCacheConfig;
-    comments?: boolean | 'license';
+    /**
+     * true: preserve all comments, false or 'none': remove all comments including license comments,
+     * 'license': only preserve license comments. Default is 'license'.
+     */
+    comments?: boolean | 'license' | 'none';
     custom?: Record<string, any>;
   };
   jsPlugins?: JsPlugin[];
@@ -252,4 +252,22 @@ export interface JsMinifyOptions {
    * @default true
    */
   moduleDecls?: boolean;
+
+  /**
+   * Preserve the names of functions and classes, so that `fn.name` is not changed by minification
+   * @default false
+   */
+  keepNames?: boolean;
+
+  /**
+   * Preserve the names of functions only
+   * @default false
+   */
+  keepFnames?: boolean;
+
+  /**
+   * Preserve the names of classes only
+   * @default false
+   */
+  keepClassnames?: boolean;
 }
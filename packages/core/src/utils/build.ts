@@ -35,9 +35,12 @@ export async function compilerHandler(
 
   const elapsedTime = Math.floor(performance.now() - startTime);
   const persistentCacheText = persistentCache ? bold(PersistentCacheBrand) : '';
+  const emittedText = output.dryRun
+    ? 'Dry run, no resources are written.'
+    : `Resources emitted to ${bold(green(output.path))}.`;
   logger.info(
     `Build completed in ${bold(
       green(`${elapsedTime}ms`)
-    )} ${persistentCacheText} Resources emitted to ${bold(green(output.path))}.`
+    )} ${persistentCacheText} ${emittedText}`
   );
 }
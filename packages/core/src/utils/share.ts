@@ -159,6 +159,11 @@ export function preventExperimentalWarning() {
 }
 
 export function mapTargetEnvValue(config: Config['config']) {
+  // native es module output for node, it's handled by the rust compiler directly
+  if (config.output.targetEnv === 'node-next') {
+    return;
+  }
+
   if (FARM_TARGET_NODE_ENVS.includes(config.output.targetEnv)) {
     config.output.targetEnv = 'node';
   } else if (FARM_TARGET_BROWSER_ENVS.includes(config.output.targetEnv)) {
@@ -146,4 +146,21 @@ describe('normalizeOutput', () => {
       expect(resolvedConfig.output.publicPath).toEqual(item.expectPublic);
     });
   });
+
+  test('normalizeOutput should keep the browserslist query of presetEnv', () => {
+    (['node', 'browser', 'library'] as const).forEach((targetEnv) => {
+      const resolvedConfig: ResolvedCompilation = {
+        input: {
+          index: 'index.html'
+        },
+        output: {
+          targetEnv
+        },
+        presetEnv: 'ie 11'
+      };
+
+      normalizeOutput(resolvedConfig, true, new NoopLogger());
+      expect(resolvedConfig.presetEnv).toEqual('ie 11');
+    });
+  });
 });
@@ -0,0 +1,27 @@
+import { existsSync, rmSync } from 'node:fs';
+import path from 'node:path';
+import { expect, test } from 'vitest';
+import { getCompiler, getFixturesDir } from './common.js';
+
+test('Dry run - report resources without writing them', async () => {
+  const root = path.resolve(getFixturesDir(), 'js-plugins');
+  const outputPath = path.join(root, 'dist', 'dry-run');
+  rmSync(outputPath, { recursive: true, force: true });
+
+  const compiler = await getCompiler(root, 'dry-run', [], undefined, {
+    dryRun: true
+  });
+
+  await compiler.compile();
+  const writtenResources = compiler.writeResourcesToDisk();
+
+  expect(writtenResources.map((resource) => resource.name)).toContain(
+    'index.mjs'
+  );
+  for (const resource of writtenResources) {
+    expect(resource.size).toBeGreaterThan(0);
+    expect(resource.filePath).toBe(path.join(outputPath, resource.name));
+    expect(existsSync(resource.filePath)).toBe(false);
+  }
+  expect(existsSync(outputPath)).toBe(false);
+});
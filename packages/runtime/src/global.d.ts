@@ -5,4 +5,6 @@ declare function _interop_require_default(obj: any): any;
 // eslint-disable-next-line @typescript-eslint/no-explicit-any
 declare function _interop_require_wildcard(obj: any, nodeInterop?: boolean): any;
 // eslint-disable-next-line @typescript-eslint/no-explicit-any
-declare function _export_star(from: any, to: any): any;
\ No newline at end of file
+declare function _export_star(from: any, to: any): any;
+// replaced by crates/plugin_runtime, false if the entry doesn't load resources dynamically
+declare const __FARM_DYNAMIC_IMPORT__: boolean;
@@ -36,8 +36,8 @@ export class ModuleSystem {
   dynamicResources: Resource[];
   // dynamic module entry and resources map
   dynamicModuleResourcesMap: Record<string, number[]>;
-  // resources loader
-  resourceLoader: ResourceLoader;
+  // resources loader, omitted from the runtime of the entries that don't load resources dynamically
+  resourceLoader?: ResourceLoader;
   // runtime plugin container
   pluginContainer: FarmRuntimePluginContainer;
   targetEnv: "browser" | "node";
@@ -47,7 +47,9 @@ export class ModuleSystem {
     this.cache = {};
     this.publicPaths = [];
     this.dynamicModuleResourcesMap = {};
-    this.resourceLoader = new ResourceLoader(this, this.publicPaths);
+    if (__FARM_DYNAMIC_IMPORT__) {
+      this.resourceLoader = new ResourceLoader(this, this.publicPaths);
+    }
     this.pluginContainer = new FarmRuntimePluginContainer([]);
     this.targetEnv = targetEnv;
     this.externalModules = {};
@@ -132,7 +134,14 @@ export class ModuleSystem {
 
   farmDynamicRequire(moduleId: string): Promise<any> {
     if (this.modules[moduleId]) {
-      const exports = this.require(moduleId);
+      let exports;
+
+      // reject the import promise if the module throws during execution, instead of throwing synchronously
+      try {
+        exports = this.require(moduleId);
+      } catch (err) {
+        return Promise.reject(err);
+      }
 
       if (exports.__farm_async) {
         return exports.default;
@@ -144,14 +153,37 @@ export class ModuleSystem {
     return this.loadDynamicResources(moduleId);
   }
 
+  // get the resources of the dynamic module, together with the chunks they depend on, so the dependencies are preloaded before executing the module
+  getDynamicResources(moduleId: string): Resource[] {
+    const indexes = new Set<number>();
+    const visit = (index: number) => {
+      if (indexes.has(index)) {
+        return;
+      }
+      indexes.add(index);
+      this.dynamicResources[index].deps?.forEach(visit);
+    };
+    this.dynamicModuleResourcesMap[moduleId]?.forEach(visit);
+
+    return Array.from(indexes).map((index) => this.dynamicResources[index]);
+  }
+
   loadDynamicResources(moduleId: string, force = false): Promise<any> {
-    const resources = this.dynamicModuleResourcesMap[moduleId].map((index) => this.dynamicResources[index]);
+    const resources = this.getDynamicResources(moduleId);
 
     if (!resources || resources.length === 0) {
       throw new Error(
         `Dynamic imported module "${moduleId}" does not belong to any resource`,
       );
     }
+
+    const resourceLoader = this.resourceLoader;
+
+    if (!resourceLoader) {
+      throw new Error(
+        `Dynamic imported module "${moduleId}" can not be loaded, the resource loader is omitted from the runtime of the entry that doesn't load resources dynamically`,
+      );
+    }
     // force reload resources
     if (force) {
       this.clearCache(moduleId);
@@ -160,18 +192,18 @@ export class ModuleSystem {
     return Promise.all(
       resources.map((resource) => {
         if (force) {
-          const resourceLoaded = this.resourceLoader.isResourceLoaded(resource.path);
-          this.resourceLoader.setLoadedResource(resource.path, false);
+          const resourceLoaded = resourceLoader.isResourceLoaded(resource.path);
+          resourceLoader.setLoadedResource(resource.path, false);
 
           if (resourceLoaded) {
-            return this.resourceLoader.load({
+            return resourceLoader.load({
               ...resource,
               // force reload the resource
               path: `${resource.path}?t=${Date.now()}`
             });
           }
         }
-        return this.resourceLoader.load(resource);
+        return resourceLoader.load(resource);
       }),
     )
       .then(() => {
@@ -257,7 +289,7 @@ export class ModuleSystem {
 
   setInitialLoadedResources(resources: string[]) {
     for (const resource of resources) {
-      this.resourceLoader.setLoadedResource(resource);
+      this.resourceLoader?.setLoadedResource(resource);
     }
   }
 
@@ -274,7 +306,9 @@ export class ModuleSystem {
   // The public paths are injected during compile time
   setPublicPaths(publicPaths: string[]): void {
     this.publicPaths = publicPaths;
-    this.resourceLoader.publicPaths = this.publicPaths;
+    if (this.resourceLoader) {
+      this.resourceLoader.publicPaths = this.publicPaths;
+    }
   }
 
   // The plugins are injected during compile time.
@@ -6,11 +6,14 @@ import type { ResourceLoadResult } from './plugin';
 export interface Resource {
   path: string;
   type: 0 | 1; // 0: script, 1: link
+  deps?: number[]; // indexes of the dynamic resources this script depends on
 }
 
 // Injected during build
 export const __farm_global_this__: any = '<@__farm_global_this__@>';
 export const __global_this__: any = typeof window !== 'undefined' ? window : typeof global !== 'undefined' ? global : {};
+// Injected during build, null if there is no timeout
+export const chunkLoadTimeout: any = '<@__farm_chunk_load_timeout__@>';
 
 export const targetEnv = __farm_global_this__.__FARM_TARGET_ENV__ || 'node';
 export const isBrowser =
@@ -110,6 +113,10 @@ export class ResourceLoader {
       promise = this._loadLink(url);
     }
 
+    if (chunkLoadTimeout > 0) {
+      promise = this._withTimeout(promise, url);
+    }
+
     this._loadingResources[resource.path] = promise;
 
     promise
@@ -135,6 +142,17 @@ export class ResourceLoader {
     return promise;
   }
 
+  private _withTimeout(promise: Promise<void>, url: string): Promise<void> {
+    let timer: ReturnType<typeof setTimeout>;
+    const timeout = new Promise<void>((_, reject) => {
+      timer = setTimeout(() => {
+        reject(new Error(`Loading "${url}" timed out after ${chunkLoadTimeout}ms`));
+      }, chunkLoadTimeout);
+    });
+
+    return Promise.race([promise, timeout]).finally(() => clearTimeout(timer));
+  }
+
   private _loadScript(path: string): Promise<void> {
     // @ts-ignore
     if (FARM_RUNTIME_TARGET_ENV !== 'browser') {
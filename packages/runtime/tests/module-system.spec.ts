@@ -0,0 +1,30 @@
+import { test, expect } from 'vitest';
+import { ModuleSystem } from '../src/module-system';
+
+test('dynamic require rejects when the module throws', async () => {
+  const moduleSystem = new ModuleSystem();
+  const error = new Error('failed to execute');
+
+  moduleSystem.register('throw.ts', () => {
+    throw error;
+  });
+
+  const promise = moduleSystem.farmDynamicRequire('throw.ts');
+
+  expect(promise).toBeInstanceOf(Promise);
+  await expect(promise).rejects.toBe(error);
+});
+
+test('dynamic require rejects when the async module throws', async () => {
+  const moduleSystem = new ModuleSystem();
+  const error = new Error('failed to execute');
+
+  moduleSystem.register('async-throw.ts', async () => {
+    await Promise.resolve();
+    throw error;
+  });
+
+  await expect(moduleSystem.farmDynamicRequire('async-throw.ts')).rejects.toBe(
+    error
+  );
+});